@@ -275,10 +275,12 @@ pub fn Layout(#[prop(optional)] children: Option<Children>) -> impl IntoView {
         create_search_option(DocRoutes::Collapsible, "Collapsible"),
         create_search_option(DocRoutes::Button, "Button"),
         create_search_option(DocRoutes::Input, "Input"),
+        create_search_option(DocRoutes::FormControl, "Form Control"),
         create_search_option(DocRoutes::TiptapEditor, "Tiptap Editor"),
         create_search_option(DocRoutes::DateTime, "Date & Time"),
         create_search_option(DocRoutes::Slider, "Slider"),
         create_search_option(DocRoutes::Select, "Select"),
+        create_search_option(DocRoutes::Combobox, "Combobox"),
         create_search_option(DocRoutes::ColorPicker, "Color Picker"),
         create_search_option(DocRoutes::Alert, "Alert"),
         create_search_option(DocRoutes::Toast, "Toast"),
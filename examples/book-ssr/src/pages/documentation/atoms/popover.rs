@@ -30,7 +30,7 @@ pub fn PageAtomPopover() -> impl IntoView {
                     use leptonic::atoms::popover::{Popover, PopoverContent, PopoverContext, PopoverTrigger};
 
                     view! {
-                        <Popover disabled=false>
+                        <Popover disabled=false placement_x=PlacementX::Center placement_y=PlacementY::Above writing_direction=WritingDirection::Ltr>
                             <PopoverTrigger>
                                 {
                                     let ctx = expect_context::<PopoverContext>();
@@ -42,7 +42,7 @@ pub fn PageAtomPopover() -> impl IntoView {
                                 }
                             </PopoverTrigger>
 
-                            <PopoverContent placement_x=PlacementX::Center placement_y=PlacementY::Above writing_direction=WritingDirection::Ltr>
+                            <PopoverContent>
                                 "Overlay"
                             </PopoverContent>
                         </Popover>
@@ -50,7 +50,7 @@ pub fn PageAtomPopover() -> impl IntoView {
                 "#)}
             </Code>
 
-            <Popover disabled=false>
+            <Popover disabled=false placement_x=PlacementX::Center placement_y=PlacementY::Above writing_direction=WritingDirection::Ltr>
                 <PopoverTrigger>
                     {
                         let ctx = expect_context::<PopoverContext>();
@@ -68,7 +68,7 @@ pub fn PageAtomPopover() -> impl IntoView {
                     }
                 </PopoverTrigger>
 
-                <PopoverContent placement_x=PlacementX::Center placement_y=PlacementY::Above writing_direction=WritingDirection::Ltr>
+                <PopoverContent>
                     "Overlay"
                 </PopoverContent>
             </Popover>
@@ -48,6 +48,44 @@ pub fn PagePopover() -> impl IntoView {
                 </Popover>
             </div>
 
+            <H2 id="arrow" class="anchor">
+                "Arrow"
+                <AnchorLink href="#arrow" description="Direct link to section: Arrow"/>
+            </H2>
+
+            <P>
+                "Set "<Code inline=true>"arrow"</Code>" to point the popover at the element it is attached to. "
+                "The arrow follows the popover when it is flipped or shifted to stay inside of its "<Code inline=true>"boundary"</Code>", the viewport by default."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    view! {
+                        <Popover arrow=true margin=Size::Em(0.5)>
+                            <PopoverContent slot>
+                                "1"
+                            </PopoverContent>
+
+                            <Skeleton animated=false width=Size::Em(10.0)>
+                                "Hover me!"
+                            </Skeleton>
+                        </Popover>
+                    }
+                "#)}
+            </Code>
+
+            <div style="margin-top: 3em; margin-bottom: 1em;">
+                <Popover arrow=true margin=Size::Em(0.5)>
+                    <PopoverContent slot>
+                        "1"
+                    </PopoverContent>
+
+                    <Skeleton animated=false width=Size::Em(10.0)>
+                        "Hover me!"
+                    </Skeleton>
+                </Popover>
+            </div>
+
             <H2 id="styling" class="anchor">
                 "Styling"
                 <AnchorLink href="#styling" description="Direct link to section: Styling"/>
@@ -65,6 +103,7 @@ pub fn PagePopover() -> impl IntoView {
         <Toc toc=Toc::List {
             inner: vec![
                 Toc::Leaf { title: "Popover", link: "#popover" },
+                Toc::Leaf { title: "Arrow", link: "#arrow" },
                 Toc::Leaf { title: "Styling", link: "#styling" },
             ]
         }/>
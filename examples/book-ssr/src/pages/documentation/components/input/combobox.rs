@@ -0,0 +1,205 @@
+use indoc::indoc;
+use leptonic::{atoms::link::AnchorLink, components::prelude::*, hooks::*};
+use leptos::*;
+
+use crate::pages::documentation::{article::Article, toc::Toc};
+
+const CITIES: [&str; 10] = [
+    "Amsterdam",
+    "Athens",
+    "Berlin",
+    "Bern",
+    "Brussels",
+    "Copenhagen",
+    "Lisbon",
+    "London",
+    "Madrid",
+    "Paris",
+];
+
+#[component]
+#[allow(clippy::too_many_lines)]
+pub fn PageCombobox() -> impl IntoView {
+    let cities = CITIES.iter().map(|city| (*city).to_owned()).collect::<Vec<String>>();
+
+    let (city, set_city) = create_signal(String::new());
+    let (completed_city, set_completed_city) = create_signal(String::new());
+    let (number, set_number) = create_signal(String::new());
+    let (last_selected, set_last_selected) = create_signal(Option::<u32>::None);
+
+    view! {
+        <Article>
+            <H1 id="combobox" class="anchor">
+                "Combobox"
+                <AnchorLink href="#combobox" description="Direct link to article header"/>
+            </H1>
+
+            <P>
+                "A combobox is a text input suggesting values while you type. Other than a "<Code inline=true>"Select"</Code>", "
+                "it accepts any text. Suggestions only help entering common values."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    let (city, set_city) = create_signal(String::new());
+
+                    view! {
+                        <Combobox
+                            get=city
+                            set=set_city
+                            options=cities
+                            search_text_provider=move |city: String| city
+                            render_option=move |city: String| city
+                            placeholder="City"
+                        />
+                    }
+                "#)}
+            </Code>
+
+            <Combobox
+                get=city
+                set=set_city
+                options=cities.clone()
+                search_text_provider=move |city: String| city
+                render_option=move |city: String| city
+                placeholder="City"
+            />
+            <P>"Text: " {move || city.get()}</P>
+
+            <P>
+                "Suggestions matching the typed text are shown below the input. Use the arrow keys to highlight a suggestion "
+                "and Enter to take its text into the input, or click it. Escape closes the suggestions, keeping your text. "
+                "Just like on a "<Code inline=true>"Select"</Code>", the "<Code inline=true>"search_text_provider"</Code>" "
+                "gives the text of a suggestion and a "<Code inline=true>"search_filter_provider"</Code>" may replace the "
+                "default case-insensitive substring search."
+            </P>
+
+            <H2 id="inline-completion" class="anchor">
+                "Inline completion"
+                <AnchorLink href="#inline-completion" description="Direct link to section: Inline completion"/>
+            </H2>
+
+            <P>
+                "Set "<Code inline=true>"inline_completion"</Code>" to complete the typed text with the first suggestion starting with it. "
+                "The completed part is selected, so typing on simply replaces it. Press Tab or Enter to accept the completion "
+                "or Escape to discard it."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    <Combobox
+                        get=city
+                        set=set_city
+                        options=cities
+                        search_text_provider=move |city: String| city
+                        render_option=move |city: String| city
+                        inline_completion=true
+                    />
+                "#)}
+            </Code>
+
+            <Combobox
+                get=completed_city
+                set=set_completed_city
+                options=cities
+                search_text_provider=move |city: String| city
+                render_option=move |city: String| city
+                inline_completion=true
+                placeholder="City"
+            />
+            <P>"Text: " {move || completed_city.get()}</P>
+
+            <H2 id="loading-suggestions" class="anchor">
+                "Loading suggestions"
+                <AnchorLink href="#loading-suggestions" description="Direct link to section: Loading suggestions"/>
+            </H2>
+
+            <P>
+                "Suggestions can be loaded asynchronously with the same "<Code inline=true>"OptionsLoader"</Code>" a "
+                <Code inline=true>"Select"</Code>" accepts. It is queried with the typed text, debounced by "
+                <Code inline=true>"search_debounce"</Code>" milliseconds, and further pages are loaded when scrolling to the end of the suggestions. "
+                "Use "<Code inline=true>"on_select"</Code>" to learn which suggestion was picked, in addition to the text it put into the input."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    <Combobox
+                        get=number
+                        set=set_number
+                        loader=OptionsLoader::new(move |query: OptionsQuery| async move {
+                            (1..=1_000_000_u32)
+                                .filter(|o| o.to_string().starts_with(&query.search))
+                                .skip(query.offset)
+                                .take(query.limit)
+                                .collect::<Vec<u32>>()
+                        })
+                        search_text_provider=move |o: u32| o.to_string()
+                        render_option=move |o: u32| format!("{o:?}")
+                        on_select=move |o: u32| set_last_selected.set(Some(o))
+                    />
+                "#)}
+            </Code>
+
+            <Combobox
+                get=number
+                set=set_number
+                loader=OptionsLoader::new(move |query: OptionsQuery| async move {
+                    (1..=1_000_000_u32)
+                        .filter(|o| o.to_string().starts_with(&query.search))
+                        .skip(query.offset)
+                        .take(query.limit)
+                        .collect::<Vec<u32>>()
+                })
+                search_text_provider=move |o: u32| o.to_string()
+                render_option=move |o: u32| format!("{o:?}")
+                on_select=move |o: u32| set_last_selected.set(Some(o))
+                placeholder="Number"
+            />
+            <P>"Text: " {move || number.get()} ", last selected: " {move || format!("{:?}", last_selected.get())}</P>
+
+            <H2 id="accessibility" class="anchor">
+                "Accessibility"
+                <AnchorLink href="#accessibility" description="Direct link to section: Accessibility"/>
+            </H2>
+
+            <P>
+                "The input is marked as a "<Code inline=true>"combobox"</Code>" controlling the "<Code inline=true>"listbox"</Code>" of suggestions. "
+                "Focus stays on the input, while the highlighted suggestion is announced through "<Code inline=true>"aria-activedescendant"</Code>". "
+                "Build your own combobox with the "<Code inline=true>"use_combobox"</Code>" and "<Code inline=true>"use_combobox_option"</Code>" hooks, "
+                "which provide these attributes and the keyboard handling. Suggestions are positioned below the input using "
+                <Code inline=true>"use_overlay_position"</Code>", flipping above it when there is not enough space."
+            </P>
+
+            <H2 id="styling" class="anchor">
+                "Styling"
+                <AnchorLink href="#styling" description="Direct link to section: Styling"/>
+            </H2>
+
+            <P>"You may overwrite any of the following CSS variables to meet your styling needs."</P>
+
+            <Code>
+                {indoc!(r"
+                    --combobox-listbox-min-width
+                    --combobox-listbox-max-height
+                    --combobox-listbox-background-color
+                    --combobox-listbox-shadow
+                    --combobox-listbox-border-radius
+                    --combobox-option-color
+                    --combobox-option-hover-background-color
+                    --combobox-option-preselected-background-color
+                    --combobox-hint-color
+                ")}
+            </Code>
+        </Article>
+
+        <Toc toc=Toc::List {
+            inner: vec![
+                Toc::Leaf { title: "Combobox", link: "#combobox" },
+                Toc::Leaf { title: "Inline completion", link: "#inline-completion" },
+                Toc::Leaf { title: "Loading suggestions", link: "#loading-suggestions" },
+                Toc::Leaf { title: "Accessibility", link: "#accessibility" },
+                Toc::Leaf { title: "Styling", link: "#styling" },
+            ]
+        }/>
+    }
+}
@@ -1,15 +1,26 @@
 use indoc::indoc;
 use leptonic::atoms::link::AnchorLink;
 use leptonic::components::prelude::*;
-use leptonic::utils::time::GuideMode;
+use leptonic::utils::locale::CalendarLocale;
+use leptonic::utils::time::{DateTimeEntry, DayDecoration, GuideMode, HourCycle, Type};
 use leptos::*;
-use time::OffsetDateTime;
+use time::{Date, Duration, OffsetDateTime, Weekday};
 
 use crate::pages::documentation::article::Article;
 use crate::pages::documentation::toc::Toc;
 
 #[component]
+#[allow(clippy::too_many_lines)]
 pub fn PageDateTime() -> impl IntoView {
+    let (time, set_time) = create_signal(OffsetDateTime::now_utc());
+    let (appointment, set_appointment) = create_signal(None::<OffsetDateTime>);
+    let (alarm, set_alarm) = create_signal(None::<OffsetDateTime>);
+    let (birthday, set_birthday) = create_signal(None::<OffsetDateTime>);
+    let (meeting, set_meeting) = create_signal(None::<OffsetDateTime>);
+    let (range, set_range) = create_signal(None::<(OffsetDateTime, OffsetDateTime)>);
+    let (stay, set_stay) = create_signal(None::<(OffsetDateTime, OffsetDateTime)>);
+    let now = OffsetDateTime::now_utc();
+
     view! {
         <Article>
             <H1 id="date-and-time" class="anchor">
@@ -37,19 +48,310 @@ pub fn PageDateTime() -> impl IntoView {
 
             <DateSelector value=OffsetDateTime::now_utc() on_change=move |_v| {} guide_mode=GuideMode::YearFirst/>
 
+            <H2 id="locales" class="anchor">
+                "Locales"
+                <AnchorLink href="#locales" description="Direct link to section: Locales"/>
+            </H2>
+
+            <P>
+                "Pass a "<Code inline=true>"CalendarLocale"</Code>" to decide on which weekday weeks start and in which language months and weekdays are named. "
+                "Locales for English, British English, German, French, Spanish, Italian, Dutch, Portuguese, Polish, Swedish and Danish are bundled, "
+                "so that the server renders exactly what the browser renders. Use "<Code inline=true>"CalendarLocale::find"</Code>" "
+                "to pick one by a language tag like \"de-AT\", or describe your own locale. Set "<Code inline=true>"show_week_numbers"</Code>" "
+                "to show the ISO 8601 number of each week."
+            </P>
+
+            <Code>
+                {indoc!(r"
+                    <DateSelector value=OffsetDateTime::now_utc() on_change=move |_v| {} locale=CalendarLocale::DE show_week_numbers=true/>
+                ")}
+            </Code>
+
+            <DateSelector value=OffsetDateTime::now_utc() on_change=move |_v| {} locale=CalendarLocale::DE show_week_numbers=true/>
+
+            <P>
+                "Week rows of a locale starting weeks on Sunday, like "<Code inline=true>"CalendarLocale::EN"</Code>", "
+                "show the ISO week their Monday to Saturday belong to."
+            </P>
+
+            <H2 id="disabled-and-decorated-days" class="anchor">
+                "Disabled and decorated days"
+                <AnchorLink href="#disabled-and-decorated-days" description="Direct link to section: Disabled and decorated days"/>
+            </H2>
+
+            <P>
+                "Besides "<Code inline=true>"min"</Code>" and "<Code inline=true>"max"</Code>", an "<Code inline=true>"is_date_disabled"</Code>" "
+                "predicate disables individual days, e.g. weekends, holidays or fully booked days. Signals read by the predicate are tracked, "
+                "so that days are disabled as soon as your data changes. Use "<Code inline=true>"decorate_day"</Code>" to add CSS classes, "
+                "a small badge or a tooltip to individual days. Both are available on every date selector and date input."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    <DateSelector
+                        value=OffsetDateTime::now_utc()
+                        on_change=move |_v| {}
+                        is_date_disabled=move |date: Date| matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+                        decorate_day=move |date: Date| match date.day() % 5 {
+                            0 => DayDecoration { badge: Some("1 left".into()), title: Some("Almost booked out".into()), ..Default::default() },
+                            _ => DayDecoration::default(),
+                        }
+                    />
+                "#)}
+            </Code>
+
+            <DateSelector
+                value=OffsetDateTime::now_utc()
+                on_change=move |_v| {}
+                is_date_disabled=move |date: Date| matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+                decorate_day=move |date: Date| match date.day() % 5 {
+                    0 => DayDecoration { badge: Some("1 left".into()), title: Some("Almost booked out".into()), ..Default::default() },
+                    _ => DayDecoration::default(),
+                }
+            />
+
+            <P>
+                "Ranges never contain disabled days: Selecting the end of a range beyond a disabled day starts a new range instead. "
+                "Presets containing disabled days cannot be selected."
+            </P>
+
+            <H2 id="time-selector" class="anchor">
+                "Time selector"
+                <AnchorLink href="#time-selector" description="Direct link to section: Time selector"/>
+            </H2>
+
+            <P>
+                "Select times using the "<Code inline=true>"<TimeSelector>"</Code>" component. It changes the time of its value, keeping the date. "
+                "Hours, minutes and optionally seconds are spin buttons: Use the arrows or the arrow keys to step them, "
+                "Home and End to jump to their lowest or highest value, or simply type a number."
+            </P>
+
+            <Code>
+                {indoc!(r"
+                    <TimeSelector value=time on_change=set_time/>
+                ")}
+            </Code>
+
+            <TimeSelector value=time on_change=set_time/>
+
+            <P>"Selected: " {move || format!("{:02}:{:02}", time.get().hour(), time.get().minute())}</P>
+
+            <P>
+                "Use "<Code inline=true>"hour_cycle=HourCycle::H12"</Code>" to show hours from 1 to 12 followed by an AM/PM period. "
+                "Set a "<Code inline=true>"minute_step"</Code>" to only offer slots such as quarter hours. Typed minutes are rounded to the nearest slot."
+            </P>
+
+            <Code>
+                {indoc!(r"
+                    <TimeSelector value=time on_change=set_time hour_cycle=HourCycle::H12 minute_step=15 show_seconds=true/>
+                ")}
+            </Code>
+
+            <TimeSelector value=time on_change=set_time hour_cycle=HourCycle::H12 minute_step=15 show_seconds=true/>
+
             <H2 id="input-fields" class="anchor">
                 "Input fields"
                 <AnchorLink href="#input-fields" description="Direct link to section: Input fields"/>
             </H2>
 
-            <P>"Selecting dates and times through input fields will be supported soon. Stay tuned."</P>
+            <P>
+                "The "<Code inline=true>"<DateTimeInput>"</Code>" shows its value in an input and opens the selectors when clicked. "
+                "Its "<Code inline=true>"input_type"</Code>" decides whether to select a "<Code inline=true>"Type::Date"</Code>", "
+                "a "<Code inline=true>"Type::Time"</Code>" or both, using "<Code inline=true>"Type::DateTime"</Code>". "
+                "The time selector options from above are available on the input as well."
+            </P>
+
+            <P>
+                "The value always lies between "<Code inline=true>"min"</Code>" and "<Code inline=true>"max"</Code>", including its time. "
+                "Selecting the day of "<Code inline=true>"max"</Code>" while a later time is set moves the time back to the time of "<Code inline=true>"max"</Code>"."
+            </P>
+
+            <Code>
+                {indoc!(r"
+                    <DateTimeInput
+                        get=appointment
+                        set=set_appointment
+                        input_type=Type::DateTime
+                        minute_step=15
+                        min=now
+                        max=now + Duration::days(30)
+                    />
+                ")}
+            </Code>
+
+            <DateTimeInput
+                get=appointment
+                set=set_appointment
+                input_type=Type::DateTime
+                minute_step=15
+                min=now
+                max=now + Duration::days(30)
+            />
+
+            <Code>
+                {indoc!(r"
+                    <DateTimeInput get=alarm set=set_alarm input_type=Type::Time hour_cycle=HourCycle::H12/>
+                ")}
+            </Code>
+
+            <DateTimeInput get=alarm set=set_alarm input_type=Type::Time hour_cycle=HourCycle::H12/>
+
+            <H2 id="typed-entry" class="anchor">
+                "Typed entry"
+                <AnchorLink href="#typed-entry" description="Direct link to section: Typed entry"/>
+            </H2>
+
+            <P>
+                "Set "<Code inline=true>"entry"</Code>" to "<Code inline=true>"DateTimeEntry::Segments"</Code>" to type the value instead of selecting it. "
+                "Every part of the value is a spin button of its own: Type its digits, step it with the arrow keys or jump to its ends with Home and End. "
+                "The focus moves on to the next part once a part is filled. AM/PM periods take \"a\" and \"p\"."
+            </P>
+
+            <P>
+                "The parts and their order follow the "<Code inline=true>"format"</Code>", a "<Code inline=true>"time"</Code>" format description "
+                "using the components year, month, day, hour, minute, second and period. Write "<Code inline=true>"[hour repr:12]"</Code>" for a 12-hour clock. "
+                "Without a format, the parts match the "<Code inline=true>"input_type"</Code>". "
+                "Entries not forming a valid date, e.g. February 30, or lying outside of "<Code inline=true>"min"</Code>" and "<Code inline=true>"max"</Code>" are marked as invalid and set the value to "<Code inline=true>"None"</Code>"."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    <DateTimeInput
+                        get=birthday
+                        set=set_birthday
+                        input_type=Type::Date
+                        entry=DateTimeEntry::Segments
+                        format="[day].[month].[year]"
+                        max=now
+                    />
+                "#)}
+            </Code>
+
+            <DateTimeInput
+                get=birthday
+                set=set_birthday
+                input_type=Type::Date
+                entry=DateTimeEntry::Segments
+                format="[day].[month].[year]"
+                max=now
+            />
+
+            <P>
+                "Use "<Code inline=true>"DateTimeEntry::SegmentsAndPopup"</Code>" to additionally offer the selectors through a button next to the segments."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    <DateTimeInput
+                        get=meeting
+                        set=set_meeting
+                        input_type=Type::DateTime
+                        entry=DateTimeEntry::SegmentsAndPopup
+                        format="[month]/[day]/[year] [hour repr:12]:[minute] [period]"
+                        hour_cycle=HourCycle::H12
+                        minute_step=15
+                    />
+                "#)}
+            </Code>
+
+            <DateTimeInput
+                get=meeting
+                set=set_meeting
+                input_type=Type::DateTime
+                entry=DateTimeEntry::SegmentsAndPopup
+                format="[month]/[day]/[year] [hour repr:12]:[minute] [period]"
+                hour_cycle=HourCycle::H12
+                minute_step=15
+            />
+
+            <H2 id="date-ranges" class="anchor">
+                "Date ranges"
+                <AnchorLink href="#date-ranges" description="Direct link to section: Date ranges"/>
+            </H2>
+
+            <P>
+                "Select a range of days using the "<Code inline=true>"<DateRangeSelector>"</Code>" component. "
+                "The first click selects the start of the range, the second click its end. While selecting the end, "
+                "the days up to the hovered day are highlighted. Both days of the range are included and emitted at midnight."
+            </P>
+
+            <P>
+                "Offer common ranges through "<Code inline=true>"presets"</Code>". Presets are evaluated whenever they are shown, "
+                "so that ranges like the last seven days always end today. Set "<Code inline=true>"two_months"</Code>" "
+                "to show the following month next to the current one."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    <DateRangeSelector
+                        value=None
+                        on_change=move |(start, end)| set_range.set(Some((start, end)))
+                        presets=vec![
+                            DateRangePreset::last_days("Last 7 days", 7),
+                            DateRangePreset::last_days("Last 30 days", 30),
+                            DateRangePreset::this_month("This month"),
+                        ]
+                        two_months=true
+                    />
+                "#)}
+            </Code>
+
+            <DateRangeSelector
+                value=None
+                on_change=move |(start, end)| set_range.set(Some((start, end)))
+                presets=vec![
+                    DateRangePreset::last_days("Last 7 days", 7),
+                    DateRangePreset::last_days("Last 30 days", 30),
+                    DateRangePreset::this_month("This month"),
+                ]
+                two_months=true
+            />
+
+            <P>"Selected: " {move || format!("{:?}", range.get().map(|(start, end)| (start.date(), end.date())))}</P>
+
+            <P>
+                "The "<Code inline=true>"<DateRangeInput>"</Code>" shows a range in an input and opens a range selector when clicked. "
+                "Press Backspace or Delete on the input to clear the range."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    <DateRangeInput
+                        get=stay
+                        set=set_stay
+                        min=now
+                        presets=vec![DateRangePreset::new("Next weekend", next_weekend)]
+                        label="Stay"
+                    />
+                "#)}
+            </Code>
+
+            <DateRangeInput
+                get=stay
+                set=set_stay
+                min=now
+                presets=vec![DateRangePreset::new("Next weekend", next_weekend)]
+                label="Stay"
+            />
         </Article>
 
         <Toc toc=Toc::List {
             inner: vec![
                 Toc::Leaf { title: "Date & Time", link: "#date-and-time" },
+                Toc::Leaf { title: "Locales", link: "#locales" },
+                Toc::Leaf { title: "Disabled and decorated days", link: "#disabled-and-decorated-days" },
+                Toc::Leaf { title: "Time selector", link: "#time-selector" },
                 Toc::Leaf { title: "Input fields", link: "#input-fields" },
+                Toc::Leaf { title: "Typed entry", link: "#typed-entry" },
+                Toc::Leaf { title: "Date ranges", link: "#date-ranges" },
             ]
         }/>
     }
 }
+
+/// Saturday and Sunday of the coming weekend.
+fn next_weekend() -> (Date, Date) {
+    let today = OffsetDateTime::now_utc().date();
+    let saturday = today.next_occurrence(Weekday::Saturday);
+    (saturday, saturday.next_day().unwrap_or(saturday))
+}
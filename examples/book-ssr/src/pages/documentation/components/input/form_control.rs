@@ -0,0 +1,248 @@
+use indoc::indoc;
+use leptonic::{atoms::link::AnchorLink, components::prelude::*, hooks::*, prelude::*};
+use leptos::*;
+
+use crate::pages::documentation::{article::Article, toc::Toc};
+
+const TAKEN_USERNAMES: [&str; 3] = ["admin", "root", "leptonic"];
+
+#[component]
+#[allow(clippy::too_many_lines)]
+pub fn PageFormControl() -> impl IntoView {
+    let (submitted, set_submitted) = create_signal(None::<String>);
+
+    let UseFormReturn {
+        props: form_props,
+        form,
+    } = use_form(UseFormInput {
+        on_submit: SubmitHandler::new(move || async move {
+            set_submitted.set(Some(String::from("Welcome aboard!")));
+        }),
+    });
+
+    let username = use_form_field(UseFormFieldInput {
+        form,
+        initial: String::new(),
+        validators: vec![
+            Validator::required("Please choose a username."),
+            Validator::new(|name: &String| match name.len() < 3 {
+                true => Err("Usernames must be at least 3 characters long.".into()),
+                false => Ok(()),
+            }),
+        ],
+        async_validators: vec![AsyncValidator::new(|name: String| async move {
+            match TAKEN_USERNAMES.contains(&name.as_str()) {
+                true => Err("This username is already taken.".into()),
+                false => Ok(()),
+            }
+        })],
+        async_debounce_ms: 300.0,
+    });
+
+    let terms = use_form_field(UseFormFieldInput {
+        form,
+        initial: false,
+        validators: vec![Validator::required("You must accept the terms.")],
+        async_validators: vec![],
+        async_debounce_ms: 0.0,
+    });
+
+    view! {
+        <Article>
+            <H1 id="form-control" class="anchor">
+                "Form control"
+                <AnchorLink href="#form-control" description="Direct link to article header"/>
+            </H1>
+
+            <P>
+                "A "<Code inline=true>"<FormControl>"</Code>" groups an input with its "<Code inline=true>"<Label>"</Code>". "
+                "Pressing the label focuses the input."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    <FormControl>
+                        <Label>"Username"</Label>
+                        <TextInput get=text set=set_text/>
+                    </FormControl>
+                "#)}
+            </Code>
+
+            <H2 id="forms" class="anchor">
+                "Forms"
+                <AnchorLink href="#forms" description="Direct link to section: Forms"/>
+            </H2>
+
+            <P>
+                "Use the "<Code inline=true>"use_form"</Code>" hook to manage the state of a form. "
+                "Every field of the form is created with "<Code inline=true>"use_form_field"</Code>", holding a typed value "
+                "and the validators checking it. Fields keep track of whether they were touched or changed by the user."
+            </P>
+
+            <P>
+                "Pass the "<Code inline=true>"status()"</Code>" of a field to a "<Code inline=true>"<FormControl>"</Code>" "
+                "and the contained "<Code inline=true>"TextInput"</Code>", "<Code inline=true>"NumberInput"</Code>", "
+                <Code inline=true>"Checkbox"</Code>", "<Code inline=true>"Select"</Code>" or "<Code inline=true>"DateTimeInput"</Code>" "
+                "will be marked as invalid, referencing the first error of the field through "<Code inline=true>"aria-errormessage"</Code>". "
+                "Errors are shown once the user left the input or tried to submit the form."
+            </P>
+
+            <P>
+                "Submitting the form runs all validators. Your "<Code inline=true>"on_submit"</Code>" handler is only called when every field is valid."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    let UseFormReturn { props: form_props, form } = use_form(UseFormInput {
+                        on_submit: SubmitHandler::new(move || async move {
+                            // Send the values to your server.
+                        }),
+                    });
+
+                    let username = use_form_field(UseFormFieldInput {
+                        form,
+                        initial: String::new(),
+                        validators: vec![
+                            Validator::required("Please choose a username."),
+                            Validator::new(|name: &String| match name.len() < 3 {
+                                true => Err("Usernames must be at least 3 characters long.".into()),
+                                false => Ok(()),
+                            }),
+                        ],
+                        async_validators: vec![AsyncValidator::new(|name: String| async move {
+                            match is_taken(&name).await {
+                                true => Err("This username is already taken.".into()),
+                                false => Ok(()),
+                            }
+                        })],
+                        async_debounce_ms: 300.0,
+                    });
+
+                    let terms = use_form_field(UseFormFieldInput {
+                        form,
+                        initial: false,
+                        validators: vec![Validator::required("You must accept the terms.")],
+                        async_validators: vec![],
+                        async_debounce_ms: 0.0,
+                    });
+
+                    view! {
+                        <form {..form_props.attrs} on:submit=form_props.on_submit on:reset=form_props.on_reset>
+                            <FormControl field=username.status()>
+                                <Label>"Username"</Label>
+                                <TextInput get=username.value() set=username.value()/>
+                            </FormControl>
+
+                            <FormControl field=terms.status()>
+                                <Stack orientation=StackOrientation::Horizontal spacing=Size::Em(0.5)>
+                                    <Checkbox checked=terms.value() set_checked=terms.value()/>
+                                    <Label>"I accept the terms"</Label>
+                                </Stack>
+                            </FormControl>
+
+                            // Buttons submit their surrounding form. Use `form.submit()` to submit from anywhere else.
+                            <Button on_press=move |_| {} disabled=form.is_submitting()>"Register"</Button>
+                        </form>
+                    }
+                "#)}
+            </Code>
+
+            <form {..form_props.attrs} on:submit=form_props.on_submit on:reset=form_props.on_reset>
+                <Stack spacing=Size::Em(1.0)>
+                    <FormControl field=username.status()>
+                        <Label>"Username"</Label>
+                        <TextInput get=username.value() set=username.value()/>
+                    </FormControl>
+
+                    <FormControl field=terms.status()>
+                        <Stack orientation=StackOrientation::Horizontal spacing=Size::Em(0.5)>
+                            <Checkbox checked=terms.value() set_checked=terms.value()/>
+                            <Label>"I accept the terms"</Label>
+                        </Stack>
+                    </FormControl>
+
+                    <Button on_press=move |_| {} disabled=form.is_submitting()>"Register"</Button>
+                </Stack>
+            </form>
+
+            <P>"valid: " {move || form.is_valid().get()} ", dirty: " {move || form.is_dirty().get()} ", submit attempts: " {move || form.submit_count().get()}</P>
+            <P>{move || submitted.get()}</P>
+
+            <P>
+                "Try \"admin\" as a username to see the async validator in action. Async validators only run when all synchronous validators "
+                "accepted the value, and results of outdated runs are discarded."
+            </P>
+
+            <H2 id="native-forms" class="anchor">
+                "Native forms"
+                <AnchorLink href="#native-forms" description="Direct link to section: Native forms"/>
+            </H2>
+
+            <P>
+                "Inputs can also be submitted with a native "<Code inline=true>"<form>"</Code>" or an "<Code inline=true>"<ActionForm>"</Code>" "
+                "calling a server function. Give them a "<Code inline=true>"name"</Code>" and they render their current value into the form. "
+                "Components not based on a native input, like "<Code inline=true>"Select"</Code>", "<Code inline=true>"Toggle"</Code>", "
+                <Code inline=true>"Checkbox"</Code>", "<Code inline=true>"Slider"</Code>" or "<Code inline=true>"ColorPicker"</Code>", "
+                "render hidden inputs for that. As these are also rendered on the server, forms work even before your app is hydrated."
+            </P>
+
+            <P>
+                "Options of a select are submitted using their search text, unless you provide a "<Code inline=true>"form_value"</Code>" serializing them. "
+                "A "<Code inline=true>"Multiselect"</Code>" submits one value per selected option and a "<Code inline=true>"RangeSlider"</Code>" submits both of its values, "
+                "just like a native "<Code inline=true>"<select multiple>"</Code>" would. Checkboxes and toggles are only submitted when checked, using the value \"on\" "
+                "unless configured otherwise."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    <ActionForm action=register>
+                        <TextInput name="username" get=username set=set_username/>
+                        <Select
+                            name="role"
+                            form_value=move |role: Role| role.id().to_string()
+                            options=roles
+                            selected=role
+                            set_selected=set_role
+                            search_text_provider=move |role: Role| role.name().to_string()
+                            render_option=move |role: Role| role.name().to_string()
+                        />
+                        <Multiselect name="tags" options=tags selected=selected_tags set_selected=set_selected_tags
+                            search_text_provider=move |tag| tag
+                            render_option=move |tag| tag
+                        />
+                        <Checkbox name="newsletter" checked=newsletter set_checked=set_newsletter/>
+                        <RangeSlider name="budget" value_a=budget_min value_b=budget_max
+                            set_value_a=set_budget_min set_value_b=set_budget_max
+                            min=0.0 max=1000.0
+                        />
+                        <button type="submit">"Register"</button>
+                    </ActionForm>
+                "#)}
+            </Code>
+
+            <H2 id="styling" class="anchor">
+                "Styling"
+                <AnchorLink href="#styling" description="Direct link to section: Styling"/>
+            </H2>
+
+            <P>"You may overwrite any of the following CSS variables to meet your styling needs."</P>
+
+            <Code>
+                {indoc!(r"
+                    --validation-error-color
+                    --validation-msg-margin-top
+                    --validation-msg-font-size
+                ")}
+            </Code>
+        </Article>
+
+        <Toc toc=Toc::List {
+            inner: vec![
+                Toc::Leaf { title: "Form control", link: "#form-control" },
+                Toc::Leaf { title: "Forms", link: "#forms" },
+                Toc::Leaf { title: "Native forms", link: "#native-forms" },
+                Toc::Leaf { title: "Styling", link: "#styling" },
+            ]
+        }/>
+    }
+}
@@ -1,6 +1,7 @@
 pub mod button;
 pub mod checkbox;
 pub mod color_picker;
+pub mod combobox;
 pub mod date_time;
 pub mod form_control;
 pub mod input_field;
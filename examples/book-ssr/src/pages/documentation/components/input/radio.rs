@@ -4,12 +4,19 @@ use leptos::*;
 
 use crate::pages::documentation::{article::Article, toc::Toc};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fruit {
+    Apple,
+    Banana,
+    Cherry,
+}
+
 #[component]
 pub fn PageRadio() -> impl IntoView {
-    let (checked, set_checked) = create_signal(false);
-    let (checked2, set_checked2) = create_signal(false);
-    let (checked3, set_checked3) = create_signal(false);
-    let (checked_disabled, set_checked_disabled) = create_signal(false);
+    let (selected, set_selected) = create_signal(Option::<Fruit>::None);
+    let (selected2, set_selected2) = create_signal(Some("a"));
+    let (selected_disabled, set_selected_disabled) = create_signal(Some(1));
+    let (group_disabled, set_group_disabled) = create_signal(false);
 
     view! {
         <Article>
@@ -18,78 +25,110 @@ pub fn PageRadio() -> impl IntoView {
                 <AnchorLink href="#radio" description="Direct link to article header"/>
             </H1>
 
-            <P>"Radio..."</P>
+            <P>
+                "Radio buttons let users select exactly one option of a set. "
+                "The " <Code inline=true>"RadioGroup"</Code> " owns the selected value, each " <Code inline=true>"Radio"</Code> " represents one possible value. "
+                "No radio is selected while the value is " <Code inline=true>"None"</Code> "."
+            </P>
 
             <Code>
                 {indoc!(r"
-                    let (checked, set_checked) = create_signal(false);
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                    enum Fruit {
+                        Apple,
+                        Banana,
+                        Cherry,
+                    }
+
+                    let (selected, set_selected) = create_signal(Option::<Fruit>::None);
                     view! {
-                        <Radio checked=checked set_checked=set_checked />
+                        <RadioGroup value=selected set_value=set_selected>
+                            <Radio value=Fruit::Apple />
+                            <Radio value=Fruit::Banana />
+                            <Radio value=Fruit::Cherry />
+                        </RadioGroup>
                     }
                 ")}
             </Code>
 
-            <Radio checked=checked set_checked=set_checked />
+            <RadioGroup value=selected set_value=set_selected>
+                <Radio value=Fruit::Apple />
+                <Radio value=Fruit::Banana />
+                <Radio value=Fruit::Cherry />
+            </RadioGroup>
 
-            <span>"checked: " {move || checked.get()}</span>
+            <span>"selected: " {move || format!("{:?}", selected.get())}</span>
 
-            <H2 id="radio-groups" class="anchor">
-                "Radio groups"
-                 <AnchorLink href="#radio-groups" description="Direct link to section: Radio groups"/>
+            <H2 id="keyboard-navigation" class="anchor">
+                "Keyboard navigation"
+                <AnchorLink href="#keyboard-navigation" description="Direct link to section: Keyboard navigation"/>
             </H2>
 
-            <Code>
-                {indoc!(r"
-                    <RadioGroup>
-                        <Radio checked=checked2 set_checked=set_checked2 />
-                        <Radio checked=checked3 set_checked=set_checked3 />
-                    </RadioGroup>
-                ")}
-            </Code>
-
-            <RadioGroup>
-                <Radio checked=checked2 set_checked=set_checked2 />
-                <Radio checked=checked3 set_checked=set_checked3 />
-            </RadioGroup>
+            <P>
+                "A radio group is a single tab stop: Tab moves focus to the selected radio, or to the first one if none is selected. "
+                "Arrow keys move focus and selection to the next or previous enabled radio, wrapping around at the ends of the group. "
+                "Set " <Code inline=true>"writing_direction"</Code> " to " <Code inline=true>"WritingDirection::Rtl"</Code> " to swap the meaning of ArrowLeft and ArrowRight in right-to-left layouts."
+            </P>
 
             <H2 id="labeled" class="anchor">
                 "Labeled"
                 <AnchorLink href="#labeled" description="Direct link to section: Labeled"/>
             </H2>
 
-            <P>"Wrap an input and a label to link them together."</P>
+            <P>"Wrap a radio and a label to link them together. Pressing the label selects the radio."</P>
 
             <Code>
                 {indoc!(r#"
-                    <FormControl>
-                        <Radio checked=checked set_checked=set_checked />
-                        <Label>"Label"</Label>
-                    </FormControl>
+                    <RadioGroup value=selected set_value=set_selected>
+                        <FormControl>
+                            <Radio value="a" />
+                            <Label>"Option A"</Label>
+                        </FormControl>
+                        <FormControl>
+                            <Radio value="b" />
+                            <Label>"Option B"</Label>
+                        </FormControl>
+                    </RadioGroup>
                 "#)}
             </Code>
 
-            <FormControl>
-                <Radio checked=checked set_checked=set_checked />
-                <Label>
-                    "Label"
-                </Label>
-            </FormControl>
+            <RadioGroup value=selected2 set_value=set_selected2>
+                <FormControl style="display: flex; flex-direction: row; align-items: center;">
+                    <Radio value="a" />
+                    <Label style="margin-left: 0.25em;">"Option A"</Label>
+                </FormControl>
+                <FormControl style="display: flex; flex-direction: row; align-items: center;">
+                    <Radio value="b" />
+                    <Label style="margin-left: 0.25em;">"Option B"</Label>
+                </FormControl>
+            </RadioGroup>
 
             <H2 id="disabled" class="anchor">
                 "Disabled"
                 <AnchorLink href="#disabled" description="Direct link to section: Disabled"/>
             </H2>
 
-            <P>"Radio buttons support the " <Code inline=true>"disabled"</Code> " property, making them unmodifiable if set true."</P>
+            <P>
+                "Single radios as well as whole groups support the " <Code inline=true>"disabled"</Code> " property. "
+                "Disabled radios can not be selected and are skipped during keyboard navigation."
+            </P>
 
             <Code>
                 {indoc!(r"
-                    <Radio disabled=true checked=checked set_checked=set_checked />
+                    <RadioGroup disabled=group_disabled value=selected set_value=set_selected>
+                        <Radio value=1 />
+                        <Radio value=2 disabled=true />
+                        <Radio value=3 />
+                    </RadioGroup>
                 ")}
             </Code>
 
-            <Radio disabled=true checked=checked_disabled set_checked=set_checked_disabled />
-            <Button variant=ButtonVariant::Flat color=ButtonColor::Secondary size=ButtonSize::Small on_press=move |_| set_checked_disabled.set(!checked_disabled.get_untracked())>"TOGGLE"</Button>
+            <RadioGroup disabled=group_disabled value=selected_disabled set_value=set_selected_disabled>
+                <Radio value=1 />
+                <Radio value=2 disabled=true />
+                <Radio value=3 />
+            </RadioGroup>
+            <Button variant=ButtonVariant::Flat color=ButtonColor::Secondary size=ButtonSize::Small on_press=move |_| set_group_disabled.set(!group_disabled.get_untracked())>"TOGGLE"</Button>
 
             <H2 id="styling" class="anchor">
                 "Styling"
@@ -117,7 +156,7 @@ pub fn PageRadio() -> impl IntoView {
         <Toc toc=Toc::List {
             inner: vec![
                 Toc::Leaf { title: "Radio", link: "#radio" },
-                Toc::Leaf { title: "Groups", link: "#radio-groups" },
+                Toc::Leaf { title: "Keyboard navigation", link: "#keyboard-navigation" },
                 Toc::Leaf { title: "Labeled", link: "#labeled" },
                 Toc::Leaf { title: "Disabled", link: "#disabled" },
                 Toc::Leaf { title: "Styling", link: "#styling" },
@@ -1,5 +1,5 @@
 use indoc::indoc;
-use leptonic::{atoms::link::AnchorLink, components::prelude::*};
+use leptonic::{atoms::link::AnchorLink, components::prelude::*, hooks::*};
 use leptos::*;
 
 use crate::pages::documentation::{article::Article, toc::Toc};
@@ -54,6 +54,20 @@ pub fn PageSelect() -> impl IntoView {
 
     let (selected_user, set_selected_user) = create_signal(selectable_users[0].clone());
 
+    let many_numbers = (1..=10_000).collect::<Vec<u32>>();
+    let (selected_number, set_selected_number) = create_signal(1_u32);
+
+    let (selected_loaded, set_selected_loaded) = create_signal(Option::<u32>::None);
+
+    let (foods, set_foods) = create_signal(vec![
+        ("Apple".to_owned(), "Fruits"),
+        ("Banana".to_owned(), "Fruits"),
+        ("Carrot".to_owned(), "Vegetables"),
+        ("Durian".to_owned(), "Fruits"),
+        ("Eggplant".to_owned(), "Vegetables"),
+    ]);
+    let (selected_foods, set_selected_foods) = create_signal(vec![("Apple".to_owned(), "Fruits")]);
+
     view! {
         <Article>
             <H1 id="select" class="anchor">
@@ -204,6 +218,11 @@ pub fn PageSelect() -> impl IntoView {
                 "Close the dropdown by pressing "<Code inline=true>"Escape"</Code>"."
             </P>
 
+            <P>
+                "While the search input does not have focus, "<Code inline=true>"Home"</Code>" and "<Code inline=true>"End"</Code>" preselect the first and last option. "
+                "Typing characters in quick succession preselects the next option starting with them, opening the dropdown if necessary."
+            </P>
+
             <P>
                 "Select options can be searched. When opening the dropdown of available options, focus will automatically jump to the search input, allowing you to type instantly. "
                 "When closing the dropdown, focus is automatically restored to the select, allowing you to "<Code inline=true>"Tab"</Code>" to the next element."
@@ -266,6 +285,224 @@ pub fn PageSelect() -> impl IntoView {
                 set_selected=move |v| set_selected_user.set(v)
             />
 
+            <H2 id="many-options" class="anchor">
+                "Many options"
+                <AnchorLink href="#many-options" description="Direct link to section: Many options"/>
+            </H2>
+
+            <P>
+                "Rendering thousands of options at once slows down opening the dropdown. "
+                "Set the " <Code inline=true>"virtualize"</Code> " prop to only render the options currently visible. "
+                "Options not yet rendered are assumed to have the given estimated height. They are measured once they become visible. "
+                "Use " <Code inline=true>"VirtualItemSize::Fixed"</Code> " instead if all options are known to have the same height."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    let options = (1..=10_000).collect::<Vec<u32>>();
+                    let (selected, set_selected) = create_signal(1_u32);
+
+                    view! {
+                        <Select
+                            options=options
+                            search_text_provider=move |o: u32| o.to_string()
+                            render_option=move |o: u32| format!("Option {o}")
+                            selected=selected
+                            set_selected=move |v| set_selected.set(v)
+                            virtualize=VirtualItemSize::Estimated(36.0)
+                        />
+                    }
+                "#)}
+            </Code>
+
+            <Select
+                options=many_numbers
+                search_text_provider=move |o: u32| o.to_string()
+                render_option=move |o: u32| format!("Option {o}")
+                selected=selected_number
+                set_selected=move |v| set_selected_number.set(v)
+                virtualize=VirtualItemSize::Estimated(36.0)
+            />
+
+            <H2 id="loading-options" class="anchor">
+                "Loading options"
+                <AnchorLink href="#loading-options" description="Direct link to section: Loading options"/>
+            </H2>
+
+            <P>
+                "When there are too many options to know them all up front, e.g. when they are stored in a database, "
+                "provide a " <Code inline=true>"loader"</Code> " instead of " <Code inline=true>"options"</Code> ". "
+                "It is called with the current search once the options are shown, and again whenever the search changes. "
+                "Calls are debounced by " <Code inline=true>"search_debounce"</Code> " milliseconds, defaulting to 300, "
+                "and a loading indicator is shown until the options arrive."
+            </P>
+
+            <P>
+                "Options are loaded in pages of " <Code inline=true>"page_size"</Code> " options, defaulting to 50. "
+                "The next page is requested once the user scrolls to the end of the options. "
+                "Return fewer options than the " <Code inline=true>"limit"</Code> " of the query to signal that no further options exist."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    let (selected, set_selected) = create_signal(Option::<u32>::None);
+
+                    view! {
+                        <OptionalSelect
+                            loader=OptionsLoader::new(move |query: OptionsQuery| async move {
+                                // Ask your server for the options matching `query.search`,
+                                // skipping `query.offset` options and returning at most `query.limit` options.
+                                (1..=1_000_000_u32)
+                                    .filter(|o| o.to_string().contains(&query.search))
+                                    .skip(query.offset)
+                                    .take(query.limit)
+                                    .collect()
+                            })
+                            search_text_provider=move |o: u32| o.to_string()
+                            render_option=move |o: u32| format!("Option {o}")
+                            selected=selected
+                            set_selected=move |v| set_selected.set(v)
+                            allow_deselect=true
+                        />
+                    }
+                "#)}
+            </Code>
+
+            <OptionalSelect
+                loader=OptionsLoader::new(move |query: OptionsQuery| async move {
+                    (1..=1_000_000_u32)
+                        .filter(|o| o.to_string().contains(&query.search))
+                        .skip(query.offset)
+                        .take(query.limit)
+                        .collect()
+                })
+                search_text_provider=move |o: u32| o.to_string()
+                render_option=move |o: u32| format!("Option {o}")
+                selected=selected_loaded
+                set_selected=move |v| set_selected_loaded.set(v)
+                allow_deselect=true
+            />
+
+            <H2 id="groups-disabled-and-creatable-options" class="anchor">
+                "Groups, disabled and creatable options"
+                <AnchorLink href="#groups-disabled-and-creatable-options" description="Direct link to section: Groups, disabled and creatable options"/>
+            </H2>
+
+            <P>
+                "Provide a " <Code inline=true>"group_provider"</Code> " to show options below a header naming their group. "
+                "Options are ordered by group, keeping the order in which groups first appear. Options without a group are shown first, without a header."
+            </P>
+
+            <P>
+                "Options for which the " <Code inline=true>"disabled_provider"</Code> " returns true are shown, but can not be selected. "
+                "Keyboard navigation skips them."
+            </P>
+
+            <P>
+                "With " <Code inline=true>"on_create"</Code> " set, the user may create a new option from the search text whenever no option matches it exactly. "
+                "The search text is emitted through the callback, either when pressing the \"Create\" entry or when pressing Enter with no option preselected. "
+                "It is up to you to add the new option and to select it."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    let (foods, set_foods) = create_signal(vec![
+                        ("Apple".to_owned(), "Fruits"),
+                        ("Banana".to_owned(), "Fruits"),
+                        ("Carrot".to_owned(), "Vegetables"),
+                        ("Durian".to_owned(), "Fruits"),
+                        ("Eggplant".to_owned(), "Vegetables"),
+                    ]);
+                    let (selected, set_selected) = create_signal(vec![("Apple".to_owned(), "Fruits")]);
+
+                    view! {
+                        <Multiselect
+                            options=foods
+                            search_text_provider=move |(name, _group): (String, &str)| name
+                            render_option=move |(name, _group): (String, &str)| name
+                            group_provider=move |(_name, group): (String, &str)| Some(group.to_owned())
+                            disabled_provider=move |(name, _group): (String, &str)| name == "Durian"
+                            on_create=move |name: String| {
+                                let food = (name, "Custom");
+                                set_foods.update(|foods| foods.push(food.clone()));
+                                set_selected.update(|selected| selected.push(food));
+                            }
+                            selected=selected
+                            set_selected=move |v| set_selected.set(v)
+                        />
+                    }
+                "#)}
+            </Code>
+
+            <Multiselect
+                options=foods
+                search_text_provider=move |(name, _group): (String, &str)| name
+                render_option=move |(name, _group): (String, &str)| name
+                group_provider=move |(_name, group): (String, &str)| Some(group.to_owned())
+                disabled_provider=move |(name, _group): (String, &str)| name == "Durian"
+                on_create=move |name: String| {
+                    let food = (name, "Custom");
+                    set_foods.update(|foods| foods.push(food.clone()));
+                    set_selected_foods.update(|selected| selected.push(food));
+                }
+                selected=selected_foods
+                set_selected=move |v| set_selected_foods.set(v)
+            />
+
+            <H2 id="building-your-own-select" class="anchor">
+                "Building your own select"
+                <AnchorLink href="#building-your-own-select" description="Direct link to section: Building your own select"/>
+            </H2>
+
+            <P>
+                "All select variants are built on the headless "<Code inline=true>"use_select"</Code>" hook. "
+                "It manages the search, the preselected option, typeahead and the selection, which can be a "<Code inline=true>"Single"</Code>", an "
+                <Code inline=true>"Optional"</Code>" or up to "<Code inline=true>"max"</Code>" "<Code inline=true>"Multiple"</Code>" options. "
+                "It returns the attributes marking your elements as a "<Code inline=true>"combobox"</Code>" controlling a "<Code inline=true>"listbox"</Code>", "
+                "while "<Code inline=true>"use_select_option"</Code>" provides the "<Code inline=true>"option"</Code>" role, "
+                <Code inline=true>"aria-selected"</Code>" and "<Code inline=true>"aria-disabled"</Code>" for every option. "
+                "The preselected option is announced through "<Code inline=true>"aria-activedescendant"</Code>", so that focus can stay on your trigger."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    let UseSelectReturn { props, listbox_props, state } = use_select(UseSelectInput {
+                        selection: SelectionModel::Single { selected, set_selected: set_selected.into() },
+                        options: options.into(),
+                        loader: None,
+                        search_debounce: 300.0,
+                        page_size: 50,
+                        search_text_provider: Consumer::new(move |o: Foo| o.to_string()),
+                        search_filter_provider: None,
+                        disabled_provider: None,
+                        group_provider: None,
+                        on_create: None,
+                        close_on_select: true,
+                    });
+
+                    view! {
+                        <div {..props.attrs} tabindex=0 on:keydown=props.on_key_down on:click=move |_| state.toggle()>
+                            { move || selected.get().to_string() }
+                        </div>
+                        <Show when=move || state.is_open() fallback=|| ()>
+                            <ul {..listbox_props.attrs.clone()}>
+                                { move || state.options().get().into_iter().map(|option| {
+                                    let UseSelectOptionReturn { props } = use_select_option(UseSelectOptionInput {
+                                        select: state,
+                                        option: option.clone(),
+                                    });
+                                    view! {
+                                        <li {..props.attrs} on:click=props.on_click on:mouseenter=props.on_mouse_enter>
+                                            { option.to_string() }
+                                        </li>
+                                    }
+                                }).collect_view() }
+                            </ul>
+                        </Show>
+                    }
+                "#)}
+            </Code>
+
             <H2 id="styling" class="anchor">
                 "Styling"
                 <AnchorLink href="#styling" description="Direct link to section: Styling"/>
@@ -292,6 +529,10 @@ pub fn PageSelect() -> impl IntoView {
                     --select-search-background-color
                     --select-no-items-color
                     --select-no-items-background-color
+                    --select-loading-color
+                    --select-group-color
+                    --select-group-font-size
+                    --select-create-color
                     --select-item-color
                     --select-item-background-color
                     --select-item-padding
@@ -314,6 +555,10 @@ pub fn PageSelect() -> impl IntoView {
                 ]},
                 Toc::Leaf { title: "Keyboard navigation", link: "#keyboard-navigation" },
                 Toc::Leaf { title: "Customization", link: "#customization" },
+                Toc::Leaf { title: "Many options", link: "#many-options" },
+                Toc::Leaf { title: "Loading options", link: "#loading-options" },
+                Toc::Leaf { title: "Groups, disabled and creatable options", link: "#groups-disabled-and-creatable-options" },
+                Toc::Leaf { title: "Building your own select", link: "#building-your-own-select" },
                 Toc::Leaf { title: "Styling", link: "#styling" },
             ]
         }/>
@@ -1,10 +1,12 @@
+use std::collections::HashSet;
+
 use indoc::indoc;
-use leptonic::{atoms::link::AnchorLink, components::prelude::*};
+use leptonic::{atoms::link::AnchorLink, components::prelude::*, create_signal_ls};
 use leptos::*;
 
 use crate::pages::documentation::{article::Article, toc::Toc};
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub struct Minion {
     id: u32,
     name: String,
@@ -12,6 +14,45 @@ pub struct Minion {
     num_eyes: u32,
 }
 
+/// Stands in for a server function loading the requested page of minions, e.g. from a database.
+fn load_minions(query: &TableQuery, minions: &[Minion]) -> (Vec<Minion>, u64) {
+    let mut matching = minions
+        .iter()
+        .filter(|minion| {
+            query
+                .filter("name")
+                .map_or(true, |name| minion.name.to_lowercase().contains(&name.to_lowercase()))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    matching.sort_by(|a, b| {
+        query
+            .sort
+            .iter()
+            .map(|sort| {
+                let ordering = match sort.column.as_str() {
+                    "id" => a.id.cmp(&b.id),
+                    "name" => a.name.cmp(&b.name),
+                    "num_eyes" => a.num_eyes.cmp(&b.num_eyes),
+                    _ => std::cmp::Ordering::Equal,
+                };
+                match sort.direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let total = matching.len() as u64;
+    let page = matching
+        .into_iter()
+        .skip(query.offset() as usize)
+        .take(query.limit() as usize)
+        .collect();
+    (page, total)
+}
+
 #[component]
 pub fn PageTable() -> impl IntoView {
     let minions = create_rw_signal(vec![
@@ -41,6 +82,38 @@ pub fn PageTable() -> impl IntoView {
         },
     ]);
 
+    let (selected_minions, set_selected_minions) = create_signal(HashSet::<u32>::new());
+
+    let columns = vec![
+        DataTableColumn::new("id", || "#", |minion: Minion| minion.id)
+            .sortable(|a: &Minion, b: &Minion| a.id.cmp(&b.id)),
+        DataTableColumn::new("name", || "Name", |minion: Minion| minion.name)
+            .sortable(|a: &Minion, b: &Minion| a.name.cmp(&b.name)),
+        DataTableColumn::new("appearance", || "Appearance", |minion: Minion| minion.appearance),
+        DataTableColumn::new("num_eyes", || "Num. eyes", |minion: Minion| minion.num_eyes)
+            .sortable(|a: &Minion, b: &Minion| a.num_eyes.cmp(&b.num_eyes)),
+    ];
+    let many_minions_columns = columns.clone();
+    let queried_minions_columns = columns.clone();
+    let arranged_minions_columns = columns.clone();
+    let (minions_layout, set_minions_layout) =
+        create_signal_ls("book-minions-column-layout", ColumnLayout::default());
+
+    let many_minions = (1..=10_000)
+        .map(|id| Minion {
+            id,
+            name: format!("Minion {id}"),
+            appearance: String::from("Yellow"),
+            num_eyes: id % 2 + 1,
+        })
+        .collect::<Vec<_>>();
+
+    let all_minions = store_value(many_minions.clone());
+    let (minions_query, set_minions_query) = create_signal(TableQuery::new(10));
+    let loaded_minions = create_memo(move |_| {
+        minions_query.with(|query| all_minions.with_value(|minions| load_minions(query, minions)))
+    });
+
     view! {
         <Article>
             <H1 id="table" class="anchor">
@@ -108,6 +181,217 @@ pub fn PageTable() -> impl IntoView {
                 </Table>
             </TableContainer>
 
+            <H2 id="data-tables" class="anchor">
+                "Data tables"
+                <AnchorLink href="#data-tables" description="Direct link to section: Data tables"/>
+            </H2>
+
+            <P>
+                "The " <Code inline=true>"DataTable"</Code> " renders a list of rows according to a list of column definitions. "
+                "Columns given a comparator become sortable by pressing their header. "
+                "Set a " <Code inline=true>"selection_mode"</Code> " to let users select one or multiple rows, identified by their key."
+            </P>
+
+            <Code>
+                {indoc!(r##"
+                    let (selected, set_selected) = create_signal(HashSet::<u32>::new());
+
+                    let columns = vec![
+                        DataTableColumn::new("id", || "#", |minion: Minion| minion.id)
+                            .sortable(|a: &Minion, b: &Minion| a.id.cmp(&b.id)),
+                        DataTableColumn::new("name", || "Name", |minion: Minion| minion.name)
+                            .sortable(|a: &Minion, b: &Minion| a.name.cmp(&b.name)),
+                        DataTableColumn::new("appearance", || "Appearance", |minion: Minion| minion.appearance),
+                        DataTableColumn::new("num_eyes", || "Num. eyes", |minion: Minion| minion.num_eyes)
+                            .sortable(|a: &Minion, b: &Minion| a.num_eyes.cmp(&b.num_eyes)),
+                    ];
+
+                    view! {
+                        <TableContainer>
+                            <DataTable
+                                rows=minions
+                                columns=columns
+                                key=|minion: &Minion| minion.id
+                                selection_mode=SelectionMode::Multiple
+                                selected=selected
+                                set_selected=set_selected
+                                bordered=true
+                                hoverable=true
+                            />
+                        </TableContainer>
+                    }
+                "##)}
+            </Code>
+
+            <TableContainer>
+                <DataTable
+                    rows=minions
+                    columns=columns
+                    key=|minion: &Minion| minion.id
+                    selection_mode=SelectionMode::Multiple
+                    selected=selected_minions
+                    set_selected=set_selected_minions
+                    bordered=true
+                    hoverable=true
+                />
+            </TableContainer>
+
+            <span>"selected: " {move || format!("{:?}", selected_minions.get())}</span>
+
+            <H3 id="virtualization" class="anchor">
+                "Virtualization"
+                <AnchorLink href="#virtualization" description="Direct link to section: Virtualization"/>
+            </H3>
+
+            <P>
+                "Tables with many rows can set the " <Code inline=true>"virtualize"</Code> " prop to only render the rows currently visible. "
+                "Place the table in a container with a limited height and scrollable content, or let it scroll with the page. "
+                "Use " <Code inline=true>"VirtualItemSize::Fixed"</Code> " when all rows have the same height, "
+                "or " <Code inline=true>"VirtualItemSize::Estimated"</Code> " to measure rows once they are rendered."
+            </P>
+
+            <Code>
+                {indoc!(r##"
+                    let many_minions = (1..=10_000)
+                        .map(|id| Minion { id, name: format!("Minion {id}"), appearance: "Yellow".to_owned(), num_eyes: id % 2 + 1 })
+                        .collect::<Vec<_>>();
+
+                    view! {
+                        <TableContainer style="max-height: 20em; overflow-y: auto;">
+                            <DataTable
+                                rows=many_minions
+                                columns=columns
+                                key=|minion: &Minion| minion.id
+                                virtualize=VirtualItemSize::Fixed(45.0)
+                            />
+                        </TableContainer>
+                    }
+                "##)}
+            </Code>
+
+            <TableContainer style="max-height: 20em; overflow-y: auto;">
+                <DataTable
+                    rows=many_minions
+                    columns=many_minions_columns
+                    key=|minion: &Minion| minion.id
+                    virtualize=VirtualItemSize::Fixed(45.0)
+                />
+            </TableContainer>
+
+            <H3 id="server-side-data" class="anchor">
+                "Server-side data"
+                <AnchorLink href="#server-side-data" description="Direct link to section: Server-side data"/>
+            </H3>
+
+            <P>
+                "When rows are sorted, filtered and paginated on the server, describe the rows to load using a " <Code inline=true>"TableQuery"</Code> ". "
+                "It holds the requested page, the page size, the sort keys and any filters, and can be passed to a server function as is. "
+                "Given a " <Code inline=true>"query"</Code> ", the table shows its rows as given and emits a new query through " <Code inline=true>"set_query"</Code> " whenever the sorting changes. "
+                "Also passing the " <Code inline=true>"total_rows"</Code> " matching the query adds a " <Code inline=true>"Pagination"</Code> " below the table, letting users choose the page and page size. "
+                "Hold " <Code inline=true>"Shift"</Code> " while pressing a column header to sort by multiple columns."
+            </P>
+
+            <Code>
+                {indoc!(r##"
+                    #[server]
+                    async fn load_minions(query: TableQuery) -> Result<(Vec<Minion>, u64), ServerFnError> {
+                        // Load the rows of `query.page` and count all rows matching `query.filters`.
+                        todo!()
+                    }
+
+                    let (query, set_query) = create_signal(TableQuery::new(10));
+                    let minions = create_resource(move || query.get(), load_minions);
+                    let loaded = move || minions.get().and_then(Result::ok).unwrap_or_default();
+
+                    view! {
+                        <TextInput
+                            get=Signal::derive(move || query.with(|q| q.filter("name").unwrap_or_default().to_owned()))
+                            set=move |name: String| set_query.set(query.get_untracked().with_filter("name", name))
+                            placeholder="Filter by name"
+                        />
+                        <Transition>
+                            <TableContainer>
+                                <DataTable
+                                    rows=Signal::derive(move || loaded().0)
+                                    columns=columns
+                                    key=|minion: &Minion| minion.id
+                                    query=query
+                                    set_query=set_query
+                                    total_rows=Signal::derive(move || loaded().1)
+                                />
+                            </TableContainer>
+                        </Transition>
+                    }
+                "##)}
+            </Code>
+
+            <TextInput
+                get=Signal::derive(move || minions_query.with(|q| q.filter("name").unwrap_or_default().to_owned()))
+                set=move |name: String| set_minions_query.set(minions_query.get_untracked().with_filter("name", name))
+                placeholder="Filter by name"
+            />
+            <TableContainer>
+                <DataTable
+                    rows=Signal::derive(move || loaded_minions.with(|(rows, _)| rows.clone()))
+                    columns=queried_minions_columns
+                    key=|minion: &Minion| minion.id
+                    query=minions_query
+                    set_query=set_minions_query
+                    total_rows=Signal::derive(move || loaded_minions.with(|(_, total)| *total))
+                />
+            </TableContainer>
+
+            <H3 id="column-layout" class="anchor">
+                "Column layout"
+                <AnchorLink href="#column-layout" description="Direct link to section: Column layout"/>
+            </H3>
+
+            <P>
+                "Enable " <Code inline=true>"resizable_columns"</Code> " to let users resize columns by dragging the right border of a header, or by focusing it and using the arrow keys. "
+                "Enable " <Code inline=true>"reorderable_columns"</Code> " to let users drag a header onto another one to reorder the columns. "
+                "A " <Code inline=true>"column_menu"</Code> " lets users pin columns to the left or right, so that they stay visible while scrolling horizontally inside a " <Code inline=true>"TableContainer"</Code> ", "
+                "move columns and choose which columns are shown."
+            </P>
+
+            <P>
+                "The resulting " <Code inline=true>"ColumnLayout"</Code> " is serializable. Pass it as " <Code inline=true>"layout"</Code> " and receive changes through " <Code inline=true>"set_layout"</Code> " to persist it, "
+                "e.g. in local storage using " <Code inline=true>"create_signal_ls"</Code> ". Rearrange the columns below and reload the page to see the layout restored."
+            </P>
+
+            <Code>
+                {indoc!(r#"
+                    let (layout, set_layout) = create_signal_ls("minions-column-layout", ColumnLayout::default());
+
+                    view! {
+                        <TableContainer>
+                            <DataTable
+                                rows=minions
+                                columns=columns
+                                key=|minion: &Minion| minion.id
+                                layout=layout
+                                set_layout=set_layout
+                                resizable_columns=true
+                                reorderable_columns=true
+                                column_menu=true
+                            />
+                        </TableContainer>
+                    }
+                "#)}
+            </Code>
+
+            <TableContainer>
+                <DataTable
+                    rows=minions
+                    columns=arranged_minions_columns
+                    key=|minion: &Minion| minion.id
+                    layout=minions_layout
+                    set_layout=set_minions_layout
+                    resizable_columns=true
+                    reorderable_columns=true
+                    column_menu=true
+                />
+            </TableContainer>
+
             <H2 id="styling">
                 "Styling"
                 <AnchorLink href="#styling" description="Direct link to section: Styling"/>
@@ -125,12 +409,28 @@ pub fn PageTable() -> impl IntoView {
                     --table-background-color
                     --table-background-color-on-hover
                     --table-background-color-of-striped-rows
+                    --table-background-color-of-selected-rows
                     --table-header-background-color
                     --table-border-color
                     --table-cell-box-shadow-on-hover
                     --table-column-background-if-ordered
                     --table-header-cell-padding
                     --table-body-cell-padding
+                    --table-select-column-width
+                    --table-pinned-column-shadow-color
+                    --table-column-resizer-color
+                    --table-column-drop-target-color
+                    --table-column-menu-background-color
+                    --table-column-menu-box-shadow-color
+                    --table-column-menu-item-background-color-on-hover
+
+                    // Pagination
+                    --pagination-page-size
+                    --pagination-page-border-radius
+                    --pagination-page-color
+                    --pagination-page-background-color-on-hover
+                    --pagination-current-page-color
+                    --pagination-current-page-background-color
                 ")}
             </Code>
         </Article>
@@ -138,6 +438,11 @@ pub fn PageTable() -> impl IntoView {
         <Toc toc=Toc::List {
             inner: vec![
                 Toc::Leaf { title: "Table", link: "#table" },
+                Toc::Group { title: "Data tables", link: "#data-tables", inner: vec![
+                    Toc::Leaf { title: "Virtualization", link: "#virtualization" },
+                    Toc::Leaf { title: "Server-side data", link: "#server-side-data" },
+                    Toc::Leaf { title: "Column layout", link: "#column-layout" },
+                ]},
                 Toc::Leaf { title: "Styling", link: "#styling" },
             ]
         }/>
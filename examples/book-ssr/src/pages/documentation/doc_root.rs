@@ -23,7 +23,9 @@ use crate::pages::documentation::components::general::typography::PageTypography
 use crate::pages::documentation::components::input::button::PageButton;
 use crate::pages::documentation::components::input::checkbox::PageCheckbox;
 use crate::pages::documentation::components::input::color_picker::PageColorPicker;
+use crate::pages::documentation::components::input::combobox::PageCombobox;
 use crate::pages::documentation::components::input::date_time::PageDateTime;
+use crate::pages::documentation::components::input::form_control::PageFormControl;
 use crate::pages::documentation::components::input::input_field::PageInput;
 use crate::pages::documentation::components::input::radio::PageRadio;
 use crate::pages::documentation::components::input::select::PageSelect;
@@ -85,10 +87,12 @@ pub enum DocRoutes {
     // Input
     Button,
     Input,
+    FormControl,
     TiptapEditor,
     DateTime,
     Slider,
     Select,
+    Combobox,
     Checkbox,
     Radio,
     Toggle,
@@ -147,10 +151,12 @@ impl DocRoutes {
 
             Self::Button => "components/button",
             Self::Input => "components/input",
+            Self::FormControl => "components/form-control",
             Self::TiptapEditor => "components/tiptap-editor",
             Self::DateTime => "components/date-time",
             Self::Slider => "components/slider",
             Self::Select => "components/select",
+            Self::Combobox => "components/combobox",
             Self::Checkbox => "components/checkbox",
             Self::Radio => "components/radio",
             Self::Toggle => "components/toggle",
@@ -224,10 +230,12 @@ pub fn DocRoutes<P: Display>(path: P) -> impl IntoView {
 
             <Route path=DocRoutes::Button view=|| view! { <PageButton/> }/>
             <Route path=DocRoutes::Input view=|| view! { <PageInput/> }/>
+            <Route path=DocRoutes::FormControl view=|| view! { <PageFormControl/> }/>
             <Route path=DocRoutes::TiptapEditor view=|| view! { <PageTiptapEditor/> }/>
             <Route path=DocRoutes::DateTime view=|| view! { <PageDateTime/> }/>
             <Route path=DocRoutes::Slider view=|| view! { <PageSlider/> }/>
             <Route path=DocRoutes::Select view=|| view! { <PageSelect/> }/>
+            <Route path=DocRoutes::Combobox view=|| view! { <PageCombobox/> }/>
             <Route path=DocRoutes::Checkbox view=|| view! { <PageCheckbox/> }/>
             <Route path=DocRoutes::Radio view=|| view! { <PageRadio/> }/>
             <Route path=DocRoutes::Toggle view=|| view! { <PageToggle/> }/>
@@ -329,10 +337,12 @@ pub fn DocLayout() -> impl IntoView {
                 <Stack orientation=StackOrientation::Vertical spacing=Size::Zero class="link-stack">
                     <Link href=DocRoutes::Button class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Button"</Link>
                     <Link href=DocRoutes::Input class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Input"</Link>
+                    <Link href=DocRoutes::FormControl class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Form Control"</Link>
                     <Link href=DocRoutes::TiptapEditor class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Tiptap editor"</Link>
                     <Link href=DocRoutes::DateTime class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Date & Time"</Link>
                     <Link href=DocRoutes::Slider class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Slider"</Link>
                     <Link href=DocRoutes::Select class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Select"</Link>
+                    <Link href=DocRoutes::Combobox class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Combobox"</Link>
                     <Link href=DocRoutes::Checkbox class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Checkbox"</Link>
                     <Link href=DocRoutes::Radio class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Radio"</Link>
                     <Link href=DocRoutes::Toggle class="item" on:click=move |_| close_doc_drawer_on_mobile()>"Toggle"</Link>
@@ -16,6 +16,7 @@ pub fn PageUseOverlay() -> impl IntoView {
 
     let trigger_el: NodeRef<html::Div> = create_node_ref();
     let overlay_el: NodeRef<html::Div> = create_node_ref();
+    let arrow_el: NodeRef<html::Div> = create_node_ref();
 
     let (overlay_content, set_overlay_content) = create_signal(String::from("overlay"));
 
@@ -38,12 +39,23 @@ pub fn PageUseOverlay() -> impl IntoView {
 
     let UseOverlayPositionReturn {
         props: overlay_pos_props,
+        placement_x: used_placement_x,
+        placement_y: used_placement_y,
+        arrow_props,
     } = use_overlay_position(UseOverlayPositionInput {
         overlay_ref: overlay_el,
         target_ref: trigger_el,
         placement_y: selected_placement_y.into(),
         placement_x: selected_placement_x.into(),
         writing_direction: WritingDirection::Ltr.into(),
+        flip: true,
+        shift: true,
+        offset: 4.0.into(),
+        padding: OVERLAY_PADDING.into(),
+        boundary: OverlayBoundary::Viewport,
+        arrow_ref: Some(Signal::derive(move || {
+            arrow_el.get().map(|el| (*el).clone().into())
+        })),
     });
 
     let UseButtonReturn { props: btn_props } = use_button(UseButtonInput {
@@ -82,6 +94,11 @@ pub fn PageUseOverlay() -> impl IntoView {
 
             <P>"Create overlays."</P>
 
+            <P>
+                "Overlays positioned with "<Code inline=true>"use_overlay_position"</Code>" flip to the opposite side of their target "
+                "and shift along it when they would otherwise leave the viewport. Scroll the page to see the used placement change."
+            </P>
+
             <Code>
                 {indoc!(r#"
                     ...
@@ -95,25 +112,25 @@ pub fn PageUseOverlay() -> impl IntoView {
                         border-radius: 0.25em;
                         padding: 0.5em;
                     ">
-                        <RadioGroup style="display: flex; flex-direction: column; gap: 0.2em; width: 100%;">
+                        <RadioGroup value=Signal::derive(move || Some(selected_placement_x.get())) set_value={move |v: Option<PlacementX>| if let Some(v) = v { set_selected_placement_x.set(v) }} style="display: flex; flex-direction: column; gap: 0.2em; width: 100%;">
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_x.get() == PlacementX::OuterLeft} set_checked=move |checked| { if checked { set_selected_placement_x.set(PlacementX::OuterLeft)} }/>
+                                <Radio value=PlacementX::OuterLeft/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"OuterLeft"</Label>
                             </FormControl>
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_x.get() == PlacementX::Left} set_checked=move |checked| { if checked { set_selected_placement_x.set(PlacementX::Left)} }/>
+                                <Radio value=PlacementX::Left/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"Left"</Label>
                             </FormControl>
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_x.get() == PlacementX::Center} set_checked=move |checked| { if checked { set_selected_placement_x.set(PlacementX::Center)} }/>
+                                <Radio value=PlacementX::Center/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"Center"</Label>
                             </FormControl>
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_x.get() == PlacementX::Right} set_checked=move |checked| { if checked { set_selected_placement_x.set(PlacementX::Right)} }/>
+                                <Radio value=PlacementX::Right/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"Right"</Label>
                             </FormControl>
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_x.get() == PlacementX::OuterRight} set_checked=move |checked| { if checked { set_selected_placement_x.set(PlacementX::OuterRight)} }/>
+                                <Radio value=PlacementX::OuterRight/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"OuterRight"</Label>
                             </FormControl>
                         </RadioGroup>
@@ -124,25 +141,25 @@ pub fn PageUseOverlay() -> impl IntoView {
                         border-radius: 0.25em;
                         padding: 0.5em;
                     ">
-                        <RadioGroup style="display: flex; flex-direction: column; gap: 0.2em; width: 100%;">
+                        <RadioGroup value=Signal::derive(move || Some(selected_placement_y.get())) set_value={move |v: Option<PlacementY>| if let Some(v) = v { set_selected_placement_y.set(v) }} style="display: flex; flex-direction: column; gap: 0.2em; width: 100%;">
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_y.get() == PlacementY::Above} set_checked=move |checked| { if checked { set_selected_placement_y.set(PlacementY::Above)} }/>
+                                <Radio value=PlacementY::Above/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"Above"</Label>
                             </FormControl>
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_y.get() == PlacementY::Top} set_checked=move |checked| { if checked { set_selected_placement_y.set(PlacementY::Top)} }/>
+                                <Radio value=PlacementY::Top/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"Top"</Label>
                             </FormControl>
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_y.get() == PlacementY::Center} set_checked=move |checked| { if checked { set_selected_placement_y.set(PlacementY::Center)} }/>
+                                <Radio value=PlacementY::Center/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"Center"</Label>
                             </FormControl>
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_y.get() == PlacementY::Bottom} set_checked=move |checked| { if checked { set_selected_placement_y.set(PlacementY::Bottom)} }/>
+                                <Radio value=PlacementY::Bottom/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"Bottom"</Label>
                             </FormControl>
                             <FormControl style="display: flex; flex-direction: row; align-items: center; width: 100%;">
-                                <Radio checked=move || {selected_placement_y.get() == PlacementY::Below} set_checked=move |checked| { if checked { set_selected_placement_y.set(PlacementY::Below)} }/>
+                                <Radio value=PlacementY::Below/>
                                 <Label style="margin-left: 0.25em; width: 100%; height: 100%;">"Below"</Label>
                             </FormControl>
                         </RadioGroup>
@@ -184,6 +201,7 @@ pub fn PageUseOverlay() -> impl IntoView {
                 {
                     let overlay_props_attrs = overlay_props.attrs.clone();
                     let overlay_pos_props_attrs = overlay_pos_props.attrs.clone();
+                    let arrow_props_attrs = arrow_props.attrs.clone();
                     view! {
                         <Show when=move || state.get()>
                             <style>
@@ -192,7 +210,16 @@ pub fn PageUseOverlay() -> impl IntoView {
                                     color: white;
                                     padding: 1em;
                                     border-radius: 0.25em;
-                                }"
+                                }
+                                .my-overlay-arrow {
+                                    width: 0;
+                                    height: 0;
+                                    border: 0.5em solid transparent;
+                                }
+                                .my-overlay-arrow[data-placement=above] { border-top-color: #0009; }
+                                .my-overlay-arrow[data-placement=below] { border-bottom-color: #0009; }
+                                .my-overlay-arrow[data-placement=left] { border-left-color: #0009; }
+                                .my-overlay-arrow[data-placement=right] { border-right-color: #0009; }"
                             </style>
                             <div
                                 {..overlay_props_attrs.clone()}
@@ -201,6 +228,9 @@ pub fn PageUseOverlay() -> impl IntoView {
                                 class="my-overlay"
                             >
                                 { move || overlay_content.get() }
+                                <br/>
+                                { move || format!("Placed {:?} / {:?}", used_placement_x.get(), used_placement_y.get()) }
+                                <div {..arrow_props_attrs.clone()} node_ref=arrow_el class="my-overlay-arrow"/>
                             </div>
                         </Show>
                     }
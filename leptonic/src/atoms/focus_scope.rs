@@ -0,0 +1,48 @@
+use leptos::*;
+
+use crate::hooks::{use_focus_scope, UseFocusScopeInput, UseFocusScopeReturn};
+
+/// Manages focus for its children. Use `use_focus_manager` inside of it to move focus programmatically.
+/// Renders a wrapper element with `display: contents`, not affecting the layout.
+#[component]
+pub fn FocusScope(
+    /// Whether the scope is active. Deactivating the scope restores focus, just like unmounting it.
+    #[prop(into, default = true.into())]
+    active: MaybeSignal<bool>,
+    /// Keep Tab navigation inside of the scope.
+    #[prop(default = true)]
+    contain: bool,
+    /// Focus the first tabbable child when the scope becomes active.
+    #[prop(default = true)]
+    auto_focus: bool,
+    /// Focus the previously focused element again when the scope is deactivated or unmounted.
+    #[prop(default = true)]
+    restore_focus: bool,
+    children: Children,
+) -> impl IntoView {
+    let scope_el: NodeRef<html::Custom> = create_node_ref();
+
+    let UseFocusScopeReturn {
+        props,
+        focus_manager,
+    } = use_focus_scope(UseFocusScopeInput {
+        scope_ref: scope_el,
+        active,
+        contain,
+        auto_focus,
+        restore_focus,
+    });
+
+    view! {
+        <Provider value=focus_manager>
+            <leptonic-focus-scope
+                {..props.attrs}
+                node_ref=scope_el
+                style="display: contents"
+                on:keydown=props.on_key_down
+            >
+                { children() }
+            </leptonic-focus-scope>
+        </Provider>
+    }
+}
@@ -5,6 +5,7 @@ use leptos_reactive::Oco;
 
 pub mod button;
 pub mod button_group;
+pub mod focus_scope;
 pub mod hoverable;
 pub mod link;
 pub mod press;
@@ -13,9 +14,11 @@ pub mod popover;
 pub mod prelude {
     pub use super::button::Button;
     pub use super::button_group::ButtonGroup;
+    pub use super::focus_scope::FocusScope;
     pub use super::hoverable::Hoverable;
     pub use super::link::AnchorLink;
     pub use super::popover::Popover;
+    pub use super::popover::PopoverArrow;
     pub use super::popover::PopoverContent;
     pub use super::popover::PopoverTrigger;
 }
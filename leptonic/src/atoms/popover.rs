@@ -1,37 +1,117 @@
+use std::rc::Rc;
+
 use crate::{
     hooks::{
-        use_overlay, use_overlay_position, use_overlay_trigger, PlacementX, PlacementY,
-        UseOverlayInput, UseOverlayPositionInput, UseOverlayPositionReturn, UseOverlayProps,
-        UseOverlayReturn, UseOverlayTriggerInput, UseOverlayTriggerReturn,
+        use_popover, OverlayBoundary, PlacementX, PlacementY, UsePopoverInput, UsePopoverReturn,
     },
-    utils::{aria::AriaHasPopup, locale::WritingDirection},
+    utils::{aria::AriaHasPopup, locale::WritingDirection, props::Attributes, NodeRefExt},
 };
+use educe::Educe;
 use leptos::*;
+use web_sys::KeyboardEvent;
 
-#[derive(Debug, Clone)]
+#[derive(Clone, Educe)]
+#[educe(Debug)]
 pub struct PopoverContext {
     pub state: ReadSignal<bool>,
     pub set_state: WriteSignal<bool>,
 
     id: Oco<'static, str>,
-    overlay_props: UseOverlayProps, // TODO: Rc?
-    trigger_el: ReadSignal<Option<NodeRef<html::Custom>>>,
-    set_trigger_el: WriteSignal<Option<NodeRef<html::Custom>>>,
+    // Element refs are only read by `node_ref`s, which are only attached in the browser.
+    #[educe(Debug(ignore))]
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    trigger_el: NodeRef<html::Custom>,
+    #[educe(Debug(ignore))]
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    content_el: NodeRef<html::Custom>,
+    #[educe(Debug(ignore))]
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    arrow_el: NodeRef<html::Custom>,
+    trigger_attrs: Attributes,
+    content_attrs: Attributes,
+    arrow_attrs: Attributes,
+    #[educe(Debug(ignore))]
+    on_content_key_down: Rc<dyn Fn(KeyboardEvent)>,
+}
+
+impl PopoverContext {
+    /// Id of the popover content element.
+    pub fn id(&self) -> Oco<'static, str> {
+        self.id.clone()
+    }
 }
 
 #[component]
-pub fn Popover(#[prop(into)] disabled: MaybeSignal<bool>, children: Children) -> impl IntoView {
-    let UseOverlayReturn {
-        props: overlay_props,
+pub fn Popover(
+    #[prop(into)] disabled: MaybeSignal<bool>,
+    #[prop(into, default = PlacementX::Center.into())] placement_x: MaybeSignal<PlacementX>,
+    #[prop(into, default = PlacementY::Above.into())] placement_y: MaybeSignal<PlacementY>,
+    #[prop(into, default = WritingDirection::Ltr.into())] writing_direction: MaybeSignal<
+        WritingDirection,
+    >,
+    /// Distance in px between the trigger and the content.
+    #[prop(into, default = 0.0.into())]
+    offset: MaybeSignal<f64>,
+    /// The area the content is kept inside of when being flipped or shifted.
+    #[prop(optional)]
+    boundary: OverlayBoundary,
+    /// Close the popover when the user clicks anywhere outside of the trigger and the content.
+    #[prop(default = true)]
+    dismiss_on_click_away: bool,
+    /// Close the popover when the user presses Escape.
+    #[prop(default = true)]
+    dismiss_on_escape: bool,
+    /// Move focus into the content when the popover opens and keep it there until the popover closes.
+    #[prop(optional)]
+    contain_focus: bool,
+    children: Children,
+) -> impl IntoView {
+    let (state, set_state) = create_signal(false);
+
+    let trigger_el: NodeRef<html::Custom> = create_node_ref();
+    let content_el: NodeRef<html::Custom> = create_node_ref();
+    let arrow_el: NodeRef<html::Custom> = create_node_ref();
+
+    let UsePopoverReturn {
         id,
+        trigger_props,
+        props,
+        placement_x: _,
+        placement_y: _,
+        arrow_props,
+    } = use_popover(UsePopoverInput {
+        trigger_ref: trigger_el,
+        popover_ref: content_el,
+        disabled,
+        show: state.into(),
+        on_open_change: Callback::new(move |show| set_state.set(show)),
+        popover_type: AriaHasPopup::Dialog,
+        dismiss_on_click_away,
+        dismiss_on_escape,
+        contain_focus,
+        placement_x,
+        placement_y,
+        writing_direction,
+        offset,
+        boundary,
+        arrow_ref: Some(Signal::derive(move || arrow_el.get_element())),
+    });
+
+    let ctx = PopoverContext {
         state,
         set_state,
-    } = use_overlay(UseOverlayInput { disabled });
-
-    let (trigger_el, set_trigger_el) = create_signal(None);
+        id,
+        trigger_el,
+        content_el,
+        arrow_el,
+        trigger_attrs: trigger_props.attrs,
+        content_attrs: props.attrs,
+        arrow_attrs: arrow_props.attrs,
+        on_content_key_down: Rc::from(props.on_key_down),
+    };
 
     view! {
-        <Provider value=PopoverContext { id, overlay_props, state, set_state, trigger_el, set_trigger_el }>
+        <Provider value=ctx>
             { children() }
         </Provider>
     }
@@ -41,63 +121,49 @@ pub fn Popover(#[prop(into)] disabled: MaybeSignal<bool>, children: Children) ->
 pub fn PopoverTrigger(children: Children) -> impl IntoView {
     let ctx = expect_context::<PopoverContext>();
 
-    let trigger_el: NodeRef<html::Custom> = create_node_ref();
-    ctx.set_trigger_el.set(Some(trigger_el));
-
-    let UseOverlayTriggerReturn {
-        props: trigger_props,
-    } = use_overlay_trigger(UseOverlayTriggerInput {
-        show: ctx.state.into(),
-        overlay_id: ctx.id,
-        overlay_type: AriaHasPopup::Menu,
-    });
-
     view! {
-        <leptonic-popover-trigger {..trigger_props.attrs} node_ref=trigger_el>
+        <leptonic-popover-trigger {..ctx.trigger_attrs} node_ref=ctx.trigger_el>
             { children() }
         </leptonic-popover-trigger>
     }
 }
 
 #[component]
-pub fn PopoverContent(
-    #[prop(into)] placement_x: MaybeSignal<PlacementX>,
-    #[prop(into)] placement_y: MaybeSignal<PlacementY>,
-    #[prop(into)] writing_direction: MaybeSignal<WritingDirection>,
-    children: ChildrenFn,
-) -> impl IntoView {
+pub fn PopoverContent(children: ChildrenFn) -> impl IntoView {
     let ctx = expect_context::<PopoverContext>();
 
-    let overlay_el: NodeRef<html::Custom> = create_node_ref();
-
-    let UseOverlayPositionReturn {
-        props: overlay_pos_props,
-    } = use_overlay_position(UseOverlayPositionInput {
-        overlay_ref: overlay_el,
-        target_ref: ctx.trigger_el.get_untracked().expect("trigger present"),
-        placement_x,
-        placement_y,
-        writing_direction,
-    });
-
     view! {
         <Portal>
         {
-            let overlay_props_attrs = ctx.overlay_props.attrs.clone();
-            let overlay_pos_props_attrs = overlay_pos_props.attrs.clone();
+            let ctx = ctx.clone();
             let children = children.clone();
             view! {
                 <Show when=move || ctx.state.get()>
-                    <leptonic-popover-content
-                        {..overlay_props_attrs.clone()}
-                        {..overlay_pos_props_attrs.clone()}
-                        node_ref=overlay_el
-                    >
-                        { children() }
-                    </leptonic-popover-content>
+                    {
+                        let on_key_down = ctx.on_content_key_down.clone();
+                        view! {
+                            <leptonic-popover-content
+                                {..ctx.content_attrs.clone()}
+                                node_ref=ctx.content_el
+                                on:keydown=move |e| on_key_down(e)
+                            >
+                                { children() }
+                            </leptonic-popover-content>
+                        }
+                    }
                 </Show>
             }
         }
         </Portal>
     }
 }
+
+/// An arrow pointing at the trigger. Must be rendered inside of `PopoverContent`.
+#[component]
+pub fn PopoverArrow(#[prop(into, optional)] class: Option<AttributeValue>) -> impl IntoView {
+    let ctx = expect_context::<PopoverContext>();
+
+    view! {
+        <leptonic-popover-arrow {..ctx.arrow_attrs} node_ref=ctx.arrow_el class=class />
+    }
+}
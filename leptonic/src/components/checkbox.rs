@@ -1,6 +1,6 @@
 use leptos::*;
 
-use crate::{components::{form_control::FormControlContext, icon::Icon}, OptMaybeSignal, Out};
+use crate::{components::{form_control::{FormControlContext, HiddenInputs}, icon::Icon}, OptMaybeSignal, Out};
 
 use super::form_control::FormInput;
 
@@ -31,6 +31,12 @@ pub fn Checkbox(
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
     #[prop(default = icondata::BsCheck2)] checked_icon: icondata::Icon,
+    /// Submits the checkbox with a surrounding native `<form>` under this name, but only when checked.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
+    /// Value submitted when the checkbox is checked. Defaults to "on", like a native checkbox.
+    #[prop(into, optional)]
+    value: Option<Oco<'static, str>>,
     /// Arbitrary additional attributes.
     #[prop(attrs)] attributes: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView {
@@ -56,17 +62,35 @@ pub fn Checkbox(
             role="checkbox"
             aria-checked=move || match checked.get() { true => "true", false => "false" }
             aria-disabled=move || match disabled() { true => "true", false => "false" }
+            aria-invalid=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_invalid())
+            aria-errormessage=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_errormessage())
+            class:leptonic-validation-error=move || form_ctrl_ctx.is_some_and(|ctx| ctx.is_invalid())
             tabindex="0"
             on:click=move |_e| {
                 if !disabled() {
-                    set_checked.set(!checked.get_untracked())
+                    set_checked.set(!checked.get_untracked());
+                    if let Some(ctx) = form_ctrl_ctx { ctx.touch(); }
                 }
             }
+            on:blur=move |_e| { if let Some(ctx) = form_ctrl_ctx { ctx.touch(); } }
         >
             <Icon icon=checked_icon style=move || match checked.get() {
                 true => "display: inherit",
                 false => "display: none",
             } />
+            { name.map(|name| {
+                let value = value.unwrap_or(Oco::Borrowed("on"));
+                view! {
+                    <HiddenInputs
+                        name=name
+                        values=Signal::derive(move || match checked.get() {
+                            true => vec![value.to_string()],
+                            false => Vec::new(),
+                        })
+                        disabled=Signal::derive(disabled)
+                    />
+                }
+            }) }
         </leptonic-checkbox>
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
     components::{
         field::{Field, FieldLabel},
+        form_control::HiddenInputs,
         input::NumberInput,
         prelude::{Slider, SliderMarks, SliderPopover},
         typography::P,
@@ -178,6 +179,9 @@ pub fn HueSlider(#[prop(into)] hue: Signal<f64>, #[prop(into)] set_hue: Out<f64>
 pub fn ColorPicker(
     #[prop(into)] hsv: Signal<HSV>,
     #[prop(into)] set_hsv: Out<HSV>,
+    /// Submits the color with a surrounding native `<form>` under this name, formatted like the value of a native color input.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
 ) -> impl IntoView {
     let hue = Signal::derive(move || hsv.get().hue);
     let saturation = Signal::derive(move || hsv.get().saturation);
@@ -252,6 +256,10 @@ pub fn ColorPicker(
             </div>
 
             <P>"Hex: #"{move || format!("{:X}", rgb.get())}</P>
+
+            { name.map(|name| view! {
+                <HiddenInputs name=name values=Signal::derive(move || vec![format!("#{:x}", rgb.get())])/>
+            }) }
         </leptonic-color-picker>
     }
 }
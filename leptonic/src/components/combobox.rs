@@ -0,0 +1,147 @@
+use std::rc::Rc;
+
+use leptos::*;
+use web_sys::MouseEvent;
+
+use crate::{
+    components::{input::TextInput, select::SelectOption},
+    hooks::{
+        use_combobox, use_combobox_option, OptionsLoader, SearchFilterProvider, UseComboboxInput,
+        UseComboboxOptionInput, UseComboboxOptionReturn, UseComboboxReturn,
+    },
+    prelude::{Consumer, ViewCallback},
+    utils::{locale::WritingDirection, options::load_more_on_scroll},
+    OptMaybeSignal, Out,
+};
+
+/// A text input suggesting values matching the typed text.
+/// Any text can be entered. Selecting a suggestion replaces the text with the `search_text_provider` text of that suggestion.
+#[component]
+#[allow(clippy::too_many_lines)]
+pub fn Combobox<O>(
+    #[prop(into)] get: MaybeSignal<String>,
+    #[prop(into)] set: Out<String>,
+    /// All suggestions. Only those matching the typed text are shown.
+    #[prop(into, optional)]
+    options: MaybeSignal<Vec<O>>,
+    /// Text of a suggestion. Used to filter suggestions and taken into the input when the suggestion is selected.
+    #[prop(into)]
+    search_text_provider: Consumer<O, String>,
+    #[prop(into)] render_option: ViewCallback<O>,
+    #[prop(into, optional)] search_filter_provider: Option<SearchFilterProvider<O>>,
+    /// Loads the suggestions matching the typed text asynchronously. `options` are ignored when set.
+    #[prop(optional)]
+    loader: Option<OptionsLoader<O>>,
+    /// Milliseconds the typed text must stay unchanged before suggestions are loaded through the `loader`.
+    #[prop(optional, default = 300.0)]
+    search_debounce: f64,
+    /// Number of suggestions loaded through the `loader` at once. Further suggestions are loaded when scrolling to their end.
+    #[prop(optional, default = 50)]
+    page_size: usize,
+    /// Called with the suggestion the user selected, in addition to updating the text.
+    #[prop(into, optional)]
+    on_select: Option<Out<O>>,
+    /// Completes the typed text with the first suggestion starting with it. The completed part is selected,
+    /// so that typing on replaces it. Press Tab or Enter to accept the completion or Escape to discard it.
+    #[prop(optional)]
+    inline_completion: bool,
+    #[prop(optional, into)] placeholder: OptMaybeSignal<String>,
+    #[prop(into, optional)] disabled: OptMaybeSignal<bool>,
+    #[prop(into, default = WritingDirection::Ltr.into())] writing_direction: MaybeSignal<
+        WritingDirection,
+    >,
+    #[prop(into, optional)] class: Option<AttributeValue>,
+    #[prop(into, optional)] style: Option<AttributeValue>,
+    /// Submits the text with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<AttributeValue>,
+) -> impl IntoView
+where
+    O: SelectOption + 'static,
+{
+    let get = Signal::derive(move || get.get());
+    let input_ref: NodeRef<html::Input> = create_node_ref();
+    let listbox_ref: NodeRef<html::Custom> = create_node_ref();
+
+    let UseComboboxReturn {
+        props,
+        listbox_props,
+        state,
+    } = use_combobox(UseComboboxInput {
+        input_ref,
+        listbox_ref,
+        text: get.into(),
+        set_text: set,
+        inline_completion,
+        writing_direction,
+        options,
+        loader,
+        search_debounce,
+        page_size,
+        search_text_provider,
+        search_filter_provider,
+        on_select,
+    });
+
+    // `TextInput` would remove the id given through its attributes when not setting its own.
+    let mut input_attrs = props.attrs;
+    let input_id = input_attrs.map.remove("id");
+    let on_listbox_mouse_down: Rc<dyn Fn(MouseEvent)> = Rc::from(listbox_props.on_mouse_down);
+
+    let option_view = move |option: O| {
+        let UseComboboxOptionReturn {
+            props: option_props,
+        } = use_combobox_option(UseComboboxOptionInput {
+            combobox: state,
+            option: option.clone(),
+        });
+        let clone = option.clone();
+        view! {
+            <leptonic-combobox-option
+                {..option_props.attrs}
+                class:preselected=move || state.is_preselected(&option)
+                on:mouseenter=option_props.on_mouse_enter
+                on:click=option_props.on_click
+            >
+                { render_option.render(clone) }
+            </leptonic-combobox-option>
+        }
+    };
+
+    view! {
+        <leptonic-combobox class=class style=style>
+            <TextInput
+                {..input_attrs}
+                input_ref=input_ref
+                id=input_id
+                get=get
+                placeholder=placeholder
+                disabled=disabled
+                name=name
+                on:input=props.on_input
+                on:keydown=props.on_key_down
+                on:focusout=props.on_blur
+            />
+
+            <Show when=move || state.is_open() && (state.has_options() || state.is_loading())>
+                <leptonic-combobox-listbox
+                    {..listbox_props.attrs.clone()}
+                    node_ref=listbox_ref
+                    on:mousedown={
+                        let on_mouse_down = on_listbox_mouse_down.clone();
+                        move |e| on_mouse_down(e)
+                    }
+                    on:scroll=move |e| load_more_on_scroll(&e, || state.load_more())
+                >
+                    { move || state.options().get().into_iter().map(option_view).collect_view() }
+
+                    { move || state.is_loading().then(|| view! {
+                        <leptonic-combobox-loading>
+                            "Loading..."
+                        </leptonic-combobox-loading>
+                    }) }
+                </leptonic-combobox-listbox>
+            </Show>
+        </leptonic-combobox>
+    }
+}
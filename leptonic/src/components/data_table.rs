@@ -0,0 +1,1393 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashSet},
+    hash::Hash,
+    ops::Deref,
+    rc::Rc,
+};
+
+use leptos::*;
+
+use crate::{
+    components::{
+        checkbox::Checkbox,
+        icon::Icon,
+        pagination::Pagination,
+        table::{Table, TableBody, TableCell, TableHeader, TableHeaderCell, TableRow},
+        virtual_list::VirtualList,
+    },
+    hooks::{
+        use_menu, use_menu_item, use_menu_section, use_menu_trigger, MenuFocusStrategy, MenuState,
+        PlacementX, PlacementY, UseMenuInput, UseMenuItemInput, UseMenuItemReturn, UseMenuReturn,
+        UseMenuSectionInput, UseMenuSectionReturn, UseMenuTriggerInput, UseMenuTriggerReturn,
+        VirtualItemSize,
+    },
+    prelude::{ViewCallback, ViewProducer},
+    utils::{locale::WritingDirection, props::Attributes, EventTargetExt},
+    Out,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Value of the `aria-sort` attribute of a column sorted in this direction.
+    pub const fn as_aria_sort(self) -> &'static str {
+        match self {
+            Self::Ascending => "ascending",
+            Self::Descending => "descending",
+        }
+    }
+
+    #[must_use]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// The column a table is sorted by.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct SortDescriptor {
+    /// Id of the sorted column.
+    pub column: Oco<'static, str>,
+    pub direction: SortDirection,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelectionMode {
+    #[default]
+    None,
+    Single,
+    Multiple,
+}
+
+/// Restricts the rows of a table to those whose `column` matches `value`.
+/// How a value is matched is up to whoever loads the rows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct TableFilter {
+    /// Id of the filtered column.
+    pub column: Oco<'static, str>,
+    pub value: String,
+}
+
+/// Describes which rows of a table should be shown: A single page of all rows matching the `filters`, ordered by the `sort` keys.
+///
+/// A `DataTable` emits a new query whenever the user changes the sorting or the page.
+/// Load the rows of the requested page, e.g. using a `Resource` calling a server function taking this query,
+/// and pass them back to the table together with the total number of matching rows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct TableQuery {
+    /// The requested (1-based) page.
+    pub page: u64,
+
+    /// Number of rows per page.
+    pub page_size: u64,
+
+    /// Sort keys, by decreasing priority.
+    pub sort: Vec<SortDescriptor>,
+
+    pub filters: Vec<TableFilter>,
+}
+
+impl Default for TableQuery {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl TableQuery {
+    /// Queries the first page of `page_size` unsorted and unfiltered rows.
+    pub const fn new(page_size: u64) -> Self {
+        Self {
+            page: 1,
+            page_size,
+            sort: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
+    /// Number of rows in front of the requested page.
+    pub const fn offset(&self) -> u64 {
+        self.page.saturating_sub(1) * self.page_size
+    }
+
+    /// Number of rows of the requested page.
+    pub const fn limit(&self) -> u64 {
+        self.page_size
+    }
+
+    /// The value the given column is filtered by, if any.
+    pub fn filter(&self, column: &str) -> Option<&str> {
+        self.filters
+            .iter()
+            .find(|f| f.column == column)
+            .map(|f| f.value.as_str())
+    }
+
+    #[must_use]
+    pub fn with_page(mut self, page: u64) -> Self {
+        self.page = page.max(1);
+        self
+    }
+
+    /// Changes the page size, keeping the first row of the current page on the requested page.
+    /// Page sizes below 1 are raised to 1.
+    #[must_use]
+    pub fn with_page_size(mut self, page_size: u64) -> Self {
+        let page_size = page_size.max(1);
+        self.page = self.offset() / page_size + 1;
+        self.page_size = page_size;
+        self
+    }
+
+    /// Changes the sort keys, going back to the first page.
+    #[must_use]
+    pub fn with_sort(mut self, sort: Vec<SortDescriptor>) -> Self {
+        self.sort = sort;
+        self.page = 1;
+        self
+    }
+
+    /// Filters the given column by `value`, or removes its filter when `value` is empty. Goes back to the first page.
+    #[must_use]
+    pub fn with_filter(
+        mut self,
+        column: impl Into<Oco<'static, str>>,
+        value: impl Into<String>,
+    ) -> Self {
+        let column = column.into();
+        let value = value.into();
+        self.filters.retain(|f| f.column != column);
+        if !value.is_empty() {
+            self.filters.push(TableFilter { column, value });
+        }
+        self.page = 1;
+        self
+    }
+}
+
+/// Side of a `DataTable` a column is pinned to. Pinned columns stay visible while scrolling horizontally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum ColumnPin {
+    Left,
+    Right,
+}
+
+/// How the columns of a `DataTable` are arranged: Their order, widths, which are pinned and which are hidden.
+/// Columns are referenced by their `DataTableColumn::id`.
+///
+/// The layout is serializable. Persist it, e.g. using `create_signal_ls`, to keep the arrangement a user chose.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ColumnLayout {
+    /// Display order of the columns. Columns not listed follow in the order they were defined in.
+    pub order: Vec<String>,
+
+    /// Widths of resized columns in pixels.
+    pub widths: BTreeMap<String, f64>,
+
+    pub pinned: BTreeMap<String, ColumnPin>,
+
+    pub hidden: BTreeSet<String>,
+}
+
+impl ColumnLayout {
+    pub fn width(&self, column: &str) -> Option<f64> {
+        self.widths.get(column).copied()
+    }
+
+    pub fn pin(&self, column: &str) -> Option<ColumnPin> {
+        self.pinned.get(column).copied()
+    }
+
+    pub fn is_hidden(&self, column: &str) -> bool {
+        self.hidden.contains(column)
+    }
+
+    /// Indices into `ids` of all columns, in display order, ignoring pinning and visibility.
+    fn ordered(&self, ids: &[impl AsRef<str>]) -> Vec<usize> {
+        let listed = self
+            .order
+            .iter()
+            .filter_map(|column| ids.iter().position(|id| id.as_ref() == column));
+        let mut ordered = Vec::with_capacity(ids.len());
+        for i in listed.chain(0..ids.len()) {
+            if !ordered.contains(&i) {
+                ordered.push(i);
+            }
+        }
+        ordered
+    }
+
+    /// Indices into `ids` of the visible columns, in the order they are displayed:
+    /// Columns pinned to the left come first, columns pinned to the right come last.
+    pub fn arrange(&self, ids: &[impl AsRef<str>]) -> Vec<usize> {
+        let mut arranged = self
+            .ordered(ids)
+            .into_iter()
+            .filter(|i| !self.is_hidden(ids[*i].as_ref()))
+            .collect::<Vec<_>>();
+        arranged.sort_by_key(|i| match self.pin(ids[*i].as_ref()) {
+            Some(ColumnPin::Left) => 0,
+            None => 1,
+            Some(ColumnPin::Right) => 2,
+        });
+        arranged
+    }
+
+    /// Index into `ids` of the visible column displayed next to `column`, either behind it (`forward`) or in front of it.
+    /// Only columns pinned to the same side as `column` are considered.
+    pub fn adjacent(&self, ids: &[impl AsRef<str>], column: &str, forward: bool) -> Option<usize> {
+        let pin = self.pin(column);
+        let group = self
+            .arrange(ids)
+            .into_iter()
+            .filter(|i| self.pin(ids[*i].as_ref()) == pin)
+            .collect::<Vec<_>>();
+        let position = group.iter().position(|i| ids[*i].as_ref() == column)?;
+        match forward {
+            true => group.get(position + 1).copied(),
+            false => position.checked_sub(1).and_then(|i| group.get(i).copied()),
+        }
+    }
+
+    /// Sets the width of `column`. `None` lets the table size the column.
+    #[must_use]
+    pub fn with_width(mut self, column: &str, width: Option<f64>) -> Self {
+        match width {
+            Some(width) => self.widths.insert(column.to_owned(), width),
+            None => self.widths.remove(column),
+        };
+        self
+    }
+
+    /// Pins `column` to the given side. `None` unpins it.
+    #[must_use]
+    pub fn with_pin(mut self, column: &str, pin: Option<ColumnPin>) -> Self {
+        match pin {
+            Some(pin) => self.pinned.insert(column.to_owned(), pin),
+            None => self.pinned.remove(column),
+        };
+        self
+    }
+
+    #[must_use]
+    pub fn with_hidden(mut self, column: &str, hidden: bool) -> Self {
+        match hidden {
+            true => self.hidden.insert(column.to_owned()),
+            false => self.hidden.remove(column),
+        };
+        self
+    }
+
+    /// Moves `column` to the position of `target`. All columns in between shift by one position towards the old position of `column`.
+    #[must_use]
+    pub fn with_column_moved(
+        mut self,
+        ids: &[impl AsRef<str>],
+        column: &str,
+        target: &str,
+    ) -> Self {
+        let mut order = self
+            .ordered(ids)
+            .into_iter()
+            .map(|i| ids[i].as_ref().to_owned())
+            .collect::<Vec<_>>();
+        if let (Some(from), Some(to)) = (
+            order.iter().position(|id| id == column),
+            order.iter().position(|id| id == target),
+        ) {
+            let moved = order.remove(from);
+            order.insert(to, moved);
+            self.order = order;
+        }
+        self
+    }
+}
+
+/// Columns can not be resized below this width in pixels.
+const MIN_COLUMN_WIDTH: f64 = 40.0;
+
+/// Amount of pixels a column is resized by per key press.
+const COLUMN_RESIZE_STEP: f64 = 10.0;
+
+/// Width of pinned columns never resized. Pinned columns need a known width, as it offsets the columns pinned next to them.
+const DEFAULT_PINNED_COLUMN_WIDTH: f64 = 150.0;
+
+/// Where and how wide a visible column is rendered.
+#[derive(Debug, Clone, PartialEq)]
+struct ColumnPlacement {
+    width: Option<f64>,
+    pin: Option<ColumnPin>,
+
+    /// Combined width of all columns pinned to the same side, closer to that side than this column.
+    offset: f64,
+
+    /// Whether this is the pinned column farthest from its side, bordering the scrolling columns.
+    edge: bool,
+}
+
+impl ColumnPlacement {
+    /// Inline style of the cells of this column. Offsets of columns pinned to the left include the selection column.
+    fn style(&self, selectable: bool) -> Option<String> {
+        let width = self.width.map(|width| {
+            format!(
+                "width: {width}px; min-width: {width}px; max-width: {width}px; overflow: hidden;"
+            )
+        });
+        let offset = match (self.pin, selectable) {
+            (Some(ColumnPin::Left), true) => Some(format!(
+                "left: calc({}px + var(--table-select-column-width));",
+                self.offset
+            )),
+            (Some(ColumnPin::Left), false) => Some(format!("left: {}px;", self.offset)),
+            (Some(ColumnPin::Right), _) => Some(format!("right: {}px;", self.offset)),
+            (None, _) => None,
+        };
+        match (width, offset) {
+            (Some(width), Some(offset)) => Some(format!("{width} {offset}")),
+            (width, offset) => width.or(offset),
+        }
+    }
+}
+
+/// Placement of every column in `ids`, `None` for hidden columns.
+fn column_placements(
+    layout: &ColumnLayout,
+    ids: &[impl AsRef<str>],
+) -> Vec<Option<ColumnPlacement>> {
+    let arranged = layout.arrange(ids);
+    let mut placements = vec![None; ids.len()];
+    for i in &arranged {
+        let id = ids[*i].as_ref();
+        let pin = layout.pin(id);
+        placements[*i] = Some(ColumnPlacement {
+            width: layout
+                .width(id)
+                .or_else(|| pin.map(|_| DEFAULT_PINNED_COLUMN_WIDTH)),
+            pin,
+            offset: 0.0,
+            edge: false,
+        });
+    }
+    for (side, columns) in [
+        (ColumnPin::Left, arranged.iter().collect::<Vec<_>>()),
+        (ColumnPin::Right, arranged.iter().rev().collect::<Vec<_>>()),
+    ] {
+        let mut offset = 0.0;
+        let mut last = None;
+        for i in columns {
+            let Some(placement) = placements[*i].as_mut().filter(|p| p.pin == Some(side)) else {
+                continue;
+            };
+            placement.offset = offset;
+            offset += placement.width.unwrap_or_default();
+            last = Some(*i);
+        }
+        if let Some(placement) = last.and_then(|i| placements[i].as_mut()) {
+            placement.edge = true;
+        }
+    }
+    placements
+}
+
+/// Orders two rows of a `DataTable`. Rows are sorted ascending according to this ordering.
+pub type DataTableComparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// Definition of a single column of a `DataTable`.
+pub struct DataTableColumn<T: 'static> {
+    /// Identifies this column, e.g. in a `SortDescriptor`. Must be unique among all columns of a table.
+    pub id: Oco<'static, str>,
+
+    /// Content of the header cell of this column.
+    pub header: ViewProducer,
+
+    /// Renders the cell of this column for the given row.
+    pub cell: ViewCallback<T>,
+
+    /// Makes this column sortable when set.
+    pub compare: Option<DataTableComparator<T>>,
+}
+
+impl<T: 'static> DataTableColumn<T> {
+    pub fn new(
+        id: impl Into<Oco<'static, str>>,
+        header: impl Into<ViewProducer>,
+        cell: impl Into<ViewCallback<T>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            header: header.into(),
+            cell: cell.into(),
+            compare: None,
+        }
+    }
+
+    /// Makes this column sortable, ordering rows by `compare`.
+    #[must_use]
+    pub fn sortable(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+}
+
+impl<T: 'static> Clone for DataTableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header,
+            cell: self.cell,
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<T: 'static> std::fmt::Debug for DataTableColumn<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataTableColumn")
+            .field("id", &self.id)
+            .field("sortable", &self.compare.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Computes the sort keys after the header of `column` was pressed.
+/// A column already sorted by is reversed, other columns start out ascending.
+/// Unless `additive`, the pressed column becomes the only sort key.
+fn toggled_sort(
+    mut sort: Vec<SortDescriptor>,
+    column: &Oco<'static, str>,
+    additive: bool,
+) -> Vec<SortDescriptor> {
+    match sort.iter_mut().find(|s| s.column == *column) {
+        Some(current) => current.direction = current.direction.reversed(),
+        None => sort.push(SortDescriptor {
+            column: column.clone(),
+            direction: SortDirection::Ascending,
+        }),
+    }
+    if !additive {
+        sort.retain(|s| s.column == *column);
+    }
+    sort
+}
+
+/// Space separated list of the `classes` whose condition holds.
+fn class_list(classes: &[(&'static str, bool)]) -> String {
+    classes
+        .iter()
+        .filter(|(_, active)| *active)
+        .map(|(class, _)| *class)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// An item of a `ColumnMenu`. Renders as a checkbox item when `checked` is given.
+#[component]
+fn ColumnMenuItem(
+    menu: MenuState,
+    #[prop(into, optional)] disabled: MaybeSignal<bool>,
+    #[prop(into, optional)] checked: Option<Signal<bool>>,
+    on_action: Callback<()>,
+    children: Children,
+) -> impl IntoView {
+    let UseMenuItemReturn { props, .. } = use_menu_item(UseMenuItemInput {
+        menu,
+        disabled,
+        on_action: Some(Callback::new(move |_| on_action.call(()))),
+        // Checkbox items are toggled in place, so that multiple columns can be shown or hidden in a row.
+        close_on_select: checked.is_none(),
+    });
+
+    let mut attrs = props.attrs;
+    if let Some(checked) = checked {
+        attrs.insert("role", Attribute::String(Oco::Borrowed("menuitemcheckbox")));
+        attrs.insert(
+            "aria-checked",
+            Attribute::Fn(Rc::new(move || match checked.get() {
+                true => Attribute::String(Oco::Borrowed("true")),
+                false => Attribute::String(Oco::Borrowed("false")),
+            })),
+        );
+    }
+
+    view! {
+        <leptonic-column-menu-item
+            {..attrs}
+            on:keydown=props.on_key_down
+            on:click=props.on_click
+            on:pointerdown=props.on_pointer_down
+            on:pointerenter=props.on_pointer_enter
+        >
+            { checked.map(|checked| view! {
+                <span class="check" aria-hidden="true">
+                    { move || checked.get().then(|| view! { <Icon icon=icondata::BsCheck/> }) }
+                </span>
+            }) }
+            { children() }
+        </leptonic-column-menu-item>
+    }
+}
+
+/// The opened menu of a `ColumnMenu`.
+#[component]
+#[allow(clippy::too_many_lines)]
+fn ColumnMenuList(
+    /// Index of the column this menu belongs to.
+    column: usize,
+    columns: StoredValue<Vec<(String, ViewProducer)>>,
+    layout: Signal<ColumnLayout>,
+    set_layout: Callback<ColumnLayout>,
+    /// Measures the current width of the column.
+    measure: Callback<(), Option<f64>>,
+    menu_ref: NodeRef<html::Custom>,
+    trigger_ref: NodeRef<html::Button>,
+    menu_id: Oco<'static, str>,
+    attrs: Attributes,
+    focus_strategy: Signal<Option<MenuFocusStrategy>>,
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let UseMenuReturn { props, state } = use_menu(UseMenuInput {
+        node_ref: menu_ref,
+        id: Some(menu_id),
+        disabled: false.into(),
+        auto_focus: focus_strategy.into(),
+        should_focus_wrap: true,
+        aria_labelledby: None,
+        on_close: Some(on_close),
+    });
+    let mut attrs = attrs;
+    attrs.merge(props.attrs);
+    let UseMenuSectionReturn {
+        group_props,
+        separator_props,
+        ..
+    } = use_menu_section(UseMenuSectionInput {
+        has_heading: false,
+        aria_label: Some(Oco::Borrowed("Columns")),
+    });
+
+    // Pressing anywhere outside of the menu closes it.
+    let click_away = window_event_listener(ev::pointerdown, move |e| {
+        let Some(target) = e.target().and_then(|t| t.as_node()) else {
+            return;
+        };
+        let within = |el: Option<web_sys::Element>| el.is_some_and(|el| el.contains(Some(&target)));
+        if !within(
+            menu_ref
+                .get_untracked()
+                .map(|el| el.into_any().deref().clone().into()),
+        ) && !within(
+            trigger_ref
+                .get_untracked()
+                .map(|el| el.deref().clone().into()),
+        ) {
+            on_close.call(());
+        }
+    });
+    on_cleanup(move || click_away.remove());
+
+    let ids = move || {
+        columns.with_value(|columns| columns.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>())
+    };
+    let id = store_value(columns.with_value(|columns| columns[column].0.clone()));
+    let pin = Signal::derive(move || layout.with(|layout| id.with_value(|id| layout.pin(id))));
+    let visible_columns = Signal::derive(move || {
+        layout.with(|layout| {
+            columns.with_value(|columns| {
+                columns
+                    .iter()
+                    .filter(|(id, _)| !layout.is_hidden(id))
+                    .count()
+            })
+        })
+    });
+
+    let pin_to = move |side: Option<ColumnPin>| {
+        let id = id.get_value();
+        let mut next = layout.get_untracked();
+        if side.is_some() && next.width(&id).is_none() {
+            next = next.with_width(&id, measure.call(()));
+        }
+        set_layout.call(next.with_pin(&id, side));
+    };
+    let adjacent = move |forward: bool| {
+        layout.with(|layout| layout.adjacent(&ids(), &id.get_value(), forward))
+    };
+    let move_to = move |forward: bool| {
+        if let Some(target) = adjacent(forward) {
+            let ids = ids();
+            set_layout.call(layout.get_untracked().with_column_moved(
+                &ids,
+                &id.get_value(),
+                &ids[target],
+            ));
+        }
+    };
+    let set_hidden = move |column: &str, hidden: bool| {
+        set_layout.call(layout.get_untracked().with_hidden(column, hidden));
+    };
+
+    let visibility_items = columns
+        .get_value()
+        .into_iter()
+        .map(|(id, header)| {
+            let id = store_value(id);
+            let hidden = Signal::derive(move || layout.with(|layout| id.with_value(|id| layout.is_hidden(id))));
+            view! {
+                <ColumnMenuItem
+                    menu=state
+                    checked=Signal::derive(move || !hidden.get())
+                    // The last visible column can not be hidden.
+                    disabled=Signal::derive(move || !hidden.get() && visible_columns.get() <= 1)
+                    on_action=Callback::new(move |()| set_hidden(&id.get_value(), !hidden.get_untracked()))
+                >
+                    { header.produce() }
+                </ColumnMenuItem>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <leptonic-column-menu-list
+            {..attrs}
+            node_ref=menu_ref
+            on:keydown=props.on_key_down
+            on:focusin=props.on_focus_in
+        >
+            <ColumnMenuItem menu=state disabled=Signal::derive(move || pin.get() == Some(ColumnPin::Left)) on_action=Callback::new(move |()| pin_to(Some(ColumnPin::Left)))>
+                "Pin to left"
+            </ColumnMenuItem>
+            <ColumnMenuItem menu=state disabled=Signal::derive(move || pin.get() == Some(ColumnPin::Right)) on_action=Callback::new(move |()| pin_to(Some(ColumnPin::Right)))>
+                "Pin to right"
+            </ColumnMenuItem>
+            <ColumnMenuItem menu=state disabled=Signal::derive(move || pin.get().is_none()) on_action=Callback::new(move |()| pin_to(None))>
+                "Unpin"
+            </ColumnMenuItem>
+            <ColumnMenuItem menu=state disabled=Signal::derive(move || adjacent(false).is_none()) on_action=Callback::new(move |()| move_to(false))>
+                "Move left"
+            </ColumnMenuItem>
+            <ColumnMenuItem menu=state disabled=Signal::derive(move || adjacent(true).is_none()) on_action=Callback::new(move |()| move_to(true))>
+                "Move right"
+            </ColumnMenuItem>
+            <ColumnMenuItem menu=state disabled=Signal::derive(move || visible_columns.get() <= 1) on_action=Callback::new(move |()| set_hidden(&id.get_value(), true))>
+                "Hide column"
+            </ColumnMenuItem>
+            <leptonic-column-menu-separator {..separator_props.attrs}/>
+            <leptonic-column-menu-group {..group_props.attrs}>
+                { visibility_items }
+            </leptonic-column-menu-group>
+        </leptonic-column-menu-list>
+    }
+}
+
+/// Lets the user pin, move and hide a column, as well as show or hide any other column of the table.
+#[component]
+fn ColumnMenu(
+    /// Index of the column this menu belongs to.
+    column: usize,
+    /// Ids and headers of all columns.
+    columns: StoredValue<Vec<(String, ViewProducer)>>,
+    layout: Signal<ColumnLayout>,
+    set_layout: Callback<ColumnLayout>,
+) -> impl IntoView {
+    let (show, set_show) = create_signal(false);
+    let trigger_ref: NodeRef<html::Button> = create_node_ref();
+    let menu_ref: NodeRef<html::Custom> = create_node_ref();
+    let menu_id: Oco<'static, str> = Oco::Owned(format!("column-menu-{}", uuid::Uuid::new_v4()));
+
+    let UseMenuTriggerReturn {
+        props,
+        menu_props,
+        focus_strategy,
+    } = use_menu_trigger(UseMenuTriggerInput {
+        trigger_ref,
+        menu_ref,
+        disabled: false.into(),
+        menu_id: menu_id.clone(),
+        show: show.into(),
+        on_open_change: Callback::new(move |open| set_show.set(open)),
+        placement_x: PlacementX::End.into(),
+        placement_y: PlacementY::Below.into(),
+        writing_direction: WritingDirection::Ltr.into(),
+    });
+
+    let measure = Callback::new(move |()| {
+        trigger_ref
+            .get_untracked()
+            .and_then(|trigger| trigger.closest("leptonic-table-header-cell").ok().flatten())
+            .map(|header| header.get_bounding_client_rect().width())
+    });
+    let on_close = Callback::new(move |()| set_show.set(false));
+    let menu_attrs = menu_props.attrs;
+
+    // The menu lives inside a header cell. Interacting with it must not sort by or drag the column.
+    view! {
+        <leptonic-column-menu
+            on:click=|e| e.stop_propagation()
+            on:keydown=|e| e.stop_propagation()
+            on:dragstart=|e| {
+                e.prevent_default();
+                e.stop_propagation();
+            }
+        >
+            <button
+                {..props.attrs}
+                node_ref=trigger_ref
+                class="leptonic-column-menu-trigger"
+                aria-label="Column options"
+                on:keydown=props.on_key_down
+                on:click=props.on_click
+                on:pointerdown=props.on_pointer_down
+            >
+                <Icon icon=icondata::BsThreeDotsVertical/>
+            </button>
+            <Show when=move || show.get()>
+                <ColumnMenuList
+                    column=column
+                    columns=columns
+                    layout=layout
+                    set_layout=set_layout
+                    measure=measure
+                    menu_ref=menu_ref
+                    trigger_ref=trigger_ref
+                    menu_id=menu_id.clone()
+                    attrs=menu_attrs.clone()
+                    focus_strategy=focus_strategy
+                    on_close=on_close
+                />
+            </Show>
+        </leptonic-column-menu>
+    }
+}
+
+/// A table rendering `rows` according to the given column definitions.
+/// Sortable columns can be sorted by pressing their header. Hold Shift to sort by multiple columns.
+/// Rows can be selected when a `selection_mode` is set.
+///
+/// Pass a `query` to load rows from elsewhere, e.g. from a server: The table then shows `rows` as given
+/// and emits a new `TableQuery` through `set_query` whenever the user changes the sorting or the page.
+///
+/// Columns can be resized, reordered, pinned and hidden by the user when enabled. The resulting `ColumnLayout`
+/// is kept by the table itself, unless a `layout` is given. Pinned columns stay in place when the table is scrolled
+/// horizontally inside a `TableContainer`.
+#[component]
+#[allow(clippy::too_many_lines, clippy::implicit_hasher)]
+pub fn DataTable<T, K, KF>(
+    #[prop(into)] rows: MaybeSignal<Vec<T>>,
+    columns: Vec<DataTableColumn<T>>,
+    /// Uniquely identifies a row. Used to render rows efficiently and to track the selection.
+    key: KF,
+    #[prop(optional)] selection_mode: SelectionMode,
+    /// Keys of all selected rows.
+    #[prop(into, optional)]
+    selected: MaybeSignal<HashSet<K>>,
+    #[prop(into, optional)] set_selected: Option<Out<HashSet<K>>>,
+    /// The sorting applied initially. Ignored when a `query` is given.
+    #[prop(optional)]
+    sort: Option<SortDescriptor>,
+    /// The query `rows` were loaded with. Sorting and paging is left to whoever loads the rows when set.
+    #[prop(into, optional)]
+    query: Option<Signal<TableQuery>>,
+    #[prop(into, optional)] set_query: Option<Out<TableQuery>>,
+    /// Total number of rows matching the `query`. Shows a `Pagination` below the table when set.
+    #[prop(into, optional)]
+    total_rows: Option<MaybeSignal<u64>>,
+    #[prop(optional)] bordered: Option<bool>,
+    #[prop(optional)] hoverable: Option<bool>,
+    /// Only renders the rows currently visible when set. Use this for tables with many rows.
+    #[prop(optional)]
+    virtualize: Option<VirtualItemSize>,
+    /// Arrangement of the columns. The table keeps track of the layout by itself when not given.
+    #[prop(into, optional)]
+    layout: Option<Signal<ColumnLayout>>,
+    /// Receives the new layout whenever the user rearranged the columns.
+    #[prop(into, optional)]
+    set_layout: Option<Out<ColumnLayout>>,
+    /// Lets the user resize columns by dragging the right border of their header.
+    #[prop(optional)]
+    resizable_columns: bool,
+    /// Lets the user reorder columns by dragging their header onto another one.
+    #[prop(optional)]
+    reorderable_columns: bool,
+    /// Shows a menu in every header, letting the user pin, move and hide columns.
+    #[prop(optional)]
+    column_menu: bool,
+    #[prop(into, optional)] id: Option<AttributeValue>,
+    #[prop(into, optional)] class: Option<AttributeValue>,
+    #[prop(into, optional)] style: Option<AttributeValue>,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    K: Clone + Eq + Hash + 'static,
+    KF: Fn(&T) -> K + 'static,
+{
+    let columns = store_value(columns);
+    let key = store_value(key);
+    let local_sort = create_rw_signal(Vec::from_iter(sort));
+    let sort = Signal::derive(move || match query {
+        Some(query) => query.with(|query| query.sort.clone()),
+        None => local_sort.get(),
+    });
+    let selected = Signal::derive(move || selected.get());
+    let selectable = selection_mode != SelectionMode::None;
+
+    let column_headers = store_value(columns.with_value(|columns| {
+        columns
+            .iter()
+            .map(|column| (column.id.to_string(), column.header))
+            .collect::<Vec<_>>()
+    }));
+    let ids = move || {
+        column_headers
+            .with_value(|columns| columns.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>())
+    };
+    let local_layout = create_rw_signal(ColumnLayout::default());
+    let layout = Signal::derive(move || match layout {
+        Some(layout) => layout.get(),
+        None => local_layout.get(),
+    });
+    let change_layout = Callback::new(move |next: ColumnLayout| {
+        local_layout.set(next.clone());
+        if let Some(set_layout) = set_layout {
+            set_layout.set(next);
+        }
+    });
+    let arranged = create_memo(move |_| layout.with(|layout| layout.arrange(&ids())));
+    let placements = create_memo(move |_| layout.with(|layout| column_placements(layout, &ids())));
+    let placement = move |index: usize| placements.with(|placements| placements[index].clone());
+    let pinned = move |index: usize, side: ColumnPin| {
+        placements.with(|placements| placements[index].as_ref().and_then(|p| p.pin) == Some(side))
+    };
+    let pinned_edge = move |index: usize| {
+        placements.with(|placements| {
+            placements[index]
+                .as_ref()
+                .is_some_and(|p| p.pin.is_some() && p.edge)
+        })
+    };
+    let any_pinned_left = Signal::derive(move || {
+        placements.with(|placements| {
+            placements
+                .iter()
+                .flatten()
+                .any(|p| p.pin == Some(ColumnPin::Left))
+        })
+    });
+
+    // Index of the column whose header is currently dragged, and of the column it would be dropped onto.
+    let dragged = create_rw_signal(Option::<usize>::None);
+    let drop_target = create_rw_signal(Option::<usize>::None);
+    // Column currently resized, together with the pointer position and column width when resizing started.
+    let resizing = store_value(Option::<(usize, i32, f64)>::None);
+    let resize = move |index: usize, width: f64| {
+        let id = column_headers.with_value(|columns| columns[index].0.clone());
+        change_layout.call(
+            layout
+                .get_untracked()
+                .with_width(&id, Some(f64::max(MIN_COLUMN_WIDTH, width.round()))),
+        );
+    };
+
+    let sorted_rows = Signal::derive(move || {
+        let mut rows = rows.get();
+        if query.is_some() {
+            return rows;
+        }
+        let comparators = sort.with(|sort| {
+            columns.with_value(|columns| {
+                sort.iter()
+                    .filter_map(|SortDescriptor { column, direction }| {
+                        columns
+                            .iter()
+                            .find(|c| c.id == *column)
+                            .and_then(|c| c.compare.clone())
+                            .map(|compare| (compare, *direction))
+                    })
+                    .collect::<Vec<_>>()
+            })
+        });
+        if !comparators.is_empty() {
+            rows.sort_by(|a, b| {
+                comparators
+                    .iter()
+                    .map(|(compare, direction)| match direction {
+                        SortDirection::Ascending => compare(a, b),
+                        SortDirection::Descending => compare(b, a),
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+        rows
+    });
+
+    let toggle_sort = move |column: Oco<'static, str>, additive: bool| {
+        let next = toggled_sort(sort.get_untracked(), &column, additive);
+        match (query, set_query) {
+            (Some(query), Some(set_query)) => set_query.set(query.get_untracked().with_sort(next)),
+            (Some(_), None) => {}
+            (None, _) => local_sort.set(next),
+        }
+    };
+
+    let toggle_selected = move |k: K| {
+        let Some(set_selected) = set_selected else {
+            return;
+        };
+        let mut next = selected.get_untracked();
+        match selection_mode {
+            SelectionMode::None => return,
+            SelectionMode::Single => {
+                let was_selected = next.contains(&k);
+                next.clear();
+                if !was_selected {
+                    next.insert(k);
+                }
+            }
+            SelectionMode::Multiple => {
+                if !next.remove(&k) {
+                    next.insert(k);
+                }
+            }
+        }
+        set_selected.set(next);
+    };
+
+    let all_keys = move || {
+        sorted_rows.with(|rows| {
+            rows.iter()
+                .map(|row| key.with_value(|key| key(row)))
+                .collect::<Vec<_>>()
+        })
+    };
+    let all_selected = Signal::derive(move || {
+        let keys = all_keys();
+        !keys.is_empty() && selected.with(|selected| keys.iter().all(|k| selected.contains(k)))
+    });
+    let set_all_selected = move |select: bool| {
+        let Some(set_selected) = set_selected else {
+            return;
+        };
+        let mut next = selected.get_untracked();
+        for k in all_keys() {
+            match select {
+                true => next.insert(k),
+                false => next.remove(&k),
+            };
+        }
+        set_selected.set(next);
+    };
+
+    let header_cell = move |index: usize| {
+        let (column_id, header, sortable) = columns.with_value(|columns| {
+            let column = &columns[index];
+            (column.id.clone(), column.header, column.compare.is_some())
+        });
+        let direction = Signal::derive({
+            let column_id = column_id.clone();
+            move || {
+                sort.with(|sort| {
+                    sort.iter()
+                        .find(|sort| sort.column == column_id)
+                        .map(|sort| sort.direction)
+                })
+            }
+        });
+        let id = column_id.clone();
+        let id2 = column_id.clone();
+        let same_side = move |a: usize, b: usize| {
+            placements.with(|placements| {
+                placements[a].as_ref().map(|p| p.pin) == placements[b].as_ref().map(|p| p.pin)
+            })
+        };
+
+        let resizer = resizable_columns.then(|| view! {
+            <span
+                class="leptonic-column-resizer"
+                role="separator"
+                aria-orientation="vertical"
+                aria-label="Resize column"
+                aria-valuenow=move || placement(index).and_then(|p| p.width).map(|width| width.to_string())
+                tabindex="0"
+                on:pointerdown=move |e| {
+                    if e.button() != 0 {
+                        return;
+                    }
+                    e.stop_propagation();
+                    e.prevent_default();
+                    let Some(resizer) = e.current_target().and_then(|t| t.as_element()) else {
+                        return;
+                    };
+                    let width = resizer
+                        .parent_element()
+                        .map_or(0.0, |header| header.get_bounding_client_rect().width());
+                    if let Err(err) = resizer.set_pointer_capture(e.pointer_id()) {
+                        tracing::warn!(?err, "Could not capture pointer.");
+                    }
+                    resizing.set_value(Some((index, e.client_x(), width)));
+                }
+                on:pointermove=move |e| {
+                    if let Some((resized, x, width)) = resizing.get_value() {
+                        if resized == index {
+                            resize(index, width + f64::from(e.client_x() - x));
+                        }
+                    }
+                }
+                on:pointerup=move |_e| resizing.set_value(None)
+                on:pointercancel=move |_e| resizing.set_value(None)
+                // A click finishing a resize must not sort by the column.
+                on:click=move |e| e.stop_propagation()
+                on:keydown=move |e| {
+                    let step = match e.key().as_str() {
+                        "ArrowLeft" => -COLUMN_RESIZE_STEP,
+                        "ArrowRight" => COLUMN_RESIZE_STEP,
+                        _ => return,
+                    };
+                    e.prevent_default();
+                    e.stop_propagation();
+                    let width = e
+                        .current_target()
+                        .and_then(|t| t.as_element())
+                        .and_then(|resizer| resizer.parent_element())
+                        .map_or(0.0, |header| header.get_bounding_client_rect().width());
+                    resize(index, width + step);
+                }
+            />
+        });
+
+        view! {
+            <TableHeaderCell
+                attr:role="columnheader"
+                attr:class=move || class_list(&[
+                    ("leptonic-order-by-trigger", sortable),
+                    ("leptonic-column-ordered", direction.get().is_some()),
+                    ("leptonic-column-pinned-left", pinned(index, ColumnPin::Left)),
+                    ("leptonic-column-pinned-right", pinned(index, ColumnPin::Right)),
+                    ("leptonic-column-pinned-edge", pinned_edge(index)),
+                    ("leptonic-column-dragged", dragged.get() == Some(index)),
+                    ("leptonic-column-drop-target", drop_target.get() == Some(index)),
+                ])
+                attr:style=move || placement(index).and_then(|p| p.style(selectable))
+                attr:aria-sort=move || sortable.then(|| direction.get().map_or("none", SortDirection::as_aria_sort))
+                attr:tabindex=sortable.then_some("0")
+                attr:draggable=reorderable_columns.then_some("true")
+                on:click=move |e| {
+                    if sortable {
+                        toggle_sort(id.clone(), e.shift_key());
+                    }
+                }
+                on:keydown=move |e| {
+                    if sortable && (e.key() == "Enter" || e.key() == " ") {
+                        e.prevent_default();
+                        toggle_sort(id2.clone(), e.shift_key());
+                    }
+                }
+                on:dragstart=move |e| {
+                    if !reorderable_columns || resizing.get_value().is_some() {
+                        e.prevent_default();
+                        return;
+                    }
+                    dragged.set(Some(index));
+                    if let Some(data) = e.data_transfer() {
+                        data.set_effect_allowed("move");
+                        if let Err(err) = data.set_data("text/plain", &column_id) {
+                            tracing::warn!(?err, "Could not set drag data.");
+                        }
+                    }
+                }
+                on:dragover=move |e| {
+                    // Columns can only be reordered among the columns pinned to the same side.
+                    let Some(from) = dragged.get_untracked() else {
+                        return;
+                    };
+                    if same_side(from, index) {
+                        e.prevent_default();
+                        if drop_target.get_untracked() != Some(index) {
+                            drop_target.set(Some(index));
+                        }
+                    }
+                }
+                on:dragleave=move |_e| {
+                    if drop_target.get_untracked() == Some(index) {
+                        drop_target.set(None);
+                    }
+                }
+                on:drop=move |e| {
+                    e.prevent_default();
+                    if let Some(from) = dragged.get_untracked().filter(|from| *from != index) {
+                        let ids = ids();
+                        change_layout.call(layout.get_untracked().with_column_moved(&ids, &ids[from], &ids[index]));
+                    }
+                    dragged.set(None);
+                    drop_target.set(None);
+                }
+                on:dragend=move |_e| {
+                    dragged.set(None);
+                    drop_target.set(None);
+                }
+            >
+                { header.produce() }
+                { sortable.then(|| view! {
+                    <span class="leptonic-order-by-sign" class:active=move || direction.get().is_some() aria-hidden="true">
+                        { move || match direction.get() {
+                            Some(SortDirection::Descending) => view! { <Icon icon=icondata::BsArrowDown/> },
+                            _ => view! { <Icon icon=icondata::BsArrowUp/> },
+                        } }
+                    </span>
+                }) }
+                { column_menu.then(|| view! {
+                    <ColumnMenu column=index columns=column_headers layout=layout set_layout=change_layout/>
+                }) }
+                { resizer }
+            </TableHeaderCell>
+        }
+    };
+    let header_cells = move || arranged.get().into_iter().map(header_cell).collect_view();
+
+    let row = move |row: T| {
+        let k = key.with_value(|key| key(&row));
+        let k2 = k.clone();
+        let is_selected = Signal::derive(move || selected.with(|selected| selected.contains(&k2)));
+        let cells = move || {
+            arranged
+                .get()
+                .into_iter()
+                .map(|index| {
+                    let cell = columns.with_value(|columns| columns[index].cell);
+                    let row = row.clone();
+                    view! {
+                        <TableCell
+                            attr:role=if selectable { "gridcell" } else { "cell" }
+                            attr:class=move || class_list(&[
+                                ("leptonic-column-pinned-left", pinned(index, ColumnPin::Left)),
+                                ("leptonic-column-pinned-right", pinned(index, ColumnPin::Right)),
+                                ("leptonic-column-pinned-edge", pinned_edge(index)),
+                            ])
+                            attr:style=move || placement(index).and_then(|p| p.style(selectable))
+                        >
+                            { cell.render(row) }
+                        </TableCell>
+                    }
+                })
+                .collect_view()
+        };
+        let k3 = k.clone();
+        view! {
+            <TableRow
+                attr:role="row"
+                attr:class=selectable.then_some("interactable")
+                attr:aria-selected=move || selectable.then(|| is_selected.get().to_string())
+                on:click=move |_e: ev::MouseEvent| toggle_selected(k.clone())
+            >
+                { selectable.then(|| view! {
+                    // The checkbox toggles the selection by itself. The click must not reach the row.
+                    <TableCell
+                        attr:role="gridcell"
+                        attr:class=move || class_list(&[("select", true), ("leptonic-column-pinned-left", any_pinned_left.get())])
+                        on:click=move |e: ev::MouseEvent| e.stop_propagation()
+                    >
+                        <Checkbox checked=is_selected set_checked=move |_| toggle_selected(k3.clone()) attr:aria-label="Select row"/>
+                    </TableCell>
+                }) }
+                { cells }
+            </TableRow>
+        }
+    };
+
+    let pagination = match (query, set_query, total_rows) {
+        (Some(query), Some(set_query), Some(total_rows)) => Some(view! {
+            <Pagination
+                page=Signal::derive(move || query.with(|query| query.page))
+                set_page=move |page| set_query.set(query.get_untracked().with_page(page))
+                page_size=Signal::derive(move || query.with(|query| query.page_size))
+                set_page_size=move |page_size| set_query.set(query.get_untracked().with_page_size(page_size))
+                total_items=total_rows
+            />
+        }),
+        _ => None,
+    };
+
+    view! {
+        <Table
+            bordered=bordered.unwrap_or(false)
+            hoverable=hoverable.unwrap_or(false)
+            id=id
+            class=class
+            style=style
+            attr:role=if selectable { "grid" } else { "table" }
+            attr:aria-multiselectable=(selection_mode == SelectionMode::Multiple).then_some("true")
+        >
+            <TableHeader attr:role="rowgroup">
+                <TableRow attr:role="row">
+                    { match selection_mode {
+                        SelectionMode::None => ().into_view(),
+                        SelectionMode::Single => view! {
+                            <TableHeaderCell
+                                attr:role="columnheader"
+                                attr:class=move || class_list(&[("select", true), ("leptonic-column-pinned-left", any_pinned_left.get())])
+                            >
+                                ""
+                            </TableHeaderCell>
+                        }.into_view(),
+                        SelectionMode::Multiple => view! {
+                            <TableHeaderCell
+                                attr:role="columnheader"
+                                attr:class=move || class_list(&[("select", true), ("leptonic-column-pinned-left", any_pinned_left.get())])
+                            >
+                                <Checkbox checked=all_selected set_checked=set_all_selected attr:aria-label="Select all rows"/>
+                            </TableHeaderCell>
+                        }.into_view(),
+                    } }
+                    { header_cells }
+                </TableRow>
+            </TableHeader>
+            <TableBody attr:role="rowgroup">
+                { match virtualize {
+                    Some(item_size) => view! {
+                        <VirtualList
+                            each=sorted_rows
+                            key=move |row: &T| key.with_value(|key| key(row))
+                            item_size=item_size
+                            children=row
+                        />
+                    }.into_view(),
+                    None => view! {
+                        <For
+                            each=move || sorted_rows.get()
+                            key=move |row| key.with_value(|key| key(row))
+                            children=row
+                        />
+                    }.into_view(),
+                } }
+            </TableBody>
+        </Table>
+        { pagination }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        column_placements, toggled_sort, ColumnLayout, ColumnPin, SortDescriptor, SortDirection,
+        TableQuery,
+    };
+
+    fn by(column: &'static str, direction: SortDirection) -> SortDescriptor {
+        SortDescriptor {
+            column: column.into(),
+            direction,
+        }
+    }
+
+    #[test]
+    fn toggling_sort_reverses_sorted_column() {
+        let sort = toggled_sort(vec![], &"a".into(), false);
+        assert_eq!(sort, vec![by("a", SortDirection::Ascending)]);
+        let sort = toggled_sort(sort, &"a".into(), false);
+        assert_eq!(sort, vec![by("a", SortDirection::Descending)]);
+    }
+
+    #[test]
+    fn toggling_sort_replaces_other_columns_unless_additive() {
+        let sort = vec![by("a", SortDirection::Descending)];
+        assert_eq!(
+            toggled_sort(sort.clone(), &"b".into(), false),
+            vec![by("b", SortDirection::Ascending)]
+        );
+        assert_eq!(
+            toggled_sort(sort, &"b".into(), true),
+            vec![
+                by("a", SortDirection::Descending),
+                by("b", SortDirection::Ascending)
+            ]
+        );
+    }
+
+    #[test]
+    fn changing_page_size_keeps_first_row_visible() {
+        let query = TableQuery::new(10).with_page(3);
+        assert_eq!(query.offset(), 20);
+        let query = query.with_page_size(25);
+        assert_eq!(query.page, 1);
+        assert_eq!(query.page_size, 25);
+        let query = TableQuery::new(10).with_page(8).with_page_size(25);
+        assert_eq!(query.page, 3);
+        let query = query.with_page_size(0);
+        assert_eq!(query.page_size, 1);
+        assert_eq!(query.page, 51);
+        assert_eq!(query.offset(), 50);
+    }
+
+    #[test]
+    fn filtering_returns_to_first_page() {
+        let query = TableQuery::new(10).with_page(4).with_filter("name", "bob");
+        assert_eq!(query.page, 1);
+        assert_eq!(query.filter("name"), Some("bob"));
+        let query = query.with_filter("name", "");
+        assert_eq!(query.filter("name"), None);
+    }
+
+    const IDS: [&str; 4] = ["a", "b", "c", "d"];
+
+    #[test]
+    fn arranges_pinned_columns_at_their_side_and_skips_hidden_ones() {
+        let layout = ColumnLayout::default()
+            .with_pin("c", Some(ColumnPin::Left))
+            .with_pin("a", Some(ColumnPin::Right))
+            .with_hidden("b", true);
+        assert_eq!(layout.arrange(&IDS), vec![2, 3, 0]);
+    }
+
+    #[test]
+    fn moving_a_column_takes_the_place_of_the_target() {
+        let layout = ColumnLayout::default().with_column_moved(&IDS, "a", "c");
+        assert_eq!(layout.arrange(&IDS), vec![1, 2, 0, 3]);
+        let layout = layout.with_column_moved(&IDS, "d", "b");
+        assert_eq!(layout.arrange(&IDS), vec![3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn adjacent_columns_are_pinned_to_the_same_side() {
+        let layout = ColumnLayout::default().with_pin("a", Some(ColumnPin::Left));
+        assert_eq!(layout.adjacent(&IDS, "b", false), None);
+        assert_eq!(layout.adjacent(&IDS, "b", true), Some(2));
+        assert_eq!(layout.adjacent(&IDS, "a", true), None);
+    }
+
+    #[test]
+    fn layout_ignores_unknown_columns() {
+        let layout =
+            serde_json::from_str::<ColumnLayout>(r#"{"order": ["x", "b", "b"], "hidden": ["y"]}"#);
+        assert_eq!(layout.ok().map(|l| l.arrange(&IDS)), Some(vec![1, 0, 2, 3]));
+    }
+
+    #[test]
+    fn pinned_columns_are_offset_by_the_columns_pinned_before_them() {
+        let layout = ColumnLayout::default()
+            .with_pin("a", Some(ColumnPin::Left))
+            .with_width("a", Some(100.0))
+            .with_pin("b", Some(ColumnPin::Left))
+            .with_pin("d", Some(ColumnPin::Right))
+            .with_width("d", Some(80.0));
+        let placements = column_placements(&layout, &IDS)
+            .into_iter()
+            .map(|p| p.map(|p| (p.pin, p.width, p.offset, p.edge)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            placements,
+            vec![
+                Some((Some(ColumnPin::Left), Some(100.0), 0.0, false)),
+                Some((Some(ColumnPin::Left), Some(150.0), 100.0, true)),
+                Some((None, None, 0.0, false)),
+                Some((Some(ColumnPin::Right), Some(80.0), 0.0, true)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use leptos::*;
+use time::format_description::well_known::Rfc3339;
+use web_sys::KeyboardEvent;
+
+use crate::{
+    components::{
+        date_range_selector::{DateRangePreset, DateRangeSelector, DateRangeSelectorProps},
+        form_control::{FormControlContext, HiddenInputs},
+    },
+    prelude::Consumer,
+    utils::{locale::CalendarLocale, time::DayDecoration},
+    Margin, OptMaybeSignal, Out,
+};
+
+/// An input for a range of days, selected in a `DateRangeSelector` shown below the input.
+///
+/// Press Backspace or Delete while the selector is closed to clear the range.
+#[component]
+#[allow(clippy::too_many_lines)]
+pub fn DateRangeInput(
+    #[prop(optional, into)] label: OptMaybeSignal<String>,
+    #[prop(into)] get: MaybeSignal<Option<(time::OffsetDateTime, time::OffsetDateTime)>>,
+    #[prop(into)] set: Out<Option<(time::OffsetDateTime, time::OffsetDateTime)>>,
+    #[prop(into, optional)] id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: Option<Oco<'static, str>>,
+    #[prop(into, optional)] disabled: OptMaybeSignal<bool>,
+    #[prop(optional)] margin: Option<Margin>,
+    /// Submits the first and the last day of the range, formatted as RFC 3339, with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
+    #[prop(optional)] min: Option<time::OffsetDateTime>,
+    #[prop(optional)] max: Option<time::OffsetDateTime>,
+    /// Ranges offered for quick selection next to the calendar.
+    #[prop(optional)]
+    presets: Vec<DateRangePreset>,
+    /// Shows two months side by side.
+    #[prop(optional)]
+    two_months: bool,
+    /// Decides the first day of each week, the names of months and weekdays, and how the range is shown in the input.
+    #[prop(into, optional)]
+    locale: MaybeSignal<CalendarLocale>,
+    /// Shows the ISO 8601 week number in front of each week.
+    #[prop(optional)]
+    show_week_numbers: bool,
+    /// Disables days in addition to those outside of `min` and `max`, Ranges never contain disabled days.
+    #[prop(into, optional)]
+    is_date_disabled: Option<Consumer<time::Date, bool>>,
+    /// Adds CSS classes, a badge or a tooltip to individual days.
+    #[prop(into, optional)]
+    decorate_day: Option<Consumer<time::Date, DayDecoration>>,
+) -> impl IntoView {
+    let locale = Signal::derive(move || locale.get());
+
+    let id = id.map(Oco::into_owned);
+
+    let class = class
+        .map(|it| Oco::from(format!("leptonic-input date-range-selected {it}")))
+        .unwrap_or_else(|| Oco::from("leptonic-input date-range-selected "));
+
+    let style = margin.map(|it| format!("--margin: {it}"));
+
+    let (open, set_open) = create_signal(false);
+    let (in_focus, set_in_focus) = create_signal(false);
+
+    let form_ctrl_ctx = use_context::<FormControlContext>();
+
+    // Closing the selector completes an interaction with this input.
+    if let Some(ctx) = form_ctrl_ctx {
+        create_effect(move |was_open: Option<bool>| {
+            let is_open = open.get();
+            if was_open == Some(true) && !is_open {
+                ctx.touch();
+            }
+            is_open
+        });
+    }
+
+    let on_key_down = move |event: KeyboardEvent| {
+        if !in_focus.get() {
+            return;
+        }
+        match (open.get(), event.key().as_str()) {
+            (false, "ArrowDown" | "Enter" | " ") => {
+                event.prevent_default();
+                set_open.set(true);
+            }
+            (false, "Backspace" | "Delete") => {
+                event.prevent_default();
+                if get.get_untracked().is_some() {
+                    set.set(None);
+                }
+            }
+            (true, "Escape" | "Tab") => set_open.set(false),
+            (_, "Tab") => {}
+            _ => {
+                event.prevent_default();
+                event.stop_propagation();
+            }
+        }
+    };
+
+    let text = move || {
+        get.get()
+            .map(|(start, end)| {
+                locale.with(|locale| {
+                    format!(
+                        "{} – {}",
+                        locale.format_date(start.date()),
+                        locale.format_date(end.date())
+                    )
+                })
+            })
+            .unwrap_or_default()
+    };
+
+    let presets = store_value(presets);
+
+    let range_selector = move || {
+        DateRangeSelector(DateRangeSelectorProps {
+            value: get.get_untracked(),
+            on_change: Out::new_func(move |range| {
+                if get.get_untracked() != Some(range) {
+                    set.set(Some(range));
+                }
+                set_open.set(false);
+            }),
+            min,
+            max,
+            presets: presets.get_value(),
+            two_months,
+            locale: locale.into(),
+            show_week_numbers,
+            is_date_disabled,
+            decorate_day,
+        })
+    };
+
+    view! {
+        <leptonic-date-range-input style=style>
+            <input
+                id=id
+                class=class
+                placeholder=move || match &label.0 {
+                    Some(label) => Oco::from(label.get()),
+                    None => Oco::from(""),
+                }
+                tabindex="0"
+                type="text"
+                prop:disabled=move || disabled.0.as_ref().is_some_and(SignalGet::get)
+                prop:value=text
+                value=text
+                on:click=move |_| set_open.update(|open| *open = !*open)
+                on:focusin=move |_| set_in_focus.set(true)
+                aria-invalid=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_invalid())
+                aria-errormessage=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_errormessage())
+                class:leptonic-validation-error=move || form_ctrl_ctx.is_some_and(|ctx| ctx.is_invalid())
+                on:focusout=move |_| {
+                    set_in_focus.set(false);
+                    if let Some(ctx) = form_ctrl_ctx.filter(|_| !open.get_untracked()) {
+                        ctx.touch();
+                    }
+                }
+                on:keydown=on_key_down
+            />
+            { name.map(|name| view! {
+                <HiddenInputs
+                    name=name
+                    values=Signal::derive(move || get.get().map(|(start, end)| vec![
+                        start.format(&Rfc3339).expect("Formatting to Rfc3339 to be non-fallible."),
+                        end.format(&Rfc3339).expect("Formatting to Rfc3339 to be non-fallible."),
+                    ]).unwrap_or_default())
+                    disabled=Signal::derive(move || disabled.0.as_ref().is_some_and(SignalGet::get))
+                />
+            }) }
+            <div class="date-range-dropdown-menu-ref">
+                <Show when=move || open.get() fallback=|| ()>
+                    <div class="date-range-dropdown-menu">
+                        { range_selector() }
+                    </div>
+                </Show>
+            </div>
+        </leptonic-date-range-input>
+    }
+}
@@ -0,0 +1,254 @@
+use leptos::*;
+
+use crate::{
+    components::date_selector::day_class,
+    hooks::calendar::{use_calendar, UseCalendarInput, UseCalendarReturn},
+    prelude::{Consumer, Producer},
+    utils::{
+        locale::CalendarLocale,
+        time::{clamp_date_time, whole_days_in, DayDecoration, InMonth},
+    },
+    Out,
+};
+
+/// A range of days offered for quick selection next to the calendar of a `DateRangeSelector`.
+#[derive(Debug, Clone)]
+pub struct DateRangePreset {
+    pub label: Oco<'static, str>,
+    /// Produces the first and the last day of the range. Called whenever the preset is shown or selected.
+    pub range: Producer<(time::Date, time::Date)>,
+}
+
+impl DateRangePreset {
+    pub fn new<F>(label: impl Into<Oco<'static, str>>, range: F) -> Self
+    where
+        F: Fn() -> (time::Date, time::Date) + 'static,
+    {
+        Self {
+            label: label.into(),
+            range: Producer::new(range),
+        }
+    }
+
+    /// The last `days` days, ending today.
+    pub fn last_days(label: impl Into<Oco<'static, str>>, days: u16) -> Self {
+        Self::new(label, move || {
+            let today = time::OffsetDateTime::now_utc().date();
+            let first = today - time::Duration::days(i64::from(days.max(1)) - 1);
+            (first, today)
+        })
+    }
+
+    /// The current month, from its first to its last day.
+    pub fn this_month(label: impl Into<Oco<'static, str>>) -> Self {
+        Self::new(label, move || {
+            let today = time::OffsetDateTime::now_utc().date();
+            let first = today - time::Duration::days(i64::from(today.day()) - 1);
+            let last = first
+                + time::Duration::days(i64::from(whole_days_in(first.year(), first.month())) - 1);
+            (first, last)
+        })
+    }
+}
+
+/// Selects a range of days. The first click selects the start of the range, the second one its end.
+/// While selecting the end, the range up to the hovered day is previewed.
+///
+/// Emitted ranges include both of their days. Both days are emitted at midnight, using the offset of the initial `value`
+/// or UTC if there is none.
+#[component]
+pub fn DateRangeSelector(
+    value: Option<(time::OffsetDateTime, time::OffsetDateTime)>,
+    #[prop(into)] on_change: Out<(time::OffsetDateTime, time::OffsetDateTime)>,
+    #[prop(optional)] min: Option<time::OffsetDateTime>,
+    #[prop(optional)] max: Option<time::OffsetDateTime>,
+    /// Ranges offered for quick selection, e.g. `DateRangePreset::last_days("Last 7 days", 7)`.
+    #[prop(optional)]
+    presets: Vec<DateRangePreset>,
+    /// Shows the following month next to the current one.
+    #[prop(optional)]
+    two_months: bool,
+    /// Decides the first day of each week and the names of months and weekdays.
+    #[prop(into, optional)]
+    locale: MaybeSignal<CalendarLocale>,
+    /// Shows the ISO 8601 week number in front of each week.
+    #[prop(optional)]
+    show_week_numbers: bool,
+    /// Disables days in addition to those outside of `min` and `max`. Ranges never contain disabled days.
+    #[prop(into, optional)]
+    is_date_disabled: Option<Consumer<time::Date, bool>>,
+    /// Adds CSS classes, a badge or a tooltip to individual days.
+    #[prop(into, optional)]
+    decorate_day: Option<Consumer<time::Date, DayDecoration>>,
+) -> impl IntoView {
+    let offset = value.map_or(time::UtcOffset::UTC, |(start, _)| start.offset());
+    let initial = value.map_or_else(
+        || {
+            clamp_date_time(
+                time::OffsetDateTime::now_utc().to_offset(offset),
+                min.as_ref(),
+                max.as_ref(),
+            )
+        },
+        |(start, _)| start,
+    );
+
+    let calendar = use_calendar(UseCalendarInput {
+        initial_value: initial,
+        min,
+        max,
+        locale,
+        is_date_disabled,
+    });
+    calendar.set_range(value.map(|(start, end)| (start.date(), end.date())));
+
+    let to_date_time = move |date: time::Date| date.midnight().assume_offset(offset);
+
+    // Only emit ranges selected by the user, not the initial one.
+    create_effect(move |previous: Option<Option<(time::Date, time::Date)>>| {
+        let range = calendar.range.get();
+        if let (Some(_), Some((start, end))) = (previous, range) {
+            on_change.set((to_date_time(start), to_date_time(end)));
+        }
+        range
+    });
+
+    let preset_views = presets
+        .into_iter()
+        .map(|DateRangePreset { label, range }| {
+            let (start, end) = range.produce();
+            let disabled = !calendar.is_range_selectable(start, end);
+            view! {
+                <button
+                    type="button"
+                    class="preset"
+                    disabled=disabled
+                    on:click=move |_| {
+                        let (start, end) = range.produce();
+                        calendar.stage_date(start);
+                        calendar.set_range(Some((start, end)));
+                    }
+                >
+                    { label }
+                </button>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <leptonic-datetime class="date-range">
+            <leptonic-date-range-selector class:previewing=move || calendar.is_selecting_range()>
+                { (!preset_views.is_empty()).then(|| view! {
+                    <div class="presets">
+                        { preset_views }
+                    </div>
+                }) }
+                <div class="months">
+                    <DateRangeMonth calendar=calendar offset=0 previous=true next=!two_months show_week_numbers=show_week_numbers decorate_day=decorate_day/>
+                    { two_months.then(|| view! {
+                        <DateRangeMonth calendar=calendar offset=1 previous=false next=true show_week_numbers=show_week_numbers decorate_day=decorate_day/>
+                    }) }
+                </div>
+            </leptonic-date-range-selector>
+        </leptonic-datetime>
+    }
+}
+
+/// The days of the month lying `offset` months after the staging month of `calendar`.
+#[component]
+#[allow(clippy::large_types_passed_by_value)] // Component props are owned.
+fn DateRangeMonth(
+    calendar: UseCalendarReturn,
+    offset: u8,
+    /// Whether to show the arrow moving to the previous month.
+    previous: bool,
+    /// Whether to show the arrow moving to the next month.
+    next: bool,
+    show_week_numbers: bool,
+    decorate_day: Option<Consumer<time::Date, DayDecoration>>,
+) -> impl IntoView {
+    let month = calendar.staging_after(offset);
+    let weeks = calendar.weeks_after(offset);
+
+    view! {
+        <leptonic-date-selector>
+            <div class="actions">
+                <div on:click=move |_| calendar.select_previous_month()
+                    class="previous arrow-left"
+                    class:invisible=!previous>
+                </div>
+                <div class="current-date">
+                    { move || month.with(|month| {
+                        calendar.locale.with(|locale| locale.format_month_year(month.month(), month.year()))
+                    }) }
+                </div>
+                <div on:click=move |_| calendar.select_next_month()
+                    class="next arrow-right"
+                    class:invisible=!next>
+                </div>
+            </div>
+
+            <div class="weekday-names">
+                { show_week_numbers.then(|| view! {
+                    <div class="week-number">
+                        { move || calendar.locale.with(|locale| locale.week_number_label) }
+                    </div>
+                }) }
+                { move || calendar.weekday_names.get().into_iter().map(|name| view! {
+                    <div class="weekday-name">{ name }</div>
+                }).collect_view() }
+            </div>
+
+            <div class="weeks" on:mouseleave=move |_| calendar.end_range_preview()>
+                <For
+                    each=move || weeks.get()
+                    key=|week| week.id
+                    children=move |week| {
+                        view! {
+                            <div class="week">
+                                { show_week_numbers.then(|| view! {
+                                    <div class="week-number">{ week.number }</div>
+                                }) }
+                                { week.days.into_iter().map(|day| {
+                                    let decoration = decorate_day
+                                        .map(|decorate_day| decorate_day.consume(day.date_time.date()))
+                                        .unwrap_or_default();
+                                    let class = day_class(&decoration);
+                                    view! {
+                                    <div
+                                        on:click=move |_| {
+                                            if !day.disabled {
+                                                calendar.select_range_day(day);
+                                            }
+                                        }
+                                        on:mouseenter=move |_| {
+                                            if !day.disabled {
+                                                calendar.preview_range_day(day);
+                                            }
+                                        }
+                                        class=class
+                                        title=decoration.title
+                                        class:is-now=day.is_now
+                                        class:not-in-month=day.in_month != InMonth::Current
+                                        class:disabled=day.disabled
+                                        class:in-range=day.highlighted
+                                        class:range-start=day.is_range_start
+                                        class:range-end=day.is_range_end
+                                    >
+                                        <span class="text">
+                                            { day.index }
+                                        </span>
+                                        { decoration.badge.map(|badge| view! {
+                                            <span class="badge">{ badge }</span>
+                                        }) }
+                                    </div>
+                                    }
+                                }).collect_view() }
+                            </div>
+                        }
+                    }
+                />
+            </div>
+        </leptonic-date-selector>
+    }
+}
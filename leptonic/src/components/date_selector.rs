@@ -1,8 +1,12 @@
 use leptos::*;
 
 use crate::{
-    hooks::calendar::use_calendar,
-    utils::time::{GuideMode, InMonth},
+    hooks::calendar::{use_calendar, UseCalendarInput},
+    prelude::Consumer,
+    utils::{
+        locale::CalendarLocale,
+        time::{DayDecoration, GuideMode, InMonth},
+    },
     Out,
 };
 
@@ -23,8 +27,26 @@ pub fn DateSelector(
     #[prop(into, optional, default = GuideMode::CalendarFirst.into())] guide_mode: MaybeSignal<
         GuideMode,
     >,
+    /// Decides the first day of each week and the names of months and weekdays.
+    #[prop(into, optional)]
+    locale: MaybeSignal<CalendarLocale>,
+    /// Shows the ISO 8601 week number in front of each week.
+    #[prop(optional)]
+    show_week_numbers: bool,
+    /// Disables days in addition to those outside of `min` and `max`, e.g. weekends or holidays.
+    #[prop(into, optional)]
+    is_date_disabled: Option<Consumer<time::Date, bool>>,
+    /// Adds CSS classes, a badge or a tooltip to individual days.
+    #[prop(into, optional)]
+    decorate_day: Option<Consumer<time::Date, DayDecoration>>,
 ) -> impl IntoView {
-    let calendar = use_calendar(value, min, max);
+    let calendar = use_calendar(UseCalendarInput {
+        initial_value: value,
+        min,
+        max,
+        locale,
+        is_date_disabled,
+    });
 
     create_effect(move |_| on_change.set(calendar.selected.get()));
 
@@ -33,9 +55,6 @@ pub fn DateSelector(
         GuideMode::YearFirst => Selection::Year,
     });
 
-    // TODO: Support internationalization
-    let (short_weekday_names, _) = create_signal(create_week_day_names());
-
     view! {
         <leptonic-datetime>
         <leptonic-date-selector>
@@ -72,7 +91,7 @@ pub fn DateSelector(
                             </div>
                             <div on:click=move |_| set_show.update(|show| *show = Selection::Year)
                                 class="current-date">
-                                { move || calendar.staging_month_name.get() } " " { move || calendar.staging_year.get() }
+                                { move || calendar.locale.with(|locale| locale.format_month_year(calendar.staging.get().month(), calendar.staging_year.get())) }
                             </div>
                             <div on:click=move |_| calendar.select_next_month()
                                 class="next arrow-right">
@@ -135,18 +154,16 @@ pub fn DateSelector(
 
                 <Show when=move || show.get() == Selection::Day fallback=|| ()>
                     <div class={"weekday-names"}>
-                        // Not use For for this...?
-                        <For
-                            each=move || short_weekday_names.get()
-                            key=|short_weekday_name| short_weekday_name.clone()
-                            children=move |short_weekday_name| {
-                                view! {
-                                    <div class={"weekday-name"}>
-                                        {short_weekday_name}
-                                    </div>
-                                }
-                            }
-                        />
+                        { show_week_numbers.then(|| view! {
+                            <div class="week-number">
+                                { move || calendar.locale.with(|locale| locale.week_number_label) }
+                            </div>
+                        }) }
+                        { move || calendar.weekday_names.get().into_iter().map(|short_weekday_name| view! {
+                            <div class={"weekday-name"}>
+                                {short_weekday_name}
+                            </div>
+                        }).collect_view() }
                     </div>
 
                     <div class={"weeks"}>
@@ -156,10 +173,19 @@ pub fn DateSelector(
                             children=move |week| {
                                 view! {
                                     <div class="week">
+                                        { show_week_numbers.then(|| view! {
+                                            <div class="week-number">
+                                                { week.number }
+                                            </div>
+                                        }) }
                                         <For
                                             each=move || week.days.clone()
                                             key=|day| day.id
                                             children=move |day| {
+                                                let decoration = decorate_day
+                                                    .map(|decorate_day| decorate_day.consume(day.date_time.date()))
+                                                    .unwrap_or_default();
+                                                let class = day_class(&decoration);
                                                 view! {
                                                     <div
                                                         on:click=move |_| {
@@ -167,7 +193,8 @@ pub fn DateSelector(
                                                                 calendar.select_day(day);
                                                             }
                                                         }
-                                                        class="day"
+                                                        class=class
+                                                        title=decoration.title
                                                         class:is-staging=day.is_staging
                                                         class:is-now=day.is_now
                                                         class:not-in-month=day.in_month != InMonth::Current
@@ -176,6 +203,9 @@ pub fn DateSelector(
                                                         <span class="text">
                                                             {day.index}
                                                         </span>
+                                                        { decoration.badge.map(|badge| view! {
+                                                            <span class="badge">{ badge }</span>
+                                                        }) }
                                                     </div>
                                                 }
                                             }
@@ -193,18 +223,10 @@ pub fn DateSelector(
     }
 }
 
-pub fn create_week_day_names() -> Vec<String> {
-    //let day_in_month = value.date().day(); // 1 based
-    //let days_from_monday = value.date().weekday().num_days_from_monday(); // 0 based
-    //let monday = value.date().with_day(day_in_month - days_from_monday).format("%a").to_string();
-
-    vec![
-        "Mon".to_owned(),
-        "Tue".to_owned(),
-        "Wed".to_owned(),
-        "Thr".to_owned(),
-        "Fri".to_owned(),
-        "Sat".to_owned(),
-        "Sun".to_owned(),
-    ]
+/// The `class` of a day carrying the given `decoration`.
+pub(crate) fn day_class(decoration: &DayDecoration) -> Oco<'static, str> {
+    match &decoration.class {
+        Some(class) => Oco::from(format!("day {class}")),
+        None => Oco::from("day"),
+    }
 }
@@ -0,0 +1,275 @@
+use leptos::*;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlInputElement, KeyboardEvent};
+
+use crate::{
+    prelude::Consumer,
+    utils::time::{
+        clamp_date_time, default_date_time_format, is_in_range, parse_date_time_segments,
+        DateTimeDraft, DateTimeSegment, HourCycle, Type,
+    },
+    OptMaybeSignal, Out,
+};
+
+/// Typed entry of a date and time. Every part of the value, e.g. the month, is a spin button of its own.
+/// Type its digits, step it with the arrow keys or jump to its ends with Home and End. The focus moves on to the next
+/// part once a part is filled. Periods take "a" and "p".
+///
+/// Emits `None` while a part is empty or the parts do not form an allowed value. An entry forming no allowed value is
+/// marked as invalid.
+#[component]
+#[allow(clippy::too_many_lines)]
+pub fn DateTimeSegments(
+    #[prop(into)] value: Signal<Option<time::OffsetDateTime>>,
+    #[prop(into)] on_change: Out<Option<time::OffsetDateTime>>,
+    /// Format description deciding the parts to enter and their order, e.g. "[day].[month].[year]".
+    /// See `parse_date_time_segments` for the supported components.
+    #[prop(into, optional)]
+    format: Option<Oco<'static, str>>,
+    /// Parts of the value not covered by the format are taken from here. Stepping an empty part starts at its value.
+    /// Defaults to the current time.
+    #[prop(optional)]
+    base: Option<time::OffsetDateTime>,
+    #[prop(optional)] min: Option<time::OffsetDateTime>,
+    #[prop(optional)] max: Option<time::OffsetDateTime>,
+    /// Rejects entered dates in addition to those outside of `min` and `max`.
+    #[prop(into, optional)]
+    is_date_disabled: Option<Consumer<time::Date, bool>>,
+    /// Minutes are stepped in slots of this size.
+    #[prop(optional, default = 1)]
+    minute_step: u8,
+    /// Seconds are stepped in slots of this size.
+    #[prop(optional, default = 1)]
+    second_step: u8,
+    #[prop(into, optional)] id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: Option<Oco<'static, str>>,
+    #[prop(into, optional)] disabled: OptMaybeSignal<bool>,
+    /// Marks the entry as invalid regardless of its value, e.g. because a surrounding form control failed validation.
+    #[prop(into, optional)]
+    invalid: OptMaybeSignal<bool>,
+) -> impl IntoView {
+    let segments = format
+        .and_then(|format| {
+            let segments = parse_date_time_segments(&format);
+            if segments.is_none() {
+                tracing::warn!(%format, "Unsupported date time format. Falling back to the default format.");
+            }
+            segments
+        })
+        .or_else(|| {
+            parse_date_time_segments(&default_date_time_format(
+                Type::DateTime,
+                HourCycle::H24,
+                false,
+            ))
+        })
+        .unwrap_or_default();
+    let segments = store_value(segments);
+
+    let base = move || {
+        value.get_untracked().or(base).unwrap_or_else(|| {
+            clamp_date_time(time::OffsetDateTime::now_utc(), min.as_ref(), max.as_ref())
+        })
+    };
+
+    let draft = create_rw_signal(
+        value
+            .get_untracked()
+            .map(DateTimeDraft::from_date_time)
+            .unwrap_or_default(),
+    );
+    let (rejected, set_rejected) = create_signal(false);
+    let last_emitted = store_value(value.get_untracked());
+
+    // Show values set from the outside, e.g. through a date selector.
+    create_effect(move |_| {
+        let value = value.get();
+        if value != last_emitted.get_value() {
+            last_emitted.set_value(value);
+            draft.set(value.map(DateTimeDraft::from_date_time).unwrap_or_default());
+            set_rejected.set(false);
+        }
+    });
+
+    let is_allowed = move |date_time: &time::OffsetDateTime| {
+        is_in_range(date_time, min.as_ref(), max.as_ref())
+            && !is_date_disabled.is_some_and(|is_disabled| is_disabled.consume(date_time.date()))
+    };
+
+    // Updates the draft and emits the value it forms. Pass `show = false` to keep the text the user typed visible.
+    let commit = move |new_draft: DateTimeDraft, show: bool| {
+        match show {
+            true => draft.set(new_draft),
+            false => draft.set_untracked(new_draft),
+        }
+        let new_value = segments.with_value(|segments| {
+            let new_value = new_draft.build(segments, base()).filter(is_allowed);
+            set_rejected.set(new_draft.is_complete(segments) && new_value.is_none());
+            new_value
+        });
+        if new_value != last_emitted.get_value() {
+            last_emitted.set_value(new_value);
+            on_change.set(new_value);
+        }
+    };
+
+    let is_disabled = move || disabled.0.as_ref().is_some_and(SignalGet::get);
+    let is_invalid = move || rejected.get() || invalid.0.as_ref().is_some_and(SignalGet::get);
+
+    let segment_view = move |segment: DateTimeSegment| {
+        if let DateTimeSegment::Literal(text) = segment {
+            return view! { <span class="literal" aria-hidden="true">{ text }</span> }.into_view();
+        }
+        let width = segment.width();
+        let range = segment.range();
+        let segment = store_value(segment);
+
+        let text = move || segment.with_value(|segment| draft.with(|draft| draft.text(segment)));
+
+        let on_key_down = move |e: KeyboardEvent| {
+            let mut new_draft = draft.get_untracked();
+            let key = e.key();
+            let handled = segment.with_value(|segment| match key.as_str() {
+                "ArrowUp" | "ArrowDown" => {
+                    let delta = if key == "ArrowUp" { 1 } else { -1 };
+                    new_draft.step(segment, delta, base(), minute_step, second_step);
+                    true
+                }
+                "Home" | "End" => {
+                    let (min, max) = range.unwrap_or((0, 1));
+                    new_draft.set(segment, Some(if key == "Home" { min } else { max }));
+                    true
+                }
+                "a" | "A" | "p" | "P" if *segment == DateTimeSegment::Period => {
+                    new_draft.set(segment, Some(i32::from(matches!(key.as_str(), "p" | "P"))));
+                    true
+                }
+                "Backspace" | "Delete" if *segment == DateTimeSegment::Period => {
+                    new_draft.set(segment, None);
+                    true
+                }
+                "Backspace" => {
+                    let input = event_target::<HtmlInputElement>(&e);
+                    if input.value().is_empty() {
+                        e.prevent_default();
+                        focus_sibling_segment(&input, false);
+                    }
+                    false
+                }
+                // Only digits can be typed, and nothing at all into periods.
+                key if key.chars().count() == 1 && !e.ctrl_key() && !e.meta_key() => {
+                    if *segment == DateTimeSegment::Period
+                        || !key.chars().all(|c| c.is_ascii_digit())
+                    {
+                        e.prevent_default();
+                    }
+                    false
+                }
+                _ => false,
+            });
+            if handled {
+                e.prevent_default();
+                commit(new_draft, true);
+            }
+        };
+
+        // Moves on to the next segment once no further digit fits.
+        let on_input = move |e: ev::Event| {
+            let input = event_target::<HtmlInputElement>(&e);
+            let typed = input.value();
+            let filled = segment.with_value(|segment| segment.is_filled(&typed));
+            if filled {
+                let mut new_draft = draft.get_untracked();
+                let entered = typed.parse::<i32>().ok();
+                let accepted = segment.with_value(|segment| {
+                    new_draft.set(segment, entered);
+                    new_draft.get(segment).is_some()
+                });
+                commit(new_draft, accepted);
+                if accepted {
+                    focus_sibling_segment(&input, true);
+                } else {
+                    set_rejected.set(true);
+                }
+            }
+        };
+
+        let on_change = move |e: ev::Event| {
+            let input = event_target::<HtmlInputElement>(&e);
+            let typed = input.value();
+            let mut new_draft = draft.get_untracked();
+            let entered = typed.trim().parse::<i32>().ok();
+            let accepted = segment.with_value(|segment| {
+                if *segment == DateTimeSegment::Period {
+                    return true;
+                }
+                new_draft.set(segment, entered);
+                typed.trim().is_empty() || new_draft.get(segment).is_some()
+            });
+            commit(new_draft, accepted);
+            if !accepted {
+                set_rejected.set(true);
+            }
+        };
+
+        // Typing replaces the value instead of appending to it.
+        let select_text = |e: ev::FocusEvent| event_target::<HtmlInputElement>(&e).select();
+
+        view! {
+            <input
+                type="text"
+                class="segment"
+                style=format!("width: calc({}ch + 0.25em)", width)
+                inputmode=move || segment.with_value(|segment| (*segment != DateTimeSegment::Period).then_some("numeric"))
+                maxlength=width
+                placeholder=move || segment.with_value(DateTimeSegment::placeholder)
+                role="spinbutton"
+                aria-label=move || segment.with_value(DateTimeSegment::label)
+                aria-valuemin=range.map(|(min, _)| min)
+                aria-valuemax=range.map(|(_, max)| max)
+                aria-valuenow=move || segment.with_value(|segment| draft.with(|draft| draft.get(segment)))
+                aria-valuetext=text
+                aria-invalid=move || is_invalid().then_some("true")
+                prop:value=move || text().unwrap_or_default()
+                prop:disabled=is_disabled
+                on:focus=select_text
+                on:keydown=on_key_down
+                on:input=on_input
+                on:change=on_change
+            />
+        }
+        .into_view()
+    };
+
+    view! {
+        <leptonic-date-time-segments
+            id=id
+            class=class
+            role="group"
+            class:leptonic-validation-error=is_invalid
+            class:disabled=is_disabled
+        >
+            { segments.get_value().into_iter().map(segment_view).collect_view() }
+        </leptonic-date-time-segments>
+    }
+}
+
+/// Focuses the next or previous segment input next to `input`, selecting its text.
+fn focus_sibling_segment(input: &HtmlInputElement, forward: bool) {
+    let mut sibling = match forward {
+        true => input.next_element_sibling(),
+        false => input.previous_element_sibling(),
+    };
+    while let Some(element) = sibling {
+        if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+            if input.focus().is_ok() {
+                input.select();
+            }
+            return;
+        }
+        sibling = match forward {
+            true => element.next_element_sibling(),
+            false => element.previous_element_sibling(),
+        };
+    }
+}
@@ -1,14 +1,29 @@
 use leptos::*;
 use time::format_description::well_known::Rfc3339;
+use wasm_bindgen::JsCast;
 use web_sys::KeyboardEvent;
 
 use crate::{
-    components::date_selector::{DateSelector, DateSelectorProps},
-    utils::time::{GuideMode, Type},
+    components::{
+        date_selector::{DateSelector, DateSelectorProps},
+        date_time_segments::{DateTimeSegments, DateTimeSegmentsProps},
+        form_control::{FormControlContext, HiddenInputs},
+        icon::Icon,
+        time_selector::{TimeSelector, TimeSelectorProps},
+    },
+    prelude::Consumer,
+    utils::{
+        locale::CalendarLocale,
+        time::{
+            clamp_date_time, default_date_time_format, parse_date_time_segments, DateTimeEntry,
+            DayDecoration, GuideMode, HourCycle, Type,
+        },
+    },
     Margin, OptMaybeSignal, Out,
 };
 
 #[component]
+#[allow(clippy::too_many_lines)]
 pub fn DateTimeInput(
     #[prop(optional, into)] label: OptMaybeSignal<String>,
     #[prop(into)] get: MaybeSignal<Option<time::OffsetDateTime>>,
@@ -18,15 +33,74 @@ pub fn DateTimeInput(
     #[prop(into, optional)] class: Option<Oco<'static, str>>,
     #[prop(into, optional)] disabled: OptMaybeSignal<bool>,
     #[prop(optional)] margin: Option<Margin>,
+    /// Submits the value with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
 
     #[prop(optional)] min: Option<time::OffsetDateTime>,
     #[prop(optional)] max: Option<time::OffsetDateTime>,
     #[prop(optional)] input_type: Type,
     #[prop(optional)] guide_mode: GuideMode,
+    /// Whether the time selector shows hours from 0 to 23 or from 1 to 12 with an AM/PM period.
+    #[prop(optional)]
+    hour_cycle: HourCycle,
+    /// Minutes are selected in slots of this size, e.g. 15 for quarter hours.
+    #[prop(optional, default = 1)]
+    minute_step: u8,
+    /// Whether the time selector lets the user select seconds.
+    #[prop(optional)]
+    show_seconds: bool,
+    /// Seconds are selected in slots of this size.
+    #[prop(optional, default = 1)]
+    second_step: u8,
+    /// Decides the first day of each week and the names of months and weekdays in the date selector.
+    #[prop(into, optional)]
+    locale: MaybeSignal<CalendarLocale>,
+    /// Shows the ISO 8601 week number in front of each week in the date selector.
+    #[prop(optional)]
+    show_week_numbers: bool,
+    /// Disables days in addition to those outside of `min` and `max`, e.g. weekends or holidays.
+    #[prop(into, optional)]
+    is_date_disabled: Option<Consumer<time::Date, bool>>,
+    /// Adds CSS classes, a badge or a tooltip to individual days.
+    #[prop(into, optional)]
+    decorate_day: Option<Consumer<time::Date, DayDecoration>>,
+    /// Whether the value is selected in a popup, typed into segments, or both.
+    #[prop(optional)]
+    entry: DateTimeEntry,
+    /// Format description of the typed segments and of the text shown in the input, e.g. "[day].[month].[year]".
+    /// Segments default to a format matching `input_type`, `hour_cycle` and `show_seconds`, the shown text to RFC 3339.
+    #[prop(into, optional)]
+    format: Option<Oco<'static, str>>,
     // #[prop(into)] on_open: Option<Callback<()>>,
     // #[prop(into)] on_close: Option<Callback<()>>,
 ) -> impl IntoView {
     let id = id.map(Oco::into_owned);
+    let locale = Signal::derive(move || locale.get());
+    let segments_class = class.clone();
+
+    let display_format = format
+        .as_deref()
+        .and_then(|format| time::format_description::parse_owned::<2>(format).ok());
+    let text = move || {
+        get.get()
+            .and_then(|it| match &display_format {
+                Some(display_format) => it.format(display_format).ok(),
+                None => it.format(&Rfc3339).ok(),
+            })
+            .unwrap_or_default()
+    };
+    let text = Signal::derive(text);
+
+    let segments_format = format
+        .filter(|format| {
+            let supported = parse_date_time_segments(format).is_some();
+            if !supported && entry != DateTimeEntry::Popup {
+                tracing::warn!(%format, "Unsupported date time format. Falling back to the default format.");
+            }
+            supported
+        })
+        .unwrap_or_else(|| Oco::from(default_date_time_format(input_type, hour_cycle, show_seconds)));
 
     let class = class
         .map(|it| Oco::from(format!("leptonic-input datetime-selected {it}")))
@@ -37,6 +111,19 @@ pub fn DateTimeInput(
     let (open, set_open) = create_signal(false);
     let (in_focus, set_in_focus) = create_signal(false);
 
+    let form_ctrl_ctx = use_context::<FormControlContext>();
+
+    // Closing the date selector completes an interaction with this input.
+    if let Some(ctx) = form_ctrl_ctx {
+        create_effect(move |was_open: Option<bool>| {
+            let is_open = open.get();
+            if was_open == Some(true) && !is_open {
+                ctx.touch();
+            }
+            is_open
+        });
+    }
+
     let on_key_down = move |event: KeyboardEvent| {
         let in_focus = in_focus.get();
         let open = open.get();
@@ -59,32 +146,83 @@ pub fn DateTimeInput(
         }
     };
 
+    // Leaving the segments, and not just moving between them, completes an interaction with this input.
+    let on_segments_focus_out = move |e: ev::FocusEvent| {
+        let field = e
+            .current_target()
+            .and_then(|it| it.dyn_into::<web_sys::Node>().ok());
+        let target = e
+            .related_target()
+            .and_then(|it| it.dyn_into::<web_sys::Node>().ok());
+        let stays = field
+            .zip(target)
+            .is_some_and(|(field, target)| field.contains(Some(&target)));
+        if let Some(ctx) = form_ctrl_ctx.filter(|_| !stays && !open.get_untracked()) {
+            ctx.touch();
+        }
+    };
+
+    let on_segments_key_down = move |e: KeyboardEvent| {
+        if e.key() == "Escape" && open.get_untracked() {
+            set_open.set(false);
+        }
+    };
+
+    // The selectors start at the current time when no value is set yet.
+    let initial_value = move || {
+        get.get_untracked().unwrap_or_else(|| {
+            let now = time::OffsetDateTime::now_utc();
+            let now = now.replace_time(
+                time::Time::from_hms(now.hour(), now.minute(), 0).expect("valid time"),
+            );
+            clamp_date_time(now, min.as_ref(), max.as_ref())
+        })
+    };
+
     let date_selector = move || {
+        let initial = initial_value();
         DateSelector(DateSelectorProps {
-            value: get.get().unwrap(),
-            on_change: Out::new_func(move |new_value| {
-                tracing::info!("Received new value {:?}", new_value);
+            value: initial,
+            on_change: Out::new_func(move |new_value: time::OffsetDateTime| {
+                // The date selector emits its initial value. Do not treat this as a selection made by the user.
+                let current = match get.get_untracked() {
+                    Some(current) => current,
+                    None if new_value == initial => return,
+                    None => initial,
+                };
+                // Only take the date, as the time may have been changed through the time selector in the meantime.
+                let new_value = clamp_date_time(
+                    current.replace_date(new_value.date()),
+                    min.as_ref(),
+                    max.as_ref(),
+                );
                 // Skip propagating a change event when the received value does not deviate from the current value.
-                if let Some(current) = get.get() {
-                    if current == new_value {
-                        return;
-                    }
+                if get.get_untracked() != Some(new_value) {
+                    set.set(Some(new_value));
                 }
-                set.set(Some(new_value));
             }),
             min,
             max,
             guide_mode: guide_mode.into(),
+            locale: locale.into(),
+            show_week_numbers,
+            is_date_disabled,
+            decorate_day,
         })
     };
 
     let time_selector = move || {
-        view! {
-            "TODO: Implement the time selector!"
-            // <CrudOffsetDatetimeTimeSelector
-            //     value={this.value.clone().unwrap_or_else(|| time::OffsetDateTime::now_utc())}
-            // />
-        }
+        let initial = initial_value();
+        TimeSelector(TimeSelectorProps {
+            value: Signal::derive(move || get.get().unwrap_or(initial)),
+            on_change: Out::new_func(move |new_value| set.set(Some(new_value))),
+            min,
+            max,
+            hour_cycle,
+            minute_step,
+            show_seconds,
+            second_step,
+        })
     };
 
     view! {
@@ -97,22 +235,73 @@ pub fn DateTimeInput(
                 }.into_view(),
                 None => ().into_view(),
             }}
-            <input
-                id=id
-                class=class
-                placeholder=move || match &label.0 {
-                    Some(label) => Oco::from(label.get()),
-                    None => Oco::from(""),
-                }
-                tabindex="0"
-                type="text"
-                prop:disabled=move || disabled.0.as_ref().map_or(false, SignalGet::get)
-                prop:value=move || get.get().map(|it| it.format(&Rfc3339).expect("Formatting to Rfc3339 to be non-fallible.")).unwrap_or_default()
-                on:click=move |_| set_open.update(|open| *open = !*open)
-                on:focusin=move |_| set_in_focus.set(true)
-                on:focusout=move |_| set_in_focus.set(false)
-                on:keydown=on_key_down
-            />
+            {match entry {
+                DateTimeEntry::Popup => view! {
+                    <input
+                        id=id
+                        class=class
+                        placeholder=move || match &label.0 {
+                            Some(label) => Oco::from(label.get()),
+                            None => Oco::from(""),
+                        }
+                        tabindex="0"
+                        type="text"
+                        prop:disabled=move || disabled.0.as_ref().map_or(false, SignalGet::get)
+                        prop:value=text
+                        value=text
+                        on:click=move |_| set_open.update(|open| *open = !*open)
+                        on:focusin=move |_| set_in_focus.set(true)
+                        aria-invalid=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_invalid())
+                        aria-errormessage=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_errormessage())
+                        class:leptonic-validation-error=move || form_ctrl_ctx.is_some_and(|ctx| ctx.is_invalid())
+                        on:focusout=move |_| {
+                            set_in_focus.set(false);
+                            if let Some(ctx) = form_ctrl_ctx.filter(|_| !open.get_untracked()) {
+                                ctx.touch();
+                            }
+                        }
+                        on:keydown=on_key_down
+                    />
+                }.into_view(),
+                DateTimeEntry::Segments | DateTimeEntry::SegmentsAndPopup => view! {
+                    <div class="datetime-segments-field" on:focusout=on_segments_focus_out on:keydown=on_segments_key_down>
+                        { DateTimeSegments(DateTimeSegmentsProps {
+                            value: Signal::derive(move || get.get()),
+                            on_change: set,
+                            format: Some(segments_format),
+                            base: Some(initial_value()),
+                            min,
+                            max,
+                            is_date_disabled,
+                            minute_step,
+                            second_step,
+                            id: id.map(Oco::from),
+                            class: segments_class,
+                            disabled,
+                            invalid: Signal::derive(move || form_ctrl_ctx.is_some_and(|ctx| ctx.is_invalid())).into(),
+                        }) }
+                        { (entry == DateTimeEntry::SegmentsAndPopup).then(|| view! {
+                            <button
+                                type="button"
+                                class="datetime-popup-toggle"
+                                aria-label="Open calendar"
+                                aria-expanded=move || open.get().to_string()
+                                prop:disabled=move || disabled.0.as_ref().is_some_and(SignalGet::get)
+                                on:click=move |_| set_open.update(|open| *open = !*open)
+                            >
+                                <Icon icon=icondata::BsCalendar3/>
+                            </button>
+                        }) }
+                    </div>
+                }.into_view(),
+            }}
+            { name.map(|name| view! {
+                <HiddenInputs
+                    name=name
+                    values=Signal::derive(move || get.get().and_then(|it| it.format(&Rfc3339).ok()).into_iter().collect())
+                    disabled=Signal::derive(move || disabled.0.as_ref().is_some_and(SignalGet::get))
+                />
+            }) }
             <div class="datetime-dropdown-menu-ref">
                 <Show when=move || open.get() fallback=|| ()>
                     <div class="datetime-dropdown-menu">
@@ -1,6 +1,8 @@
 use leptos::*;
 use leptos_use::{use_interval_fn_with_options, utils::Pausable, UseIntervalFnOptions};
 
+use crate::hooks::{use_focus_scope, UseFocusScopeInput, UseFocusScopeReturn};
+
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DrawerSide {
     #[default]
@@ -33,10 +35,33 @@ pub fn Drawer(
     #[prop(into, optional)] id: Option<AttributeValue>,
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
+    /// Move focus into the drawer when it is opened, keep Tab navigation inside of it
+    /// and give focus back to the previously focused element when it is closed.
+    #[prop(default = true)]
+    contain_focus: bool,
     children: Children,
 ) -> impl IntoView {
     let memoized_shown = create_memo(move |_| shown.get());
 
+    // A drawer which is shown from the start is part of the page layout. Only drawers opened later on take focus.
+    let opened = create_memo(move |was_evaluated: Option<&bool>| {
+        let shown = memoized_shown.get();
+        was_evaluated.is_some() && shown
+    });
+
+    let el: NodeRef<html::Custom> = create_node_ref();
+    let UseFocusScopeReturn {
+        props: focus_scope_props,
+        focus_manager,
+    } = use_focus_scope(UseFocusScopeInput {
+        scope_ref: el,
+        active: Signal::derive(move || contain_focus && opened.get()).into(),
+        contain: true,
+        auto_focus: true,
+        restore_focus: true,
+    });
+    provide_context(focus_manager);
+
     let (anim_state, set_anim_state) = create_signal(match memoized_shown.get_untracked() {
         true => DrawerAnimationState::Shown,
         false => DrawerAnimationState::Hidden,
@@ -99,6 +124,8 @@ pub fn Drawer(
 
     view! {
         <leptonic-drawer
+            {..focus_scope_props.attrs}
+            node_ref=el
             id=id
             class=class
             class:shown=move || anim_state.get() == DrawerAnimationState::Shown
@@ -107,6 +134,7 @@ pub fn Drawer(
             class:hidden=move || anim_state.get() == DrawerAnimationState::Hidden
             style=style
             data-side=side.to_str()
+            on:keydown=focus_scope_props.on_key_down
         >
             { children() }
         </leptonic-drawer>
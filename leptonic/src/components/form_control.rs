@@ -1,32 +1,133 @@
 use std::fmt::Debug;
 
-use leptos::*;
+use leptos::{
+    leptos_dom::{HydrationCtx, HydrationKey},
+    *,
+};
+
+use crate::hooks::FormFieldStatus;
 
 pub trait FormInput: Debug {
     fn on_label_press(&self);
 }
 
+/// Provided by `FormControl` to the input it contains.
+/// To provide a context yourself, start from `FormControlContext::detached()`, e.g. `FormControlContext { input, ..FormControlContext::detached() }`.
 #[derive(Debug, Clone, Copy)]
 pub struct FormControlContext {
     pub input: RwSignal<Option<Box<dyn FormInput>>>,
+
+    /// Validation state of the form field controlled by the input, if any.
+    pub field: Option<FormFieldStatus>,
+
+    /// Makes the ids of elements rendered for this control, like its error message, unique.
+    /// Taken from the hydration context, so that the ids rendered on the server are kept when hydrating.
+    pub id: HydrationKey,
 }
 
+impl FormControlContext {
+    /// A context not controlling any form field. Inputs rendered inside other inputs, like the search input of a `Select`,
+    /// should be placed in such a context, so that they are not mistaken for the input of the surrounding `FormControl`.
+    pub fn detached() -> Self {
+        Self {
+            input: create_rw_signal(None),
+            field: None,
+            id: HydrationCtx::id().unwrap_or_else(HydrationCtx::peek_always),
+        }
+    }
+
+    /// Id of the element showing the error of the field.
+    pub fn error_id(&self) -> String {
+        format!("form-control-error-{}", self.id)
+    }
+
+    /// Whether the field holds an invalid value which should be pointed out to the user.
+    pub fn is_invalid(&self) -> bool {
+        self.field.is_some_and(|field| field.is_invalid())
+    }
+
+    /// Value of the `aria-invalid` attribute of the input.
+    pub fn aria_invalid(&self) -> Option<&'static str> {
+        self.is_invalid().then_some("true")
+    }
+
+    /// Value of the `aria-errormessage` attribute of the input, referencing the error shown by the `FormControl`.
+    pub fn aria_errormessage(&self) -> Option<String> {
+        self.is_invalid().then(|| self.error_id())
+    }
+
+    /// Marks the field as touched. Inputs call this when the user is done interacting with them.
+    pub fn touch(&self) {
+        if let Some(field) = self.field {
+            field.touch();
+        }
+    }
+}
+
+/// Groups an input with its `Label`.
+///
+/// When given a `field`, the input shows whether the field is invalid and the first error of the field is shown below the input,
+/// once the field was touched or its form was submitted.
 #[component]
 pub fn FormControl(
     children: Children,
+    #[prop(into, optional)] field: Option<FormFieldStatus>,
     #[prop(into, optional)] id: Option<AttributeValue>,
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
 ) -> impl IntoView {
-    let input = create_rw_signal(None);
+    let ctx = FormControlContext {
+        field,
+        ..FormControlContext::detached()
+    };
 
-    let ctx = FormControlContext { input };
+    let error = field.map(|field| {
+        view! {
+            <leptonic-form-control-error id=ctx.error_id() class="leptonic-validation-msg-output" aria-live="polite">
+                { move || match field.show_errors.get() {
+                    true => field.errors.with(|errors| errors.first().cloned()),
+                    false => None,
+                } }
+            </leptonic-form-control-error>
+        }
+    });
 
     view! {
-        <leptonic-form-control id=id class=class style=style>
+        <leptonic-form-control
+            id=id
+            class=class
+            style=style
+            data-invalid=move || ctx.is_invalid().then_some("true")
+            aria-busy=move || field.is_some_and(|field| field.validating.get()).then_some("true")
+        >
             <Provider value=ctx>
                 { children() }
             </Provider>
+            { error }
         </leptonic-form-control>
     }
 }
+
+/// Submits `values` under the given `name` with a surrounding native `<form>`, using hidden inputs.
+///
+/// One input is rendered per value, so that multiple values are submitted just like those of a `<select multiple>`.
+/// As the inputs are also rendered on the server, forms can be submitted before the app is hydrated.
+/// Disabled inputs are not submitted.
+#[component]
+pub fn HiddenInputs(
+    #[prop(into)] name: Oco<'static, str>,
+    #[prop(into)] values: Signal<Vec<String>>,
+    #[prop(into, optional)] disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    move || {
+        values
+            .get()
+            .into_iter()
+            .map(|value| {
+                view! {
+                    <input type="hidden" name=name.clone() value=value disabled=move || disabled.get()/>
+                }
+            })
+            .collect_view()
+    }
+}
@@ -40,7 +40,7 @@ fn use_focus<T: ElementDescriptor + Clone + Deref<Target = HtmlInputElement> + '
 
 #[derive(Clone, Copy)]
 pub struct TextInputContext {
-    el: NodeRef<html::Input>,
+    pub(crate) el: NodeRef<html::Input>,
 }
 
 impl TextInputContext {
@@ -83,8 +83,18 @@ pub fn TextInput(
     #[prop(into, optional)] on_focus_change: Option<Out<bool>>,
     #[prop(into, optional)] autofocus: bool,
     #[prop(into, optional)] style: Option<AttributeValue>,
+    /// Submits the value with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<AttributeValue>,
+    /// Reference to the rendered `<input>` element.
+    #[prop(optional)]
+    input_ref: Option<NodeRef<html::Input>>,
+    /// Arbitrary additional attributes of the rendered `<input>` element.
+    #[prop(attrs)]
+    attributes: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView {
-    let node_ref: NodeRef<html::Input> = create_node_ref();
+    let node_ref: NodeRef<html::Input> = input_ref.unwrap_or_else(create_node_ref);
+    let get = Signal::derive(move || get.get());
 
     let ctx = TextInputContext { el: node_ref };
 
@@ -105,6 +115,7 @@ pub fn TextInput(
     view! {
         <leptonic-input style=style>
             <input
+                {..attributes}
                 node_ref=node_ref
                 id=id
                 class=class
@@ -115,10 +126,18 @@ pub fn TextInput(
                 type="text"
                 prop:disabled=move || disabled.0.as_ref().map_or(false, SignalGet::get)
                 prop:value=move || get.get()
+                value=move || get.get()
+                name=name
                 on:change=move |e| { if let Some(set) = &set { set.set(event_target::<HtmlInputElement>(&e).value()) } }
                 on:keyup=move |e| { if let Some(set) = &set { set.set(event_target::<HtmlInputElement>(&e).value()) } }
-                on:blur=move |_e| { if let Some(cb) = &on_focus_change { cb.set(false) }; }
-                on:focus=move |_e| { if let Some(cb) = &on_focus_change { cb.set(true) }; }
+                aria-invalid=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_invalid())
+                aria-errormessage=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_errormessage())
+                class:leptonic-validation-error=move || form_ctrl_ctx.is_some_and(|ctx| ctx.is_invalid())
+                on:blur=move |_e| {
+                    if let Some(ctx) = form_ctrl_ctx { ctx.touch(); }
+                    if let Some(cb) = &on_focus_change { cb.set(false) }
+                }
+                on:focus=move |_e| { if let Some(cb) = &on_focus_change { cb.set(true) } }
             />
             {match prepend.0 {
                 Some(view) => view! {
@@ -145,8 +164,20 @@ pub fn PasswordInput(
     #[prop(into, optional)] on_focus_change: Option<Out<bool>>,
     #[prop(into, optional)] autofocus: bool,
     #[prop(into, optional)] style: Option<AttributeValue>,
+    /// Submits the value with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<AttributeValue>,
 ) -> impl IntoView {
     let node_ref: NodeRef<html::Input> = create_node_ref();
+    let get = Signal::derive(move || get.get());
+
+    let form_ctrl_ctx = use_context::<FormControlContext>();
+
+    if let Some(fc_ctx) = form_ctrl_ctx {
+        fc_ctx
+            .input
+            .set(Some(Box::new(TextInputContext { el: node_ref })));
+    }
 
     if autofocus {
         prepare_autofocus(node_ref);
@@ -169,10 +200,18 @@ pub fn PasswordInput(
                 type="password"
                 prop:disabled=move || disabled.0.as_ref().map_or(false, SignalGet::get)
                 prop:value=move || get.get()
+                value=move || get.get()
+                name=name
                 on:change=move |e| { if let Some(set) = &set { set.set(event_target::<HtmlInputElement>(&e).value()) } }
                 on:keyup=move |e| { if let Some(set) = &set { set.set(event_target::<HtmlInputElement>(&e).value()) } }
-                on:blur=move |_e| { if let Some(cb) = &on_focus_change { cb.set(false) }; }
-                on:focus=move |_e| { if let Some(cb) = &on_focus_change { cb.set(true) }; }
+                aria-invalid=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_invalid())
+                aria-errormessage=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_errormessage())
+                class:leptonic-validation-error=move || form_ctrl_ctx.is_some_and(|ctx| ctx.is_invalid())
+                on:blur=move |_e| {
+                    if let Some(ctx) = form_ctrl_ctx { ctx.touch(); }
+                    if let Some(cb) = &on_focus_change { cb.set(false) }
+                }
+                on:focus=move |_e| { if let Some(cb) = &on_focus_change { cb.set(true) } }
             />
             {match prepend.0 {
                 Some(view) => view! {
@@ -202,9 +241,20 @@ pub fn NumberInput(
     #[prop(into, optional)] on_focus_change: Option<Out<bool>>,
     #[prop(into, optional)] autofocus: bool,
     #[prop(into, optional)] style: Option<AttributeValue>,
+    /// Submits the value with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<AttributeValue>,
 ) -> impl IntoView {
     let node_ref: NodeRef<html::Input> = create_node_ref();
 
+    let form_ctrl_ctx = use_context::<FormControlContext>();
+
+    if let Some(fc_ctx) = form_ctrl_ctx {
+        fc_ctx
+            .input
+            .set(Some(Box::new(TextInputContext { el: node_ref })));
+    }
+
     if autofocus {
         prepare_autofocus(node_ref);
     }
@@ -249,10 +299,18 @@ pub fn NumberInput(
                 step=move || step.0.as_ref().map(SignalGet::get).unwrap_or(0.0)
                 prop:disabled=move || disabled.0.as_ref().map(SignalGet::get).unwrap_or(false)
                 prop:value=move || get.get()
+                value=move || get.get()
+                name=name
                 on:change=move |e| { if let Some(set_value) = &set_value { set_value(event_target::<HtmlInputElement>(&e).value()) } }
                 on:keyup=move |e| { if let Some(set_value) = &set_value { set_value(event_target::<HtmlInputElement>(&e).value()) } }
-                on:blur=move |_e| { if let Some(cb) = &on_focus_change { cb.set(false) }; }
-                on:focus=move |_e| { if let Some(cb) = &on_focus_change { cb.set(true) }; }
+                aria-invalid=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_invalid())
+                aria-errormessage=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_errormessage())
+                class:leptonic-validation-error=move || form_ctrl_ctx.is_some_and(|ctx| ctx.is_invalid())
+                on:blur=move |_e| {
+                    if let Some(ctx) = form_ctrl_ctx { ctx.touch(); }
+                    if let Some(cb) = &on_focus_change { cb.set(false) }
+                }
+                on:focus=move |_e| { if let Some(cb) = &on_focus_change { cb.set(true) } }
             />
             {match prepend.0 {
                 Some(view) => view! {
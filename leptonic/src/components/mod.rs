@@ -7,7 +7,12 @@ pub mod checkbox;
 pub mod chip;
 pub mod collapsible;
 pub mod color_picker;
+pub mod combobox;
+pub mod data_table;
+pub mod date_range_input;
+pub mod date_range_selector;
 pub mod date_selector;
+pub mod date_time_segments;
 pub mod datetime_input;
 pub mod drawer;
 pub mod field;
@@ -19,6 +24,7 @@ pub mod kbd;
 pub mod label;
 pub mod link;
 pub mod modal;
+pub mod pagination;
 pub mod popover;
 pub mod progress_bar;
 pub mod quicksearch;
@@ -35,12 +41,15 @@ pub mod table;
 pub mod tabs;
 pub mod theme;
 pub mod tile;
+pub mod time_selector;
 #[cfg(feature = "tiptap")]
 pub mod tiptap_editor;
 pub mod toast;
 pub mod toggle;
+pub mod tooltip;
 pub mod transitions;
 pub mod typography;
+pub mod virtual_list;
 
 pub mod prelude {
     pub use super::alert::Alert;
@@ -72,13 +81,29 @@ pub mod prelude {
     pub use super::color_picker::ColorPicker;
     pub use super::color_picker::ColorPreview;
     pub use super::color_picker::HueSlider;
+    pub use super::combobox::Combobox;
+    pub use super::data_table::ColumnLayout;
+    pub use super::data_table::ColumnPin;
+    pub use super::data_table::DataTable;
+    pub use super::data_table::DataTableColumn;
+    pub use super::data_table::DataTableComparator;
+    pub use super::data_table::SelectionMode;
+    pub use super::data_table::SortDescriptor;
+    pub use super::data_table::SortDirection;
+    pub use super::data_table::TableFilter;
+    pub use super::data_table::TableQuery;
+    pub use super::date_range_input::DateRangeInput;
+    pub use super::date_range_selector::DateRangePreset;
+    pub use super::date_range_selector::DateRangeSelector;
     pub use super::date_selector::DateSelector;
+    pub use super::date_time_segments::DateTimeSegments;
     pub use super::datetime_input::DateTimeInput;
     pub use super::drawer::Drawer;
     pub use super::drawer::DrawerSide;
     pub use super::field::Field;
     pub use super::field::FieldLabel;
     pub use super::form_control::FormControl;
+    pub use super::form_control::HiddenInputs;
     pub use super::grid::Col;
     pub use super::grid::ColAlign;
     pub use super::grid::Grid;
@@ -102,8 +127,12 @@ pub mod prelude {
     pub use super::modal::ModalHeader;
     pub use super::modal::ModalRoot;
     pub use super::modal::ModalTitle;
+    pub use super::pagination::Pagination;
+    pub use super::pagination::PaginationItem;
     pub use super::popover::Popover;
+    #[allow(deprecated)]
     pub use super::popover::PopoverAlignX;
+    #[allow(deprecated)]
     pub use super::popover::PopoverAlignY;
     pub use super::popover::PopoverContent;
     pub use super::progress_bar::ProgressBar;
@@ -146,6 +175,7 @@ pub mod prelude {
     pub use super::theme::ThemeProvider;
     pub use super::theme::ThemeToggle;
     pub use super::tile::Tile;
+    pub use super::time_selector::TimeSelector;
     #[cfg(feature = "tiptap")]
     pub use super::tiptap_editor::TiptapEditor;
     pub use super::toast::Toast;
@@ -157,6 +187,8 @@ pub mod prelude {
     pub use super::toggle::ToggleIcons;
     pub use super::toggle::ToggleSize;
     pub use super::toggle::ToggleVariant;
+    pub use super::tooltip::Tooltip;
+    pub use super::tooltip::TooltipContent;
     pub use super::transitions::collapse::Collapse;
     pub use super::transitions::collapse::CollapseAxis;
     pub use super::transitions::fade::Fade;
@@ -173,4 +205,8 @@ pub mod prelude {
     pub use super::typography::H5;
     pub use super::typography::H6;
     pub use super::typography::P;
+    pub use super::virtual_list::VirtualList;
+    pub use crate::hooks::SliderOrientation;
+    pub use crate::hooks::VirtualAlign;
+    pub use crate::hooks::VirtualItemSize;
 }
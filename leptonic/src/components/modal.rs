@@ -3,6 +3,7 @@ use std::rc::Rc;
 use uuid::Uuid;
 
 use crate::{
+    atoms::focus_scope::FocusScope,
     hooks::{use_press, use_prevent_scroll, UsePressInput, UsePressReturn, UsePreventScrollInput},
     prelude::{GlobalKeyboardEvent, Producer},
     OptMaybeSignal,
@@ -113,6 +114,10 @@ pub fn Modal(
     #[prop(into, optional)] class: Option<String>,
     #[prop(into, optional)] on_escape: Option<Producer<()>>,
     #[prop(into, optional)] on_backdrop_interaction: Option<Producer<()>>,
+    /// Move focus into the modal when it is shown, keep Tab navigation inside of it
+    /// and give focus back to the previously focused element when it is hidden.
+    #[prop(default = true)]
+    contain_focus: bool,
     children: ChildrenFn,
 ) -> impl IntoView {
     let ctx = expect_context::<ModalRootContext>();
@@ -135,9 +140,12 @@ pub fn Modal(
     let class = store_value(class);
 
     let modal = Rc::new(move || {
+        let children = children.clone();
         view! {
             <leptonic-modal id=id.get_value() class=class.get_value()>
-                { children() }
+                <FocusScope active=contain_focus>
+                    { children() }
+                </FocusScope>
             </leptonic-modal>
         }
         .into_view()
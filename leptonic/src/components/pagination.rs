@@ -0,0 +1,277 @@
+use leptos::*;
+
+use crate::{
+    components::{icon::Icon, select::Select},
+    Out,
+};
+
+/// An entry of the list of pages shown by a `Pagination`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaginationItem {
+    /// The page with this (1-based) number.
+    Page(u64),
+
+    /// Placeholder for a range of pages not shown.
+    Ellipsis,
+}
+
+/// Lists the pages to show when `current` is selected out of `page_count` pages.
+///
+/// The first and last `boundaries` pages, as well as `siblings` pages on both sides of the current page, are always shown.
+/// All other ranges of pages are collapsed into an ellipsis, as long as the ellipsis replaces more than one page.
+/// The number of items returned stays the same regardless of which page is current.
+pub fn pagination_items(
+    current: u64,
+    page_count: u64,
+    siblings: u64,
+    boundaries: u64,
+) -> Vec<PaginationItem> {
+    // Boundaries, siblings, the current page and two ellipses.
+    if page_count <= 2 * boundaries + 2 * siblings + 3 {
+        return (1..=page_count).map(PaginationItem::Page).collect();
+    }
+    let current = current.clamp(1, page_count);
+
+    let siblings_start = u64::max(
+        u64::min(
+            current.saturating_sub(siblings),
+            page_count - boundaries - 2 * siblings - 1,
+        ),
+        boundaries + 2,
+    );
+    let siblings_end = u64::min(
+        u64::max(current + siblings, boundaries + 2 * siblings + 2),
+        page_count - boundaries - 1,
+    );
+
+    let mut items = Vec::new();
+    items.extend((1..=boundaries).map(PaginationItem::Page));
+    if siblings_start > boundaries + 2 {
+        items.push(PaginationItem::Ellipsis);
+    } else {
+        items.push(PaginationItem::Page(boundaries + 1));
+    }
+    items.extend((siblings_start..=siblings_end).map(PaginationItem::Page));
+    if siblings_end < page_count - boundaries - 1 {
+        items.push(PaginationItem::Ellipsis);
+    } else {
+        items.push(PaginationItem::Page(page_count - boundaries));
+    }
+    items.extend((page_count - boundaries + 1..=page_count).map(PaginationItem::Page));
+    items
+}
+
+/// Number of pages needed to show `total_items` items, `page_size` items at a time. There is always at least one page.
+pub fn page_count(total_items: u64, page_size: u64) -> u64 {
+    match page_size {
+        0 => 1,
+        page_size => u64::max(
+            1,
+            total_items / page_size + u64::from(total_items % page_size != 0),
+        ),
+    }
+}
+
+/// Lets the user navigate through `total_items` items shown `page_size` items at a time.
+///
+/// Pages are numbered starting at 1. When `set_page_size` is given, the user can choose one of the `page_sizes`.
+/// The current page is left untouched when the page size changes. Use `TableQuery::with_page_size` to stay on the page showing the same items.
+#[component]
+#[allow(clippy::too_many_lines)]
+pub fn Pagination(
+    /// The current (1-based) page.
+    #[prop(into)]
+    page: Signal<u64>,
+    #[prop(into)] set_page: Out<u64>,
+    #[prop(into)] page_size: MaybeSignal<u64>,
+    #[prop(into, optional)] set_page_size: Option<Out<u64>>,
+    /// The page sizes the user can choose from.
+    #[prop(optional, default = vec![10, 25, 50, 100])]
+    page_sizes: Vec<u64>,
+    #[prop(into)] total_items: MaybeSignal<u64>,
+    /// Number of pages always shown on both sides of the current page.
+    #[prop(optional, default = 1)]
+    siblings: u64,
+    /// Number of pages always shown at the start and end of the list.
+    #[prop(optional, default = 1)]
+    boundaries: u64,
+    #[prop(into, optional)] id: Option<AttributeValue>,
+    #[prop(into, optional)] class: Option<AttributeValue>,
+    #[prop(into, optional)] style: Option<AttributeValue>,
+) -> impl IntoView {
+    let page_count = Signal::derive(move || page_count(total_items.get(), page_size.get()));
+    let current = Signal::derive(move || page.get().clamp(1, page_count.get()));
+    let items = Signal::derive(move || {
+        pagination_items(current.get(), page_count.get(), siblings, boundaries)
+    });
+
+    let go_to = move |page: u64| {
+        let page = page.clamp(1, page_count.get_untracked());
+        if page != current.get_untracked() {
+            set_page.set(page);
+        }
+    };
+
+    let info = move || {
+        let total = total_items.get();
+        let first = u64::min(total, (current.get() - 1) * page_size.get() + 1);
+        let last = u64::min(total, current.get() * page_size.get());
+        format!("{first} - {last} of {total}")
+    };
+
+    let page_size_selector = set_page_size.map(|set_page_size| {
+        let selected = Signal::derive(move || page_size.get());
+        view! {
+            <div class="items-per-page-selector">
+                <span class="label">"Items per page"</span>
+                <Select
+                    options=page_sizes
+                    search_text_provider=move |size: u64| size.to_string()
+                    render_option=move |size: u64| size.to_string()
+                    selected=selected
+                    set_selected=move |size: u64| set_page_size.set(size)
+                />
+            </div>
+        }
+    });
+
+    view! {
+        <nav id=id class=class class:leptonic-pagination=true style=style aria-label="Pagination">
+            { page_size_selector }
+
+            <span class="pagination-info">{ info }</span>
+
+            <ul class="pages">
+                <li>
+                    <button
+                        class="page"
+                        aria-label="Previous page"
+                        disabled=move || current.get() <= 1
+                        on:click=move |_e| go_to(current.get_untracked() - 1)
+                    >
+                        <Icon icon=icondata::BsChevronLeft/>
+                    </button>
+                </li>
+                { move || items.get().into_iter().map(|item| match item {
+                    PaginationItem::Page(page) => view! {
+                        <li>
+                            <button
+                                class="page"
+                                aria-label=format!("Page {page}")
+                                aria-current=move || (current.get() == page).then_some("page")
+                                on:click=move |_e| go_to(page)
+                            >
+                                { page }
+                            </button>
+                        </li>
+                    },
+                    PaginationItem::Ellipsis => view! {
+                        <li>
+                            <span class="ellipsis" aria-hidden="true">"…"</span>
+                        </li>
+                    },
+                }).collect_view() }
+                <li>
+                    <button
+                        class="page"
+                        aria-label="Next page"
+                        disabled=move || current.get() >= page_count.get()
+                        on:click=move |_e| go_to(current.get_untracked() + 1)
+                    >
+                        <Icon icon=icondata::BsChevronRight/>
+                    </button>
+                </li>
+            </ul>
+        </nav>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{page_count, pagination_items, PaginationItem::Ellipsis, PaginationItem::Page};
+
+    #[test]
+    fn shows_all_pages_when_there_are_few() {
+        assert_eq!(
+            pagination_items(3, 7, 1, 1),
+            (1..=7).map(Page).collect::<Vec<_>>()
+        );
+        assert_eq!(pagination_items(1, 1, 1, 1), vec![Page(1)]);
+    }
+
+    #[test]
+    fn collapses_pages_behind_current_page() {
+        assert_eq!(
+            pagination_items(1, 10, 1, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Ellipsis,
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn collapses_pages_around_current_page() {
+        assert_eq!(
+            pagination_items(5, 10, 1, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                Ellipsis,
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn collapses_pages_in_front_of_current_page() {
+        assert_eq!(
+            pagination_items(10, 10, 1, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9),
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn respects_siblings_and_boundaries() {
+        assert_eq!(
+            pagination_items(10, 20, 2, 2),
+            vec![
+                Page(1),
+                Page(2),
+                Ellipsis,
+                Page(8),
+                Page(9),
+                Page(10),
+                Page(11),
+                Page(12),
+                Ellipsis,
+                Page(19),
+                Page(20)
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_pages() {
+        assert_eq!(page_count(0, 10), 1);
+        assert_eq!(page_count(10, 10), 1);
+        assert_eq!(page_count(11, 10), 2);
+        assert_eq!(page_count(11, 0), 1);
+    }
+}
@@ -4,25 +4,32 @@ use leptos::*;
 use leptos_use::{use_element_bounding, use_element_hover};
 use uuid::Uuid;
 
-use crate::{prelude::Consumer, Size, UseElementBoundingReturnReadOnly};
+use crate::{
+    hooks::{
+        use_popover, OverlayBoundary, PlacementX, PlacementY, UsePopoverInput, UsePopoverReturn,
+    },
+    prelude::Consumer,
+    utils::{aria::AriaHasPopup, locale::WritingDirection, NodeRefExt},
+    Size, UseElementBoundingReturnReadOnly,
+};
 
 #[derive(Clone)]
-struct PopoverData {
-    key: Uuid,
-    children: ChildrenFn,
+pub(crate) struct PopoverData {
+    pub(crate) key: Uuid,
+    pub(crate) children: ChildrenFn,
 }
 
 #[derive(Clone)]
-struct PopoverRootContext {
+pub(crate) struct PopoverRootContext {
     popovers: RwSignal<Vec<PopoverData>>,
 }
 
 impl PopoverRootContext {
-    fn push(&self, data: PopoverData) {
+    pub(crate) fn push(&self, data: PopoverData) {
         self.popovers.update(move |p| p.push(data));
     }
 
-    fn remove(&self, key: Uuid) {
+    pub(crate) fn remove(&self, key: Uuid) {
         self.popovers.update(move |p| {
             if let Some(idx) = p.iter().position(|it| it.key == key) {
                 p.remove(idx);
@@ -51,6 +58,7 @@ pub(crate) fn PopoverRoot(children: Children) -> impl IntoView {
     }
 }
 
+#[deprecated(note = "Use `PlacementX` together with the `placement_x` prop instead.")]
 #[derive(Debug, Clone, Copy)]
 pub enum PopoverAlignX {
     Left,
@@ -58,6 +66,18 @@ pub enum PopoverAlignX {
     Right,
 }
 
+#[allow(deprecated)]
+impl From<PopoverAlignX> for PlacementX {
+    fn from(align: PopoverAlignX) -> Self {
+        match align {
+            PopoverAlignX::Left => Self::Left,
+            PopoverAlignX::Center => Self::Center,
+            PopoverAlignX::Right => Self::Right,
+        }
+    }
+}
+
+#[deprecated(note = "Use `PlacementY` together with the `placement_y` prop instead.")]
 #[derive(Debug, Clone, Copy)]
 pub enum PopoverAlignY {
     Top,
@@ -65,6 +85,23 @@ pub enum PopoverAlignY {
     Bottom,
 }
 
+#[allow(deprecated)]
+impl From<PopoverAlignY> for PlacementY {
+    fn from(align: PopoverAlignY) -> Self {
+        match align {
+            PopoverAlignY::Top => Self::Above,
+            PopoverAlignY::Center => Self::Center,
+            PopoverAlignY::Bottom => Self::Below,
+        }
+    }
+}
+
+// Lets the deprecated `align_x` and `align_y` props of `Popover` be declared without warnings.
+#[allow(deprecated)]
+type LegacyAlignX = PopoverAlignX;
+#[allow(deprecated)]
+type LegacyAlignY = PopoverAlignY;
+
 #[derive(Clone)]
 #[slot]
 pub struct PopoverContent {
@@ -77,22 +114,45 @@ pub fn Popover(
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
 
-    #[prop(default = PopoverAlignX::Center)] align_x: PopoverAlignX,
-    #[prop(default = PopoverAlignY::Top)] align_y: PopoverAlignY,
+    #[prop(into, default = PlacementX::Center.into())] placement_x: MaybeSignal<PlacementX>,
+    #[prop(into, default = PlacementY::Above.into())] placement_y: MaybeSignal<PlacementY>,
+    #[prop(into, default = WritingDirection::Ltr.into())] writing_direction: MaybeSignal<
+        WritingDirection,
+    >,
+
+    /// Deprecated: Use `placement_x` instead. Overrides `placement_x` when set.
+    #[prop(optional)]
+    align_x: Option<LegacyAlignX>,
+    /// Deprecated: Use `placement_y` instead. Overrides `placement_y` when set.
+    #[prop(optional)]
+    align_y: Option<LegacyAlignY>,
 
-    /// Margin.
+    /// Distance between the popover and the element it is attached to.
+    /// Percentages and `Size::Auto` are not supported and result in no distance.
     #[prop(default = Size::Em(0.25))]
     margin: Size,
 
-    /// Custom X position of the popover.
+    /// The area the popover is kept inside of when being flipped or shifted.
+    #[prop(optional)]
+    boundary: OverlayBoundary,
+
+    /// Render an arrow pointing at the element the popover is attached to.
+    #[prop(optional)]
+    arrow: bool,
+
+    /// Custom X position of the popover. Overrides the position derived from `placement_x`.
     #[prop(optional)]
     position_x: Option<Consumer<UseElementBoundingReturnReadOnly, String>>,
 
-    /// Custom Y position of the popover.
+    /// Custom Y position of the popover. Overrides the position derived from `placement_y`.
     #[prop(optional)]
     position_y: Option<Consumer<UseElementBoundingReturnReadOnly, String>>,
 
-    #[prop(into, optional)] show: Option<MaybeSignal<bool>>,
+    /// Controls the visibility of the popover.
+    /// If not set, the popover is shown while hovered and toggled by clicks. It can then be dismissed by
+    /// clicking anywhere else or pressing Escape.
+    #[prop(into, optional)]
+    show: Option<MaybeSignal<bool>>,
 
     popover_content: PopoverContent,
 
@@ -103,11 +163,15 @@ pub fn Popover(
     let ctx = expect_context::<PopoverRootContext>();
 
     let el: NodeRef<html::Custom> = create_node_ref();
-    let el_bounds = use_element_bounding(el);
 
     let pop_el: NodeRef<html::Custom> = create_node_ref();
     let pop_bounds = use_element_bounding(pop_el);
+    let arrow_el: NodeRef<html::Custom> = create_node_ref();
 
+    let placement_x = align_x.map_or(placement_x, |align| PlacementX::from(align).into());
+    let placement_y = align_y.map_or(placement_y, |align| PlacementY::from(align).into());
+
+    let is_controlled = show.is_some();
     let show: MaybeSignal<bool> = match show {
         Some(show) => show,
         None => {
@@ -116,50 +180,48 @@ pub fn Popover(
         }
     };
 
+    let UsePopoverReturn {
+        id: _,
+        trigger_props,
+        props,
+        placement_x: _,
+        placement_y: _,
+        arrow_props,
+    } = use_popover(UsePopoverInput {
+        trigger_ref: el,
+        popover_ref: pop_el,
+        disabled: false.into(),
+        show: Signal::derive(move || show.get()),
+        on_open_change: Callback::new(move |show| set_clicked.set(show)),
+        popover_type: AriaHasPopup::Dialog,
+        dismiss_on_click_away: !is_controlled,
+        dismiss_on_escape: !is_controlled,
+        contain_focus: false,
+        placement_x,
+        placement_y,
+        writing_direction,
+        offset: MaybeSignal::derive(move || margin_to_px(margin, pop_el)),
+        boundary,
+        arrow_ref: arrow.then(|| Signal::derive(move || arrow_el.get_element())),
+    });
+
+    let mut pop_attrs = props.attrs;
+    let position_style = pop_attrs.map.remove("style");
+
     let pop_bounds_read_only: UseElementBoundingReturnReadOnly = pop_bounds.into();
 
-    let pop_style: Signal<String> = Signal::derive(move || match show.get() {
-        true => {
-            let left = match position_x {
-                Some(pos_x) => pos_x.consume(pop_bounds_read_only),
-                None => {
-                    let x = match align_x {
-                        PopoverAlignX::Left => el_bounds.x.get(),
-                        PopoverAlignX::Center => {
-                            el_bounds.x.get() + (el_bounds.width.get() / 2.0)
-                                - (pop_bounds_read_only.width.get() / 2.0)
-                        }
-                        PopoverAlignX::Right => el_bounds.x.get(),
-                    };
-
-                    match align_x {
-                        PopoverAlignX::Left => format!("calc({}px - {})", x, margin),
-                        PopoverAlignX::Center => format!("{}px", x),
-                        PopoverAlignX::Right => format!("calc({}px + {})", x, margin),
-                    }
-                }
-            };
-
-            let top = match position_y {
-                Some(pos_y) => pos_y.consume(pop_bounds_read_only),
-                None => {
-                    let y = match align_y {
-                        PopoverAlignY::Top => el_bounds.y.get() - pop_bounds_read_only.height.get(),
-                        PopoverAlignY::Center => el_bounds.y.get(),
-                        PopoverAlignY::Bottom => el_bounds.y.get(),
-                    };
-
-                    match align_y {
-                        PopoverAlignY::Top => format!("calc({}px - {})", y, margin),
-                        PopoverAlignY::Center => format!("{}px", y),
-                        PopoverAlignY::Bottom => format!("calc({}px + {})", y, margin),
-                    }
-                }
-            };
-
-            format!("left: {}; top: {};", left, top)
-        }
-        false => String::new(),
+    let pop_style: Signal<String> = Signal::derive(move || {
+        let position = position_style
+            .as_ref()
+            .and_then(Attribute::as_nameless_value_string)
+            .unwrap_or_default();
+        let left = position_x
+            .map(|pos_x| format!("; left: {}", pos_x.consume(pop_bounds_read_only)))
+            .unwrap_or_default();
+        let top = position_y
+            .map(|pos_y| format!("; top: {}", pos_y.consume(pop_bounds_read_only)))
+            .unwrap_or_default();
+        format!("{position}{left}{top}")
     });
 
     let key = Uuid::now_v7();
@@ -168,8 +230,16 @@ pub fn Popover(
         key,
         children: Rc::new(move || {
             view! {
-                <leptonic-popover ref=pop_el id=key.to_string() style=pop_style data-active=move || match show.get() { true => "true", false => "false" }> // id=id class=class style=style
+                <leptonic-popover
+                    {..pop_attrs.clone()}
+                    ref=pop_el
+                    style=pop_style
+                    data-active=move || match show.get() { true => "true", false => "false" }
+                >
                     { (popover_content.children)() }
+                    { arrow.then(|| view! {
+                        <leptonic-popover-arrow {..arrow_props.attrs.clone()} node_ref=arrow_el />
+                    }) }
                 </leptonic-popover>
             }
             .into_view()
@@ -182,8 +252,40 @@ pub fn Popover(
     });
 
     view! {
-        <leptonic-has-popover ref=el id=id class=class style=style on:click=move |_| set_clicked.set(!clicked.get_untracked())>
+        <leptonic-has-popover
+            {..trigger_props.attrs}
+            ref=el
+            id=id
+            class=class
+            style=style
+            on:click=move |_| set_clicked.set(!clicked.get_untracked())
+        >
             { children() }
         </leptonic-has-popover>
     }
 }
+
+/// Resolves `margin` to px, so that it can be used as the positioning offset.
+/// Em sizes are relative to the font size of the popover, rem sizes to the one of the document.
+/// Falls back to a font size of 16px as long as the popover is not rendered.
+fn margin_to_px(margin: Size, pop_el: NodeRef<html::Custom>) -> f64 {
+    let font_size = |root: bool| {
+        pop_el
+            .get_html_element_untracked()
+            .map(web_sys::Element::from)
+            .and_then(|el| match root {
+                true => el.owner_document().and_then(|doc| doc.document_element()),
+                false => Some(el),
+            })
+            .and_then(|el| window().get_computed_style(&el).ok().flatten())
+            .and_then(|style| style.get_property_value("font-size").ok())
+            .and_then(|font_size| font_size.trim_end_matches("px").parse::<f64>().ok())
+            .unwrap_or(16.0)
+    };
+    match margin {
+        Size::Px(px) => f64::from(px),
+        Size::Em(em) => f64::from(em) * font_size(false),
+        Size::Rem(rem) => f64::from(rem) * font_size(true),
+        Size::Zero | Size::Percent(_) | Size::Auto => 0.0,
+    }
+}
@@ -1,85 +1,100 @@
+use std::fmt::Debug;
+
 use leptos::*;
 
 use crate::{
     components::form_control::{FormControlContext, FormInput},
+    hooks::{
+        use_radio, use_radio_group, RadioGroupState, UseRadioGroupInput, UseRadioGroupReturn,
+        UseRadioInput, UseRadioReturn,
+    },
+    utils::{focus::focus, locale::WritingDirection, NodeRefExt},
     OptMaybeSignal, Out,
 };
 
-#[derive(Clone)]
-struct RadioGroupContext {
-    states: StoredValue<Vec<(uuid::Uuid, Signal<bool>, Out<bool>)>>,
+struct RadioGroupContext<T: 'static> {
+    state: RadioGroupState<T>,
+    /// The form control the group registered itself with. Radios must not take over this form control.
+    form_control: Option<FormControlContext>,
 }
 
-impl RadioGroupContext {
-    fn register(&mut self, id: uuid::Uuid, checked: Signal<bool>, set_checked: Out<bool>) {
-        self.states.update_value(|states| {
-            states.push((id, checked, set_checked));
-        });
+impl<T: 'static> Clone for RadioGroupContext<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state,
+            form_control: self.form_control,
+        }
     }
+}
 
-    fn deregister(&mut self, id: uuid::Uuid) {
-        if let Some(idx) = self
-            .states
-            .with_value(|states| states.iter().position(|it| it.0 == id))
-        {
-            self.states.update_value(|states| {
-                states.remove(idx);
-            });
-        } else {
-            tracing::warn!(
-                "Could not deregister radio button {id}, as it is not currently registered."
-            )
-        }
+/// Pressing the label of a radio group focuses its selected (or first) radio.
+struct RadioGroupFormInput<T: 'static> {
+    state: RadioGroupState<T>,
+}
+
+impl<T: 'static> Debug for RadioGroupFormInput<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadioGroupFormInput")
+            .finish_non_exhaustive()
     }
+}
 
-    fn toggle(&self, id: uuid::Uuid, new_state: bool) {
-        let mut found = false;
-        self.states.with_value(|states| {
-            match new_state {
-                // Uncheck everything, there is nothing we can check...
-                false => {
-                    for (stored_id, _get, set) in states.iter() {
-                        if *stored_id == id {
-                            found = true;
-                            set.set(false);
-                        } else {
-                            set.set(false);
-                        }
-                    }
-                }
-                // Check targeted, uncheck all other.
-                true => {
-                    for (stored_id, _get, set) in states.iter() {
-                        if *stored_id == id {
-                            found = true;
-                            set.set(true);
-                        } else {
-                            set.set(false);
-                        }
-                    }
-                }
-            }
-        });
-        if !found {
-            tracing::warn!(
-                "Could not toggle radio button with id '{id}' as it is not currently registered."
-            );
-        }
+impl<T: Clone + PartialEq + 'static> FormInput for RadioGroupFormInput<T> {
+    fn on_label_press(&self) {
+        self.state.focus_tab_stop();
     }
 }
 
 #[component]
-pub fn RadioGroup(
+pub fn RadioGroup<T>(
+    /// Value of the selected radio. `None` when no radio is selected.
+    #[prop(into)]
+    value: Signal<Option<T>>,
+    #[prop(into)] set_value: Out<Option<T>>,
+    #[prop(into, optional)] disabled: OptMaybeSignal<bool>,
+    /// Horizontal arrow keys follow the writing direction.
+    #[prop(into, default = WritingDirection::Ltr.into())]
+    writing_direction: MaybeSignal<WritingDirection>,
     children: Children,
     #[prop(into, optional)] id: Option<AttributeValue>,
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
-) -> impl IntoView {
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let el: NodeRef<html::Custom> = create_node_ref();
+
+    let UseRadioGroupReturn { props, state } = use_radio_group(UseRadioGroupInput {
+        group_ref: el,
+        value,
+        set_value,
+        disabled: disabled.0.unwrap_or_else(|| false.into()),
+        writing_direction,
+    });
+
+    let form_ctrl_ctx = use_context::<FormControlContext>();
+
+    if let Some(form_ctrl_ctx) = form_ctrl_ctx {
+        form_ctrl_ctx
+            .input
+            .set(Some(Box::new(RadioGroupFormInput { state })));
+    }
+
     let ctx = RadioGroupContext {
-        states: store_value(Vec::new()),
+        state,
+        form_control: form_ctrl_ctx,
     };
+
     view! {
-        <leptonic-radio-group id=id class=class style=style role="radiogroup">
+        <leptonic-radio-group
+            {..props.attrs}
+            node_ref=el
+            id=id
+            class=class
+            style=style
+            on:keydown=props.on_key_down
+        >
             <Provider value=ctx>
                 { children() }
             </Provider>
@@ -87,79 +102,100 @@ pub fn RadioGroup(
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct RadioContext {
-    checked: Signal<bool>,
-    set_checked: Out<bool>,
+/// Pressing the label of a radio selects and focuses it.
+struct RadioFormInput<T: 'static> {
+    state: RadioGroupState<T>,
+    value: StoredValue<T>,
+    is_disabled: Signal<bool>,
+    el: NodeRef<html::Custom>,
 }
 
-impl RadioContext {
-    fn toggle(&self) {
-        self.set_checked.set(!self.checked.get_untracked());
+impl<T: 'static> Debug for RadioFormInput<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadioFormInput").finish_non_exhaustive()
     }
 }
 
-impl FormInput for RadioContext {
+impl<T: Clone + PartialEq + 'static> FormInput for RadioFormInput<T> {
     fn on_label_press(&self) {
-        self.toggle();
+        if self.is_disabled.get_untracked() {
+            return;
+        }
+        self.state.select(self.value.get_value());
+        if let Some(el) = self.el.get_html_element_untracked() {
+            focus(&el);
+        }
     }
 }
 
+/// A single option of a `RadioGroup`.
+///
+/// Must be rendered inside of a `RadioGroup` of the same value type. Otherwise, an error is logged and a disabled
+/// radio is rendered instead.
 #[component]
-pub fn Radio(
-    #[prop(into)] checked: Signal<bool>,
-    #[prop(into)] set_checked: Out<bool>,
+pub fn Radio<T>(
+    /// The value the group takes when this radio is selected.
+    value: T,
     #[prop(into, optional)] disabled: OptMaybeSignal<bool>,
     #[prop(into, optional)] id: Option<AttributeValue>,
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
-) -> impl IntoView {
-    let ctx = RadioContext {
-        checked,
-        set_checked,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let Some(group_ctx) = use_context::<RadioGroupContext<T>>() else {
+        tracing::error!(
+            "Radio must be rendered inside of a RadioGroup of the same value type. Rendering a disabled radio."
+        );
+        return view! {
+            <leptonic-radio id=id class=class style=style role="radio" aria-disabled="true" data-value="false">
+                <leptonic-radio-fill />
+            </leptonic-radio>
+        }
+        .into_view();
     };
-
-    let form_ctrl_ctx = use_context::<FormControlContext>();
-
-    if let Some(form_ctrl_ctx) = form_ctrl_ctx {
-        form_ctrl_ctx.input.set(Some(Box::new(ctx)));
-    }
-
-    let group_ctx = use_context::<RadioGroupContext>();
-    let mut opt_uuid = None;
-
-    if let Some(mut ctx) = group_ctx.clone() {
-        let uuid = uuid::Uuid::now_v7();
-        ctx.register(uuid, checked, set_checked);
-        opt_uuid = Some(uuid);
-
-        on_cleanup(move || {
-            ctx.deregister(uuid);
-        })
+    let state = group_ctx.state;
+
+    let el: NodeRef<html::Custom> = create_node_ref();
+    let stored_value = store_value(value.clone());
+
+    let UseRadioReturn {
+        props,
+        is_selected,
+        is_disabled,
+    } = use_radio(UseRadioInput {
+        radio_ref: el,
+        state,
+        value,
+        disabled: disabled.0.unwrap_or_else(|| false.into()),
+    });
+
+    // A form control surrounding the whole group is handled by the group.
+    if let Some(form_ctrl_ctx) = use_context::<FormControlContext>() {
+        if group_ctx.form_control.map(|it| it.input) != Some(form_ctrl_ctx.input) {
+            form_ctrl_ctx.input.set(Some(Box::new(RadioFormInput {
+                state,
+                value: stored_value,
+                is_disabled,
+                el,
+            })));
+        }
     }
 
-    let disabled = move || disabled.0.as_ref().map_or(false, SignalGet::get);
-
     view! {
         <leptonic-radio
+            {..props.attrs}
+            node_ref=el
             id=id
             class=class
             style=style
-            role="radio"
-            aria-disabled=move || match disabled() { true => "true", false => "false" }
-            aria-checked=move || match checked.get() { true => "true", false => "false" }
-            data-value=move || match checked.get() { true => "true", false => "false" }
-            tabindex="0"
-            on:click=move |_e| {
-                if !disabled() {
-                    match &group_ctx {
-                        Some(group_ctx) => group_ctx.toggle(opt_uuid.expect("to be present"), !checked.get_untracked()),
-                        None => ctx.toggle(),
-                    }
-                }
-            }
+            data-value=move || match is_selected.get() { true => "true", false => "false" }
+            on:click=props.on_click
+            on:keydown=props.on_key_down
         >
             <leptonic-radio-fill />
         </leptonic-radio>
     }
+    .into_view()
 }
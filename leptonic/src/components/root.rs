@@ -12,6 +12,7 @@ use crate::{
     contexts::{
         global_click_event::GlobalClickEvent,
         global_keyboard_event::GlobalKeyboardEvent,
+        global_modality::GlobalModality,
         global_mouseup_event::GlobalMouseupEvent,
         global_pointer_event::{
             GlobalPointerCancelEvent, GlobalPointerDownEvent, GlobalPointerMoveEvent,
@@ -173,6 +174,9 @@ where
         set_g_mouseup_event,
     ));
 
+    // MODALITY
+    provide_context(GlobalModality::new(g_keyboard_event, g_pointer_down_event));
+
     // RESIZE
     let (g_resize_event, set_g_resize_event) = create_signal::<Option<Event>>(None);
     let mut onresize = None;
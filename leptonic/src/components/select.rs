@@ -1,16 +1,26 @@
 use std::fmt::Debug;
 
 use leptos::*;
-use web_sys::{HtmlElement, KeyboardEvent, MouseEvent};
+use leptos_use::use_element_bounding;
+use web_sys::MouseEvent;
 
 use crate::{
     components::{
         chip::{Chip, ChipColor},
+        form_control::{FormControlContext, FormInput, HiddenInputs},
         icon::Icon,
         input::TextInput,
         prelude::Leptonic,
+        virtual_list::VirtualList,
     },
-    prelude::{Consumer, GlobalClickEvent, GlobalKeyboardEvent, ViewCallback},
+    hooks::{
+        use_overlay_position, use_select, use_select_option, OptionsLoader, OverlayBoundary,
+        PlacementX, PlacementY, SearchFilterProvider, SelectRow, SelectionModel,
+        UseOverlayPositionInput, UseSelectInput, UseSelectOptionInput, UseSelectOptionReturn,
+        UseSelectReturn, VirtualItemSize, OVERLAY_PADDING,
+    },
+    prelude::{Consumer, ViewCallback},
+    utils::{locale::WritingDirection, options::load_more_on_scroll},
     Out,
 };
 
@@ -30,76 +40,76 @@ pub trait SelectOption: Debug + Clone + PartialEq {}
 
 impl<T: Debug + Clone + PartialEq> SelectOption for T {}
 
-// TODO: select_previous and select_next could be made more efficient.
-// If we would know that the initial vec from which the current preselect'ed option was taken didn't change
-// and if we also keep track of the index of this option in the vec, we can just read the previous / next option
-// be decrementing or incrementing the old index!
-
 // TODO: Prop: close_options_menu_on_selection: bool
 // TODO: Prop: selection_changed: Consumer<Selection<T>>
 // TODO: multiselect deselect performance
-// TODO: remove code duplication between select variants
-
-// TODO: Replace select_previous and select_next with a function that stores the current index and does not need to traverse on each call!
-
-fn select_previous<O: SelectOption + 'static>(
-    available: &[O],
-    preselected: ReadSignal<Option<O>>,
-    set_preselected: WriteSignal<Option<O>>,
-) {
-    let previous = preselected.with_untracked(|current| match current {
-        Some(current) => match available.iter().position(|it| it == current) {
-            Some(current_pos) => match current_pos >= 1 {
-                true => Some(available[current_pos - 1].clone()),
-                false => available.last().cloned(),
-            },
-            None => available.last().cloned(),
-        },
-        None => available.last().cloned(),
-    });
-    set_preselected.set(previous);
+
+#[derive(Clone, Copy)]
+struct SelectFormInput {
+    wrapper: NodeRef<html::Div>,
 }
 
-fn select_next<O: SelectOption + 'static>(
-    available: &[O],
-    preselected: ReadSignal<Option<O>>,
-    set_preselected: WriteSignal<Option<O>>,
-) {
-    let next = preselected.with_untracked(|current| match current {
-        Some(current) => match available.iter().position(|it| it == current) {
-            Some(current_pos) => match (current_pos + 1) < available.len() {
-                true => Some(available[current_pos + 1].clone()),
-                false => available.first().cloned(),
-            },
-            None => available.first().cloned(),
-        },
-        None => available.first().cloned(),
-    });
-    set_preselected.set(next);
+impl Debug for SelectFormInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SelectFormInput").finish_non_exhaustive()
+    }
+}
+
+impl FormInput for SelectFormInput {
+    fn on_label_press(&self) {
+        if let Some(wrapper) = self.wrapper.get_untracked() {
+            if let Err(err) = wrapper.focus() {
+                tracing::warn!(?err, "Could not focus select.");
+            }
+        }
+    }
+}
+
+/// Registers a select with the surrounding `FormControl`, if any.
+/// The field of the control is touched when the options of the select are closed.
+fn use_form_control(
+    wrapper: NodeRef<html::Div>,
+    show_options: Signal<bool>,
+) -> Option<FormControlContext> {
+    let form_ctrl_ctx = use_context::<FormControlContext>();
+    if let Some(ctx) = form_ctrl_ctx {
+        ctx.input.set(Some(Box::new(SelectFormInput { wrapper })));
+        create_effect(move |was_shown: Option<bool>| {
+            let shown = show_options.get();
+            if was_shown == Some(true) && !shown {
+                ctx.touch();
+            }
+            shown
+        });
+    }
+    form_ctrl_ctx
 }
 
+/// Renders everything the select variants have in common around the display of their selection:
+/// The focusable wrapper, the trigger toggling the options and the options themselves.
 #[component]
 #[allow(clippy::too_many_lines)]
-pub fn Select<O>(
-    #[prop(into)] options: MaybeSignal<Vec<O>>,
-    #[prop(into)] selected: Signal<O>,
-    #[prop(into)] set_selected: Out<O>,
-    #[prop(into)] search_text_provider: Consumer<O, String>,
-    #[prop(into)] render_option: ViewCallback<O>,
-    #[prop(into, optional)] search_filter_provider: Option<Consumer<(String, Vec<O>), Vec<O>>>,
-    #[prop(into, optional)] autofocus_search: Option<Signal<bool>>,
-    #[prop(into, optional)] class: Option<AttributeValue>,
-    #[prop(into, optional)] style: Option<AttributeValue>,
+fn SelectShell<O>(
+    select: UseSelectReturn<O>,
+    variant: &'static str,
+    render_option: ViewCallback<O>,
+    autofocus_search: Option<Signal<bool>>,
+    virtualize: Option<VirtualItemSize>,
+    class: Option<AttributeValue>,
+    style: Option<AttributeValue>,
+    hidden_inputs: Option<View>,
+    children: Children,
 ) -> impl IntoView
 where
     O: SelectOption + 'static,
 {
-    let id: uuid::Uuid = uuid::Uuid::new_v4();
-    let id_string = format!("s-{id}");
-    let id_selector_string = format!("#{id_string}");
+    let UseSelectReturn {
+        props,
+        listbox_props,
+        state,
+    } = select;
 
-    let (focused, set_focused) = create_signal(false);
-    let (show_options, set_show_options) = create_signal(false);
+    let show_options = Signal::derive(move || state.is_open());
 
     let autofocus_search =
         autofocus_search.unwrap_or(expect_context::<Leptonic>().is_desktop_device);
@@ -108,102 +118,8 @@ where
         Signal::derive(move || show_options.get() && autofocus_search.get());
     let (search_is_focused, set_search_is_focused) = create_signal(false);
 
-    let stored_options = store_value(options);
-    let (preselected, set_preselected) = create_signal(Option::<O>::None);
-    let memoized_preselected = create_memo(move |_| preselected.get());
-
-    let (search, set_search) = create_signal(String::new());
-
-    let search_filter_provider =
-        search_filter_provider.unwrap_or(Consumer::new(move |(s, o): (String, Vec<O>)| {
-            let lowercased_search = s.to_lowercase();
-            o.into_iter()
-                .filter(|it| {
-                    search_text_provider
-                        .consume(it.clone())
-                        .to_lowercase()
-                        .contains(lowercased_search.as_str())
-                })
-                .collect::<Vec<O>>()
-        }));
-
-    let filtered_options = create_memo(move |_| {
-        search_filter_provider.consume((search.get(), stored_options.get_value().get()))
-    });
-
-    let has_options = create_memo(move |_| !filtered_options.with(Vec::is_empty));
-
-    let select = Consumer::new(move |option: O| {
-        set_selected.set(option);
-        set_show_options.set(false);
-    });
-
-    let is_selected = move |option: &O| selected.with(|selected| selected == option);
-
-    let is_disabled = move |option: &O| selected.with(|selected| selected == option);
-
-    let is_disabled_untracked =
-        move |option: &O| selected.with_untracked(|selected| selected == option);
-
-    // We need to check for global mouse events.
-    // If our option list is shown and such an event occurs and does not target our option list, the options list should be closed.
-    create_click_away_listener(id_selector_string, show_options, set_show_options.into());
-
-    create_key_down_listener(move |e| {
-        match (show_options.get_untracked(), focused.get_untracked()) {
-            (true, _) => match e.key().as_str() {
-                "Escape" => set_show_options.set(false),
-                "Backspace" => {
-                    if !search_is_focused.get_untracked() {
-                        set_show_options.set(false);
-                    }
-                }
-                "ArrowUp" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    // TODO: Use options_available_for_preselect.with_untracked when https://github.com/leptos-rs/leptos/issues/1212 is resolved and released.
-                    select_previous(
-                        &filtered_options.get_untracked(),
-                        preselected,
-                        set_preselected,
-                    );
-                }
-                "ArrowDown" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    // TODO: Use options_available_for_preselect.with_untracked when https://github.com/leptos-rs/leptos/issues/1212 is resolved and released.
-                    select_next(
-                        &filtered_options.get_untracked(),
-                        preselected,
-                        set_preselected,
-                    );
-                }
-                "Enter" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    if let Some(preselected) = preselected.get_untracked() {
-                        if !is_disabled_untracked(&preselected) {
-                            select.consume(preselected);
-                        }
-                    }
-                }
-                _ => {}
-            },
-            (false, true) => match e.key().as_str() {
-                "Enter" | "ArrowDown" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    set_show_options.set(true);
-                }
-                _ => {}
-            },
-            _ => {}
-        }
-    });
-
-    let toggle_show = move || set_show_options.update(|val| *val = !*val);
-
     let wrapper: NodeRef<html::Div> = create_node_ref();
+    let form_ctrl_ctx = use_form_control(wrapper, show_options);
 
     // Put focus back on our wrapper when the dropdown was closed while the search input had focus.
     create_effect(move |_| {
@@ -217,25 +133,97 @@ where
         }
     });
 
+    let option_view = move |option: O| {
+        let UseSelectOptionReturn {
+            props: option_props,
+        } = use_select_option(UseSelectOptionInput {
+            select: state,
+            option: option.clone(),
+        });
+        let clone1 = option.clone();
+        let clone2 = option.clone();
+        let clone3 = option.clone();
+        view! {
+            <leptonic-select-option
+                {..option_props.attrs}
+                class:preselected=move || state.is_preselected(&option)
+                class:selected=move || state.is_selected(&clone1)
+                class:disabled=move || !state.is_selectable(&clone2)
+                on:mouseenter=option_props.on_mouse_enter
+                on:click=option_props.on_click
+            >
+                { render_option.render(clone3) }
+            </leptonic-select-option>
+        }
+    };
+
+    let row_view = move |row: SelectRow<O>| match row {
+        SelectRow::Group(group) => view! {
+            <leptonic-select-group role="presentation">
+                { group }
+            </leptonic-select-group>
+        }
+        .into_view(),
+        SelectRow::Option(option) => option_view(option).into_view(),
+    };
+
+    // Keeps the preselected option in view when navigating a virtualized list using the keyboard.
+    let preselected_index = Signal::derive(move || state.preselected_index());
+
+    // The options are positioned next to the select, flipping above it when there is not enough space below.
+    let select_el: NodeRef<html::Custom> = create_node_ref();
+    let options_el: NodeRef<html::Custom> = create_node_ref();
+    let select_bounds = use_element_bounding(select_el);
+    let overlay_position = use_overlay_position(UseOverlayPositionInput {
+        overlay_ref: options_el,
+        target_ref: select_el,
+        placement_x: PlacementX::Left.into(),
+        placement_y: PlacementY::Below.into(),
+        writing_direction: WritingDirection::Ltr.into(),
+        flip: true,
+        shift: true,
+        offset: 0.0.into(),
+        padding: OVERLAY_PADDING.into(),
+        boundary: OverlayBoundary::Viewport,
+        arrow_ref: None,
+    });
+    let mut options_attrs = overlay_position.props.attrs;
+    let position_style = options_attrs.map.remove("style");
+    // The options span the full width of the select.
+    let options_style = Signal::derive(move || {
+        let position = position_style
+            .as_ref()
+            .and_then(Attribute::as_nameless_value_string)
+            .unwrap_or_default();
+        format!("{position}; width: {}px", select_bounds.width.get())
+    });
+
     view! {
         // TODO: If possible, move this focus-tracking functionality to our main leptonic-select element. it requires the focus() method to be available.
         <div
+            {..props.attrs}
             node_ref=wrapper
             class="leptonic-select-wrapper"
             tabindex=0
-            on:blur=move |_| set_focused.set(false)
-            on:focus=move |_| set_focused.set(true)
+            aria-invalid=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_invalid())
+            aria-errormessage=move || form_ctrl_ctx.and_then(|ctx| ctx.aria_errormessage())
+            on:keydown=props.on_key_down
+            on:blur=move |_| {
+                if let Some(ctx) = form_ctrl_ctx.filter(|_| !show_options.get_untracked()) {
+                    ctx.touch();
+                }
+            }
         >
             <leptonic-select
-                id=id_string
-                data-variant="select"
-                aria-haspopup="listbox"
+                node_ref=select_el
+                data-variant=variant
                 class=class
+                class:leptonic-validation-error=move || form_ctrl_ctx.is_some_and(|ctx| ctx.is_invalid())
                 class:active=move || show_options.get()
                 style=style
             >
-                <leptonic-select-selected on:click=move |_| toggle_show()>
-                    { move || render_option.render(selected.get()) }
+                <leptonic-select-selected on:click=move |_| state.toggle()>
+                    { children() }
 
                     <leptonic-select-show-trigger>
                         {move || match show_options.get() {
@@ -245,56 +233,77 @@ where
                     </leptonic-select-show-trigger>
                 </leptonic-select-selected>
 
-                <leptonic-select-options class:shown=move || show_options.get()>
-                    <TextInput
-                        get=search
-                        set=set_search
-                        should_be_focused=search_should_be_focused
-                        on_focus_change=move |focused| {
-                            // We only update our state as long as show_options is true.
-                            // It it is no longer true, the dropdown is no longer shown through a CSS rule (display: none).
-                            // This will automatically de-focus the search input if it had focus, resulting in a call of this callback.
-                            // When storing the received `false` in `search_is_focused` before our effect above, resetting focus on our wrapper may, runs,
-                            // that create_effect will not be able to set the focus. We accept not setting `search_is_focused` all the time
-                            // for the create_effect above to work reliably.
-                            if show_options.get_untracked() {
-                                set_search_is_focused.set(focused);
+                <leptonic-select-options
+                    {..options_attrs}
+                    node_ref=options_el
+                    style=options_style
+                    class:shown=move || show_options.get()
+                    on:scroll=move |e| load_more_on_scroll(&e, || state.load_more())
+                >
+                    // The search input must not be mistaken for the input of a surrounding `FormControl`.
+                    <Provider value=FormControlContext::detached()>
+                        <TextInput
+                            get=state.search()
+                            set=state.search()
+                            should_be_focused=search_should_be_focused
+                            on_focus_change=move |focused| {
+                                // We only update our state as long as show_options is true.
+                                // It it is no longer true, the dropdown is no longer shown through a CSS rule (display: none).
+                                // This will automatically de-focus the search input if it had focus, resulting in a call of this callback.
+                                // When storing the received `false` in `search_is_focused` before our effect above, resetting focus on our wrapper may, runs,
+                                // that create_effect will not be able to set the focus. We accept not setting `search_is_focused` all the time
+                                // for the create_effect above to work reliably.
+                                if show_options.get_untracked() {
+                                    set_search_is_focused.set(focused);
+                                }
                             }
-                        }
-                        class="search"
-                    />
+                            class="search"
+                        />
+                    </Provider>
+
+                    <leptonic-select-listbox {..listbox_props.attrs}>
+                        <Show
+                            when=move || show_options.get()
+                            fallback=move || ()
+                        >
+                            { match virtualize {
+                                Some(item_size) => view! {
+                                    <VirtualList
+                                        each=create_memo(move |previous: Option<&Vec<(RowKey, SelectRow<O>)>>| {
+                                            keyed_rows(previous.map(Vec::as_slice), state.rows().get())
+                                        })
+                                        key=|(key, _): &(RowKey, SelectRow<O>)| *key
+                                        item_size=item_size
+                                        scroll_to_index=preselected_index
+                                        children=move |(_, row)| row_view(row)
+                                    />
+                                }.into_view(),
+                                // TOD: Use <For> once leptos 0.4 is out. Use full option for hash.
+                                None => state.rows().get().into_iter().map(row_view).collect_view(),
+                            } }
+                        </Show>
+                    </leptonic-select-listbox>
 
                     <Show
                         when=move || show_options.get()
                         fallback=move || ()
                     >
-                        // TOD: Use <For> once leptos 0.4 is out. Use full option for hash.
-                        { filtered_options.get().into_iter().map(|option| {
-                            let clone1 = option.clone();
-                            let clone2 = option.clone();
-                            let clone3 = option.clone();
-                            let clone4 = option.clone();
-                            let clone5 = option.clone();
-                            view! {
-                                <leptonic-select-option
-                                    class:preselected=move || memoized_preselected.with(|preselected| preselected.as_ref() == Some(&option))
-                                    class:selected=move || is_selected(&clone4)
-                                    class:disabled=move || is_disabled(&clone5)
-                                    on:mouseenter=move |_e| {
-                                        set_preselected.set(Some(clone3.clone()));
-                                    }
-                                    on:click=move |_e| {
-                                        if !is_disabled_untracked(&clone2) {
-                                            select.consume(clone2.clone());
-                                        }
-                                    }
-                                >
-                                    { render_option.render(clone1) }
-                                </leptonic-select-option>
-                            }
-                        }).collect_view() }
-
-                        { move || match has_options.get() {
+                        { move || state.is_loading().then(|| view! {
+                            <leptonic-select-loading>
+                                "Loading..."
+                            </leptonic-select-loading>
+                        }) }
+
+                        { move || state.can_create().then(|| view! {
+                            <leptonic-select-create
+                                class:preselected=move || !state.preselected_is_shown()
+                                on:click=move |_e| state.create()
+                            >
+                                "Create \"" { move || state.search().get().trim().to_owned() } "\""
+                            </leptonic-select-create>
+                        }) }
+
+                        { move || match state.has_options() || state.is_loading() || state.can_create() {
                             true => ().into_view(),
                             false => view! {
                                 <leptonic-select-no-search-results>
@@ -304,579 +313,376 @@ where
                         } }
                     </Show>
                 </leptonic-select-options>
+                { hidden_inputs }
             </leptonic-select>
         </div>
     }
 }
 
 #[component]
-#[allow(clippy::too_many_lines)]
-pub fn OptionalSelect<O>(
-    #[prop(into)] options: MaybeSignal<Vec<O>>,
-    #[prop(into)] selected: Signal<Option<O>>,
-    #[prop(into)] set_selected: Out<Option<O>>,
+pub fn Select<O>(
+    #[prop(into, optional)] options: MaybeSignal<Vec<O>>,
+    #[prop(into)] selected: Signal<O>,
+    #[prop(into)] set_selected: Out<O>,
     #[prop(into)] search_text_provider: Consumer<O, String>,
     #[prop(into)] render_option: ViewCallback<O>,
-    #[prop(into)] allow_deselect: MaybeSignal<bool>,
-    #[prop(into, optional)] search_filter_provider: Option<Consumer<(String, Vec<O>), Vec<O>>>,
+    #[prop(into, optional)] search_filter_provider: Option<SearchFilterProvider<O>>,
+    /// Loads the options matching the current search asynchronously while the options are shown. `options` are ignored when set.
+    #[prop(optional)]
+    loader: Option<OptionsLoader<O>>,
+    /// Milliseconds the search must stay unchanged before options are loaded through the `loader`.
+    #[prop(optional, default = 300.0)]
+    search_debounce: f64,
+    /// Number of options loaded through the `loader` at once. Further options are loaded when scrolling to the end of the options.
+    #[prop(optional, default = 50)]
+    page_size: usize,
+    /// Options for which this returns true are shown, but can not be selected.
+    #[prop(into, optional)]
+    disabled_provider: Option<Consumer<O, bool>>,
+    /// Shows options below a header naming their group. Options without a group are shown first.
+    #[prop(into, optional)]
+    group_provider: Option<Consumer<O, Option<String>>>,
+    /// Lets the user create a new option from the search text, when no option matches it exactly.
+    /// The search text is emitted through this callback. Add the new option to the options and select it in response.
+    #[prop(into, optional)]
+    on_create: Option<Out<String>>,
     #[prop(into, optional)] autofocus_search: Option<Signal<bool>>,
+    /// Only renders the options currently visible when set. Use this for selects with many options.
+    #[prop(optional)]
+    virtualize: Option<VirtualItemSize>,
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
+    /// Submits the selected option with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
+    /// Serializes an option for native form submission. Defaults to the `search_text_provider`.
+    #[prop(into, optional)]
+    form_value: Option<Consumer<O, String>>,
 ) -> impl IntoView
 where
     O: SelectOption + 'static,
 {
-    let id: uuid::Uuid = uuid::Uuid::new_v4();
-    let id_string = format!("s-{id}");
-    let id_selector_string = format!("#{id_string}");
-
-    let (focused, set_focused) = create_signal(false);
-    let (show_options, set_show_options) = create_signal(false);
-
-    let autofocus_search =
-        autofocus_search.unwrap_or(expect_context::<Leptonic>().is_desktop_device);
-
-    let search_should_be_focused =
-        Signal::derive(move || show_options.get() && autofocus_search.get());
-    let (search_is_focused, set_search_is_focused) = create_signal(false);
-
-    let stored_options = store_value(options);
-    let (preselected, set_preselected) = create_signal(Option::<O>::None);
-    let memoized_preselected = create_memo(move |_| preselected.get());
-
-    let (search, set_search) = create_signal(String::new());
-
-    let search_filter_provider =
-        search_filter_provider.unwrap_or(Consumer::new(move |(s, o): (String, Vec<O>)| {
-            let lowercased_search = s.to_lowercase();
-            o.into_iter()
-                .filter(|it| {
-                    search_text_provider
-                        .consume(it.clone())
-                        .to_lowercase()
-                        .contains(lowercased_search.as_str())
-                })
-                .collect::<Vec<O>>()
-        }));
-
-    let filtered_options = create_memo(move |_| {
-        search_filter_provider.consume((search.get(), stored_options.get_value().get()))
+    let hidden_inputs = name.map(|name| {
+        hidden_inputs(
+            name,
+            move || vec![selected.get()],
+            form_value.unwrap_or(search_text_provider),
+        )
     });
 
-    let has_options = create_memo(move |_| !filtered_options.with(Vec::is_empty));
-
-    let select = Consumer::new(move |option: O| {
-        set_selected.set(Some(option));
-        set_show_options.set(false);
+    let select = use_select(UseSelectInput {
+        selection: SelectionModel::Single {
+            selected,
+            set_selected,
+        },
+        options,
+        loader,
+        search_debounce,
+        page_size,
+        search_text_provider,
+        search_filter_provider,
+        disabled_provider,
+        group_provider,
+        on_create,
+        close_on_select: true,
     });
 
-    let deselect = move || {
-        set_selected.set(None);
-    };
-
-    let is_selected = move |option: &O| selected.with(|selected| selected.as_ref() == Some(option));
-
-    let is_disabled = move |option: &O| selected.with(|selected| selected.as_ref() == Some(option));
-
-    let is_disabled_untracked =
-        move |option: &O| selected.with_untracked(|selected| selected.as_ref() == Some(option));
-
-    // We need to check for global mouse events.
-    // If our option list is shown and such an event occurs and does not target our option list, the options list should be closed.
-    create_click_away_listener(id_selector_string, show_options, set_show_options.into());
+    view! {
+        <SelectShell
+            select=select
+            variant="select"
+            render_option=render_option
+            autofocus_search=autofocus_search
+            virtualize=virtualize
+            class=class
+            style=style
+            hidden_inputs=hidden_inputs
+        >
+            { move || render_option.render(selected.get()) }
+        </SelectShell>
+    }
+}
 
-    create_key_down_listener(move |e| {
-        match (show_options.get_untracked(), focused.get_untracked()) {
-            (true, _) => match e.key().as_str() {
-                "Escape" => set_show_options.set(false),
-                "Backspace" => {
-                    if !search_is_focused.get_untracked() {
-                        set_show_options.set(false);
-                    }
-                }
-                "ArrowUp" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    // TODO: Use options_available_for_preselect.with_untracked when https://github.com/leptos-rs/leptos/issues/1212 is resolved and released.
-                    select_previous(
-                        &filtered_options.get_untracked(),
-                        preselected,
-                        set_preselected,
-                    );
-                }
-                "ArrowDown" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    // TODO: Use options_available_for_preselect.with_untracked when https://github.com/leptos-rs/leptos/issues/1212 is resolved and released.
-                    select_next(
-                        &filtered_options.get_untracked(),
-                        preselected,
-                        set_preselected,
-                    );
-                }
-                "Enter" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    if let Some(preselected) = preselected.get_untracked() {
-                        if !is_disabled_untracked(&preselected) {
-                            select.consume(preselected);
-                        }
-                    }
-                }
-                _ => {}
-            },
-            (false, true) => match e.key().as_str() {
-                "Enter" | "ArrowDown" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    set_show_options.set(true);
-                }
-                _ => {}
-            },
-            _ => {}
-        }
+#[component]
+pub fn OptionalSelect<O>(
+    #[prop(into, optional)] options: MaybeSignal<Vec<O>>,
+    #[prop(into)] selected: Signal<Option<O>>,
+    #[prop(into)] set_selected: Out<Option<O>>,
+    #[prop(into)] search_text_provider: Consumer<O, String>,
+    #[prop(into)] render_option: ViewCallback<O>,
+    #[prop(into)] allow_deselect: MaybeSignal<bool>,
+    #[prop(into, optional)] search_filter_provider: Option<SearchFilterProvider<O>>,
+    /// Loads the options matching the current search asynchronously while the options are shown. `options` are ignored when set.
+    #[prop(optional)]
+    loader: Option<OptionsLoader<O>>,
+    /// Milliseconds the search must stay unchanged before options are loaded through the `loader`.
+    #[prop(optional, default = 300.0)]
+    search_debounce: f64,
+    /// Number of options loaded through the `loader` at once. Further options are loaded when scrolling to the end of the options.
+    #[prop(optional, default = 50)]
+    page_size: usize,
+    /// Options for which this returns true are shown, but can not be selected.
+    #[prop(into, optional)]
+    disabled_provider: Option<Consumer<O, bool>>,
+    /// Shows options below a header naming their group. Options without a group are shown first.
+    #[prop(into, optional)]
+    group_provider: Option<Consumer<O, Option<String>>>,
+    /// Lets the user create a new option from the search text, when no option matches it exactly.
+    /// The search text is emitted through this callback. Add the new option to the options and select it in response.
+    #[prop(into, optional)]
+    on_create: Option<Out<String>>,
+    #[prop(into, optional)] autofocus_search: Option<Signal<bool>>,
+    /// Only renders the options currently visible when set. Use this for selects with many options.
+    #[prop(optional)]
+    virtualize: Option<VirtualItemSize>,
+    #[prop(into, optional)] class: Option<AttributeValue>,
+    #[prop(into, optional)] style: Option<AttributeValue>,
+    /// Submits the selected option with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
+    /// Serializes an option for native form submission. Defaults to the `search_text_provider`.
+    #[prop(into, optional)]
+    form_value: Option<Consumer<O, String>>,
+) -> impl IntoView
+where
+    O: SelectOption + 'static,
+{
+    let hidden_inputs = name.map(|name| {
+        hidden_inputs(
+            name,
+            move || selected.get().into_iter().collect(),
+            form_value.unwrap_or(search_text_provider),
+        )
     });
 
-    let toggle_show = move || set_show_options.update(|val| *val = !*val);
-
-    let wrapper: NodeRef<html::Div> = create_node_ref();
-
-    // Put focus back on our wrapper when the dropdown was closed while the search input had focus.
-    create_effect(move |_| {
-        if !show_options.get() && search_is_focused.get_untracked() {
-            // TODO: Use with() when available.
-            if let Some(wrapper) = wrapper.get() {
-                wrapper.focus().expect("wrapper to be focusable");
-            } else {
-                tracing::warn!("missing node_ref");
-            }
-        }
+    let select = use_select(UseSelectInput {
+        selection: SelectionModel::Optional {
+            selected,
+            set_selected,
+        },
+        options,
+        loader,
+        search_debounce,
+        page_size,
+        search_text_provider,
+        search_filter_provider,
+        disabled_provider,
+        group_provider,
+        on_create,
+        close_on_select: true,
     });
+    let state = select.state;
 
     view! {
-        // TODO: If possible, move this focus-tracking functionality to our main leptonic-select element. it requires the focus() method to be available.
-        <div
-            node_ref=wrapper
-            class="leptonic-select-wrapper"
-            tabindex=0
-            on:blur=move |_| set_focused.set(false)
-            on:focus=move |_| set_focused.set(true)
+        <SelectShell
+            select=select
+            variant="optional-select"
+            render_option=render_option
+            autofocus_search=autofocus_search
+            virtualize=virtualize
+            class=class
+            style=style
+            hidden_inputs=hidden_inputs
         >
-            <leptonic-select
-                id=id_string
-                data-variant="optional-select"
-                aria-haspopup="listbox"
-                class=class
-                style=style
-            >
-                <leptonic-select-selected on:click=move |_| toggle_show()>
-                    { move || match selected.get() {
-                        Some(selected) => view! {
-                            <leptonic-select-option>
-                                { render_option.render(selected) }
-                            </leptonic-select-option>
-                        }.into_view(),
-                        None => ().into_view(),
-                    }}
-
-                    { match allow_deselect.get() {
-                        false => ().into_view(),
-                        true => view! {
-                            <leptonic-select-deselect-trigger on:click=move |e| {
-                                e.prevent_default();
-                                e.stop_propagation();
-                                deselect();
-                            }>
-                                <Icon icon=icondata::BsXCircleFill/>
-                            </leptonic-select-deselect-trigger>
-                        }.into_view(),
-                    }}
-
-                    <leptonic-select-show-trigger>
-                        {move || match show_options.get() {
-                            true => view! { <Icon icon=icondata::BsCaretUpFill/>},
-                            false => view! { <Icon icon=icondata::BsCaretDownFill/>}
-                        }}
-                    </leptonic-select-show-trigger>
-                </leptonic-select-selected>
-
-                <leptonic-select-options class:shown=move || show_options.get()>
-                    <TextInput
-                        get=search
-                        set=set_search
-                        should_be_focused=search_should_be_focused
-                        on_focus_change=move |focused| {
-                            // We only update our state as long as show_options is true.
-                            // It it is no longer true, the dropdown is no longer shown through a CSS rule (display: none).
-                            // This will automatically de-focus the search input if it had focus, resulting in a call of this callback.
-                            // When storing the received `false` in `search_is_focused` before our effect above, resetting focus on our wrapper may, runs,
-                            // that create_effect will not be able to set the focus. We accept not setting `search_is_focused` all the time
-                            // for the create_effect above to work reliably.
-                            if show_options.get_untracked() {
-                                set_search_is_focused.set(focused);
-                            }
-                        }
-                        class="search"
-                    />
-
-                    <Show
-                        when=move || show_options.get()
-                        fallback=move || ()
-                    >
-                        // TOD: Use <For> once leptos 0.4 is out. Use full option for hash.
-                        { filtered_options.get().into_iter().map(|option| {
-                            let clone1 = option.clone();
-                            let clone2 = option.clone();
-                            let clone3 = option.clone();
-                            let clone4 = option.clone();
-                            let clone5 = option.clone();
-                            view! {
-                                <leptonic-select-option
-                                    class:preselected=move || memoized_preselected.with(|preselected| preselected.as_ref() == Some(&option))
-                                    class:selected=move || is_selected(&clone4)
-                                    class:disabled=move || is_disabled(&clone5)
-                                    on:mouseenter=move |_e| {
-                                        set_preselected.set(Some(clone3.clone()));
-                                    }
-                                    on:click=move |_e| {
-                                        if !is_disabled_untracked(&clone2) {
-                                            select.consume(clone2.clone());
-                                        }
-                                    }
-                                >
-                                    { render_option.render(clone1) }
-                                </leptonic-select-option>
-                            }
-                        }).collect_view() }
-
-                        { move || match has_options.get() {
-                            true => ().into_view(),
-                            false => view! {
-                                <div class="option">
-                                    "No options..."
-                                </div>
-                            }.into_view(),
-                        } }
-                    </Show>
-                </leptonic-select-options>
-            </leptonic-select>
-        </div>
+            { move || match selected.get() {
+                Some(selected) => view! {
+                    <leptonic-select-option>
+                        { render_option.render(selected) }
+                    </leptonic-select-option>
+                }.into_view(),
+                None => ().into_view(),
+            }}
+
+            { match allow_deselect.get() {
+                false => ().into_view(),
+                true => view! {
+                    <leptonic-select-deselect-trigger on:click=move |e| {
+                        e.prevent_default();
+                        e.stop_propagation();
+                        state.clear();
+                    }>
+                        <Icon icon=icondata::BsXCircleFill/>
+                    </leptonic-select-deselect-trigger>
+                }.into_view(),
+            }}
+        </SelectShell>
     }
 }
 
 #[component]
-#[allow(clippy::too_many_lines)]
 pub fn Multiselect<O>(
     #[prop(optional, default=u64::MAX)] max: u64,
-    #[prop(into)] options: MaybeSignal<Vec<O>>,
+    #[prop(into, optional)] options: MaybeSignal<Vec<O>>,
     #[prop(into)] selected: Signal<Vec<O>>,
     #[prop(into)] set_selected: Out<Vec<O>>,
     #[prop(into)] search_text_provider: Consumer<O, String>,
     #[prop(into)] render_option: ViewCallback<O>,
-    #[prop(into, optional)] search_filter_provider: Option<Consumer<(String, Vec<O>), Vec<O>>>,
+    #[prop(into, optional)] search_filter_provider: Option<SearchFilterProvider<O>>,
+    /// Loads the options matching the current search asynchronously while the options are shown. `options` are ignored when set.
+    #[prop(optional)]
+    loader: Option<OptionsLoader<O>>,
+    /// Milliseconds the search must stay unchanged before options are loaded through the `loader`.
+    #[prop(optional, default = 300.0)]
+    search_debounce: f64,
+    /// Number of options loaded through the `loader` at once. Further options are loaded when scrolling to the end of the options.
+    #[prop(optional, default = 50)]
+    page_size: usize,
+    /// Options for which this returns true are shown, but can not be selected.
+    #[prop(into, optional)]
+    disabled_provider: Option<Consumer<O, bool>>,
+    /// Shows options below a header naming their group. Options without a group are shown first.
+    #[prop(into, optional)]
+    group_provider: Option<Consumer<O, Option<String>>>,
+    /// Lets the user create a new option from the search text, when no option matches it exactly.
+    /// The search text is emitted through this callback. Add the new option to the options and select it in response.
+    #[prop(into, optional)]
+    on_create: Option<Out<String>>,
     #[prop(into, optional)] autofocus_search: Option<Signal<bool>>,
+    /// Only renders the options currently visible when set. Use this for selects with many options.
+    #[prop(optional)]
+    virtualize: Option<VirtualItemSize>,
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
+    /// Submits all selected options with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
+    /// Serializes an option for native form submission. Defaults to the `search_text_provider`.
+    #[prop(into, optional)]
+    form_value: Option<Consumer<O, String>>,
 ) -> impl IntoView
 where
     O: SelectOption + PartialOrd + Ord + 'static,
 {
-    let id: uuid::Uuid = uuid::Uuid::new_v4();
-    let id_string = format!("s-{id}");
-    let id_selector_string = format!("#{id_string}");
-
-    let (focused, set_focused) = create_signal(false);
-    let (show_options, set_show_options) = create_signal(false);
-
-    let autofocus_search =
-        autofocus_search.unwrap_or(expect_context::<Leptonic>().is_desktop_device);
-
-    let search_should_be_focused =
-        Signal::derive(move || show_options.get() && autofocus_search.get());
-    let (search_is_focused, set_search_is_focused) = create_signal(false);
-
-    let stored_options = store_value(options);
-    let (preselected, set_preselected) = create_signal(Option::<O>::None);
-    let memoized_preselected = create_memo(move |_| preselected.get());
-
-    let (search, set_search) = create_signal(String::new());
-
-    let search_filter_provider =
-        search_filter_provider.unwrap_or(Consumer::new(move |(s, o): (String, Vec<O>)| {
-            let lowercased_search = s.to_lowercase();
-            o.into_iter()
-                .filter(|it| {
-                    search_text_provider
-                        .consume(it.clone())
-                        .to_lowercase()
-                        .contains(lowercased_search.as_str())
-                })
-                .collect::<Vec<O>>()
-        }));
-
-    let filtered_options = create_memo(move |_| {
-        search_filter_provider.consume((search.get(), stored_options.get_value().get()))
-    });
-
-    let has_options = create_memo(move |_| !filtered_options.with(Vec::is_empty));
-
-    let select = Consumer::new(move |option: O| {
-        let mut vec = selected.get_untracked();
-        if !vec.contains(&option) {
-            vec.push(option); // TODO
-        }
-        vec.sort();
-        tracing::info!(?vec, "selected");
-        set_selected.set(vec);
-        set_show_options.set(false); // TODO: Make this optional.
-    });
-
-    let deselect = Consumer::new(move |option: O| {
-        let mut vec = selected.get_untracked();
-        if let Some(pos) = vec.iter().position(|it| it == &option) {
-            vec.remove(pos);
-        }
-        tracing::info!(?vec, "deselected");
-        set_selected.set(vec);
-        // set_show_options.set(false); // TODO: Make this optional.
+    let hidden_inputs = name.map(|name| {
+        hidden_inputs(
+            name,
+            move || selected.get(),
+            form_value.unwrap_or(search_text_provider),
+        )
     });
 
-    let is_selected = move |option: &O| selected.with(|selected| selected.contains(option));
-
-    let is_disabled = move |option: &O| {
-        selected.with(|selected| selected.contains(option) || selected.len() as u64 == max)
-    };
-
-    let is_disabled_untracked = move |option: &O| {
-        selected
-            .with_untracked(|selected| selected.contains(option) || selected.len() as u64 == max)
-    };
-
-    // We need to check for global mouse events.
-    // If our option list is shown and such an event occurs and does not target our option list, the options list should be closed.
-    create_click_away_listener(id_selector_string, show_options, set_show_options.into());
-
-    create_key_down_listener(move |e| {
-        match (show_options.get_untracked(), focused.get_untracked()) {
-            (true, _) => match e.key().as_str() {
-                "Escape" => set_show_options.set(false),
-                "Backspace" => {
-                    if !search_is_focused.get_untracked() {
-                        set_show_options.set(false);
-                    }
-                }
-                "ArrowUp" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    // TODO: Use options_available_for_preselect.with_untracked when https://github.com/leptos-rs/leptos/issues/1212 is resolved and released.
-                    select_previous(
-                        &filtered_options.get_untracked(),
-                        preselected,
-                        set_preselected,
-                    );
-                }
-                "ArrowDown" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    // TODO: Use options_available_for_preselect.with_untracked when https://github.com/leptos-rs/leptos/issues/1212 is resolved and released.
-                    select_next(
-                        &filtered_options.get_untracked(),
-                        preselected,
-                        set_preselected,
-                    );
-                }
-                "Enter" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    if let Some(preselected) = preselected.get_untracked() {
-                        if !is_disabled_untracked(&preselected) {
-                            select.consume(preselected);
-                        }
-                    }
-                }
-                _ => {}
-            },
-            (false, true) => match e.key().as_str() {
-                "Enter" | "ArrowDown" => {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    set_show_options.set(true);
-                }
-                _ => {}
-            },
-            _ => {}
-        }
-    });
-
-    let toggle_show = move || set_show_options.update(|val| *val = !*val);
-
-    let wrapper: NodeRef<html::Div> = create_node_ref();
-
-    // Put focus back on our wrapper when the dropdown was closed while the search input had focus.
-    create_effect(move |_| {
-        if !show_options.get() && search_is_focused.get_untracked() {
-            // TODO: Use with() when available.
-            if let Some(wrapper) = wrapper.get() {
-                wrapper.focus().expect("wrapper to be focusable");
-            } else {
-                tracing::warn!("missing node_ref");
-            }
-        }
+    let select = use_select(UseSelectInput {
+        selection: SelectionModel::Multiple {
+            selected,
+            // Selected options are always kept in order.
+            set_selected: Out::new_func(move |mut options: Vec<O>| {
+                options.sort();
+                set_selected.set(options);
+            }),
+            max,
+        },
+        options,
+        loader,
+        search_debounce,
+        page_size,
+        search_text_provider,
+        search_filter_provider,
+        disabled_provider,
+        group_provider,
+        on_create,
+        close_on_select: true, // TODO: Make this optional.
     });
+    let state = select.state;
 
     view! {
-        // TODO: If possible, move this focus-tracking functionality to our main leptonic-select element. it requires the focus() method to be available.
-        <div
-            node_ref=wrapper
-            class="leptonic-select-wrapper"
-            tabindex=0
-            on:blur=move |_| set_focused.set(false)
-            on:focus=move |_| set_focused.set(true)
+        <SelectShell
+            select=select
+            variant="multiselect"
+            render_option=render_option
+            autofocus_search=autofocus_search
+            virtualize=virtualize
+            class=class
+            style=style
+            hidden_inputs=hidden_inputs
         >
-            <leptonic-select
-                id=id_string
-                data-variant="multiselect"
-                aria-haspopup="listbox"
-                class=class
-                style=style
-            >
-                <leptonic-select-selected on:click=move |_| toggle_show()>
-                    // TOD: Use <For> once leptos 0.4 is out. Use full option for hash.
-                    { move || selected.get().into_iter().map(|selected| {
-                        let clone = selected.clone();
-                        view! {
-                            <leptonic-select-option>
-                                <Chip
-                                    color=ChipColor::Secondary
-                                    on:click=move |e| {
-                                        e.stop_propagation();
-                                    }
-                                    dismissible=move |e: MouseEvent| {
-                                        e.stop_propagation();
-                                        deselect.consume(clone.clone());
-                                    }>
-                                    { render_option.render(selected) }
-                                </Chip>
-                            </leptonic-select-option>
-                        }}).collect_view()
-                    }
-
-                    <leptonic-select-show-trigger>
-                        {move || match show_options.get() {
-                            true => view! { <Icon icon=icondata::BsCaretUpFill/>},
-                            false => view! { <Icon icon=icondata::BsCaretDownFill/>}
-                        }}
-                    </leptonic-select-show-trigger>
-                </leptonic-select-selected>
-
-                <leptonic-select-options class:shown=move || show_options.get()>
-                    <TextInput
-                        get=search
-                        set=set_search
-                        should_be_focused=search_should_be_focused
-                        on_focus_change=move |focused| {
-                            // We only update our state as long as show_options is true.
-                            // It it is no longer true, the dropdown is no longer shown through a CSS rule (display: none).
-                            // This will automatically de-focus the search input if it had focus, resulting in a call of this callback.
-                            // When storing the received `false` in `search_is_focused` before our effect above, resetting focus on our wrapper may, runs,
-                            // that create_effect will not be able to set the focus. We accept not setting `search_is_focused` all the time
-                            // for the create_effect above to work reliably.
-                            if show_options.get_untracked() {
-                                set_search_is_focused.set(focused);
-                            }
-                        }
-                        class="search"
-                    />
-
-                    <Show
-                        when=move || show_options.get()
-                        fallback=move || ()
-                    >
-                        // TOD: Use <For> once leptos 0.4 is out. Use full option for hash.
-                        { filtered_options.get().into_iter().map(|option| {
-                            let clone1 = option.clone();
-                            let clone2 = option.clone();
-                            let clone3 = option.clone();
-                            let clone4 = option.clone();
-                            let clone5 = option.clone();
-                            view! {
-                                <leptonic-select-option
-                                    class:preselected=move || memoized_preselected.with(|preselected| preselected.as_ref() == Some(&option))
-                                    class:selected=move || is_selected(&clone4)
-                                    class:disabled=move || is_disabled(&clone5)
-                                    on:mouseenter=move |_e| {
-                                        set_preselected.set(Some(clone3.clone()));
-                                    }
-                                    on:click=move |_e| {
-                                        if !is_disabled_untracked(&clone2) {
-                                            select.consume(clone2.clone());
-                                        }
-                                    }
-                                >
-                                    { render_option.render(clone1) }
-                                </leptonic-select-option>
+            // TOD: Use <For> once leptos 0.4 is out. Use full option for hash.
+            { move || selected.get().into_iter().map(|selected| {
+                let clone = selected.clone();
+                view! {
+                    <leptonic-select-option>
+                        <Chip
+                            color=ChipColor::Secondary
+                            on:click=move |e| {
+                                e.stop_propagation();
                             }
-                        }).collect_view() }
-
-                        { move || match has_options.get() {
-                            true => ().into_view(),
-                            false => view! {
-                                <div class="option">
-                                    "No options..."
-                                </div>
-                            }.into_view(),
-                        } }
-                    </Show>
-                </leptonic-select-options>
-            </leptonic-select>
-        </div>
+                            dismissible=move |e: MouseEvent| {
+                                e.stop_propagation();
+                                state.deselect(&clone);
+                            }>
+                            { render_option.render(selected) }
+                        </Chip>
+                    </leptonic-select-option>
+                }}).collect_view()
+            }
+        </SelectShell>
     }
 }
 
-fn create_click_away_listener(
-    id_selector_string: String,
-    when: ReadSignal<bool>,
-    on_click_outside: Out<bool>,
-) {
-    let g_mouse_event =
-        use_context::<GlobalClickEvent>().expect("Must be a child of the Root component.");
-
-    create_effect(move |_old| {
-        use wasm_bindgen::JsCast;
-        let last_mouse_event = g_mouse_event.read_signal.get();
-
-        if when.get_untracked() {
-            if let Some(e) = last_mouse_event {
-                if let Some(target) = e.target() {
-                    if let Some(target_elem) = target.dyn_ref::<HtmlElement>() {
-                        match target_elem.closest(id_selector_string.as_ref()) {
-                            Ok(closest) => {
-                                if let Some(_found) = closest {
-                                    // User clicked on the options list. Ignoring this global mouse event.
-                                } else {
-                                    // User clicked outside.
-                                    on_click_outside.set(false);
-                                }
-                            }
-                            Err(err) => {
-                                tracing::error!("Error processing latest mouse event: {err:?}");
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    });
+/// Identifies a row by its generation and its position in that generation.
+type RowKey = (usize, usize);
+
+/// Keys the `rows` replacing the `previous` rows. Rows are not guaranteed to be unique and are therefore identified
+/// by their position. Every change of the rows starts a new generation, so that no view of a replaced row is reused
+/// for a different row at the same position.
+fn keyed_rows<O>(
+    previous: Option<&[(RowKey, SelectRow<O>)]>,
+    rows: Vec<SelectRow<O>>,
+) -> Vec<(RowKey, SelectRow<O>)> {
+    let generation = previous
+        .and_then(|previous| previous.first())
+        .map_or(0, |((generation, _), _)| generation + 1);
+    rows.into_iter()
+        .enumerate()
+        .map(|(index, row)| ((generation, index), row))
+        .collect()
 }
 
-fn create_key_down_listener<T: Fn(KeyboardEvent) + 'static>(then: T) {
-    let g_keyboard_event =
-        use_context::<GlobalKeyboardEvent>().expect("Must be a child of the Root component.");
+/// Hidden inputs submitting the `selected` options under `name` with a surrounding native `<form>`.
+fn hidden_inputs<O: 'static>(
+    name: Oco<'static, str>,
+    selected: impl Fn() -> Vec<O> + 'static,
+    form_value: Consumer<O, String>,
+) -> View {
+    view! {
+        <HiddenInputs
+            name=name
+            values=Signal::derive(move || selected().into_iter().map(|option| form_value.consume(option)).collect())
+        />
+    }
+    .into_view()
+}
 
-    create_effect(move |_old| {
-        let g_keyboard_event = g_keyboard_event.read_signal.get();
-        if let Some(e) = g_keyboard_event {
-            then(e);
-        }
-    });
+#[cfg(test)]
+mod tests {
+    use super::{keyed_rows, SelectRow};
+
+    #[test]
+    fn replaced_rows_get_new_keys() {
+        let first = keyed_rows(
+            None,
+            vec![SelectRow::Group("a".to_owned()), SelectRow::Option(1)],
+        );
+        assert_eq!(
+            first,
+            vec![
+                ((0, 0), SelectRow::Group("a".to_owned())),
+                ((0, 1), SelectRow::Option(1))
+            ]
+        );
+
+        let second = keyed_rows(
+            Some(&first),
+            vec![SelectRow::Option(2), SelectRow::Option(2)],
+        );
+        assert_eq!(
+            second,
+            vec![
+                ((1, 0), SelectRow::Option(2)),
+                ((1, 1), SelectRow::Option(2))
+            ]
+        );
+        assert!(second
+            .iter()
+            .all(|(key, _)| first.iter().all(|(previous, _)| previous != key)));
+    }
 }
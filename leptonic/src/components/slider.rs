@@ -4,12 +4,16 @@ use leptos::*;
 use leptos_use::{use_element_bounding, use_element_hover};
 
 use crate::{
-    components::popover::{Popover, PopoverContent},
-    contexts::global_mouseup_event::GlobalMouseupEvent,
+    components::{
+        form_control::HiddenInputs,
+        popover::{Popover, PopoverContent},
+    },
+    hooks::{
+        use_slider, use_slider_thumb, PlacementX, PlacementY, SliderOrientation, SliderState,
+        UseSliderInput, UseSliderReturn, UseSliderThumbInput, UseSliderThumbReturn,
+    },
     prelude::Consumer,
-    utils::math::project_into_range,
-    Out, RelativeMousePosition, Size, TrackedElementClientBoundingRect,
-    UseElementBoundingReturnReadOnly,
+    Out, Size, UseElementBoundingReturnReadOnly,
 };
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
@@ -167,13 +171,16 @@ fn create_marks(
 }
 
 #[component]
-fn Marks(marks: Signal<Vec<Mark>>) -> impl IntoView {
+fn Marks(marks: Signal<Vec<Mark>>, orientation: SliderOrientation) -> impl IntoView {
     view! {
         <div class="marks">
             {
                 move || marks.get().into_iter()
                     .map(|mark| {
-                        let style = format!("left: {}%", mark.percentage * 100.0);
+                        let style = match orientation {
+                            SliderOrientation::Horizontal => format!("left: {}%", mark.percentage * 100.0),
+                            SliderOrientation::Vertical => format!("bottom: {}%", mark.percentage * 100.0),
+                        };
                         view! {
                             <div class="mark" class:in-range=move || mark.in_range.get() style=style>
                                 { match &mark.name {
@@ -210,22 +217,25 @@ impl Default for SliderPopover {
 }
 
 impl SliderPopover {
-    fn to_maybe_signal(self, knob_el: NodeRef<html::Div>, knob: &KnobControl) -> MaybeSignal<bool> {
+    fn to_maybe_signal(
+        self,
+        knob_el: NodeRef<html::Div>,
+        is_dragged: Signal<bool>,
+    ) -> MaybeSignal<bool> {
         match self {
             Self::Never => MaybeSignal::Static(false),
             Self::When { hovered, dragged } => match (hovered, dragged) {
                 (true, true) => {
                     let knob_is_hovered = use_element_hover(knob_el);
-                    let listening = knob.listening;
                     MaybeSignal::Dynamic(Signal::derive(move || {
-                        knob_is_hovered.get() || listening.get()
+                        knob_is_hovered.get() || is_dragged.get()
                     }))
                 }
                 (true, false) => {
                     let knob_is_hovered = use_element_hover(knob_el);
                     MaybeSignal::Dynamic(knob_is_hovered)
                 }
-                (false, true) => MaybeSignal::Dynamic(knob.listening.into()),
+                (false, true) => MaybeSignal::Dynamic(is_dragged),
                 (false, false) => MaybeSignal::Static(false),
             },
             Self::Always => MaybeSignal::Static(true),
@@ -233,6 +243,111 @@ impl SliderPopover {
     }
 }
 
+/// Style of the range element, highlighting the track between `from` and `to` (both in 0..1 range).
+fn range_style(orientation: SliderOrientation, from: f64, to: f64) -> String {
+    let (start, length) = (from * 100.0, (to - from) * 100.0);
+    match orientation {
+        SliderOrientation::Horizontal => format!("left: {start}%; width: {length}%;"),
+        SliderOrientation::Vertical => format!("bottom: {start}%; height: {length}%;"),
+    }
+}
+
+#[component]
+#[allow(clippy::too_many_arguments)]
+fn SliderThumb(
+    state: SliderState,
+    index: usize,
+    value: MaybeSignal<f64>,
+    set_value: Out<f64>,
+    bar_el: NodeRef<html::Div>,
+    popover: SliderPopover,
+    value_display: Option<Consumer<f64, String>>,
+) -> impl IntoView {
+    let knob_el: NodeRef<html::Div> = create_node_ref();
+    let bar_bounds = use_element_bounding(bar_el);
+
+    let UseSliderThumbReturn {
+        props,
+        percent,
+        is_dragged,
+    } = use_slider_thumb(UseSliderThumbInput {
+        thumb_ref: knob_el,
+        state,
+        index,
+        value: Signal::derive(move || value.get()),
+        set_value: Callback::new(move |v| set_value.set(v)),
+        value_display,
+        aria_label: None,
+        disabled: false.into(),
+    });
+
+    let show_popover = popover.to_maybe_signal(knob_el, is_dragged);
+
+    let orientation = state.orientation();
+    let knob_style = Signal::derive(move || match orientation {
+        SliderOrientation::Horizontal => format!("left: {}%", percent.get() * 100.0),
+        SliderOrientation::Vertical => format!("bottom: {}%", percent.get() * 100.0),
+    });
+
+    let popover_content = move || {
+        let value = value.get();
+        match &value_display {
+            Some(callback) => callback.consume(value),
+            None => format!("{value}"),
+        }
+    };
+
+    let knob = view! {
+        <div
+            {..props.attrs}
+            node_ref=knob_el
+            class="knob"
+            class:is-dragged=move || is_dragged.get()
+            style=move || knob_style.get()
+            on:keydown=props.on_key_down
+        />
+    };
+
+    view! {
+        <div class="knob-wrapper">
+            { match orientation {
+                SliderOrientation::Horizontal => {
+                    let pos_x = Consumer::new(move |pop_bounds: UseElementBoundingReturnReadOnly| {
+                        format!(
+                            "calc({}px + {}px - {}px)",
+                            bar_bounds.x.get(),
+                            (percent.get() * bar_bounds.width.get()),
+                            (pop_bounds.width.get() / 2.0)
+                        )
+                    });
+                    view! {
+                        <Popover show=show_popover position_x=pos_x margin=Size::Em(1.2)>
+                            <PopoverContent slot>{popover_content}</PopoverContent>
+                            {knob}
+                        </Popover>
+                    }
+                }
+                SliderOrientation::Vertical => {
+                    let pos_y = Consumer::new(move |pop_bounds: UseElementBoundingReturnReadOnly| {
+                        format!(
+                            "calc({}px + {}px - {}px)",
+                            bar_bounds.y.get(),
+                            ((1.0 - percent.get()) * bar_bounds.height.get()),
+                            (pop_bounds.height.get() / 2.0)
+                        )
+                    });
+                    view! {
+                        <Popover show=show_popover placement_x=PlacementX::OuterRight placement_y=PlacementY::Center position_y=pos_y margin=Size::Em(1.2)>
+                            <PopoverContent slot>{popover_content}</PopoverContent>
+                            {knob}
+                        </Popover>
+                    }
+                }
+            } }
+        </div>
+    }
+}
+
 #[component]
 pub fn Slider(
     #[prop(into)] value: MaybeSignal<f64>,
@@ -241,6 +356,7 @@ pub fn Slider(
     max: f64,
     #[prop(optional)] step: Option<f64>,
     #[prop(optional)] variant: SliderVariant,
+    #[prop(optional)] orientation: SliderOrientation,
     #[prop(optional)] popover: SliderPopover,
     #[prop(optional)] active: bool,
     #[prop(optional)] disabled: bool,
@@ -249,46 +365,24 @@ pub fn Slider(
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
     #[prop(into, optional)] value_display: Option<Consumer<f64, String>>,
+    /// Submits the value with a surrounding native `<form>` under this name.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
 ) -> impl IntoView {
     let range = create_memo(move |_| max - min);
 
     let bar_el: NodeRef<html::Div> = create_node_ref();
-    let bar = TrackedElementClientBoundingRect::new(bar_el);
-    let cursor = RelativeMousePosition::new(bar);
-    let knob_el: NodeRef<html::Div> = create_node_ref();
-    let bar_bounds = use_element_bounding(bar_el);
-    let knob = KnobControl::new(min, max, step, value);
-    let show_popover = popover.to_maybe_signal(knob_el, &knob);
-
-    let range_style = Signal::derive(move || {
-        format!(
-            "left: 0%; width: {}%;",
-            knob.clipped_value_percent.get() * 100.0
-        )
-    });
-
-    // Stop listening whenever any mouseup event got fired.
-    let GlobalMouseupEvent {
-        read_signal: mouse_up,
-        ..
-    } = expect_context();
-    create_effect(move |_| {
-        if mouse_up.get().is_some() {
-            knob.set_listening.set(false);
-        }
+    let UseSliderReturn { props, state } = use_slider(UseSliderInput {
+        track_ref: bar_el,
+        min,
+        max,
+        step,
+        orientation,
+        disabled: disabled.into(),
     });
 
-    // While this slider is "listening", project the relative cursor position into the sliders value range and propagate.
-    create_effect(move |_| {
-        if knob.listening.get() {
-            set_value.set(project_into_range(
-                cursor.rel_mouse_pos.get().0,
-                range.get(),
-                min,
-                step,
-            ));
-        }
-    });
+    let range_style =
+        Signal::derive(move || range_style(orientation, 0.0, state.percent(value.get())));
 
     let marks = create_marks(
         min,
@@ -303,73 +397,35 @@ pub fn Slider(
         value_display,
     );
 
-    let pos_x = Consumer::new(move |pop_bounds: UseElementBoundingReturnReadOnly| {
-        format!(
-            "calc({}px + {}px - {}px)",
-            bar_bounds.x.get(),
-            (knob.clipped_value_percent.get() * bar_bounds.width.get()),
-            (pop_bounds.width.get() / 2.0)
-        )
-    });
-
     view! {
         <leptonic-slider
+            {..props.attrs}
             id=id
             data-variant=variant.to_str()
             class=class
             class:active=active
             class:disabled=disabled
             style=style
-            // Note(lukas): Setting set_listening to false is handled though capturing a global mouseup event,
-            // as the user may click, drag and move the cursor outside of this element.
-            on:mousedown=move |_e| {
-                bar.track_client_rect();
-                knob.set_listening.set(true);
-            }
-            on:touchstart=move |_e| {
-                bar.track_client_rect();
-                knob.set_listening.set(true);
-            }
-            on:touchmove=move |e| {
-                if knob.listening.get_untracked() {
-                    e.prevent_default();
-                    e.stop_propagation();
-                }
-            }
-            on:touchend=move |_e| knob.set_listening.set(false)
+            on:pointerdown=props.on_pointer_down
         >
             <div class="bar-wrapper">
                 <div node_ref=bar_el class="bar">
                     <div class="range" style=move || range_style.get()></div>
-
-                    <div class="knob-wrapper">
-
-                        <Popover show=show_popover position_x=pos_x margin=Size::Em(1.2)>
-                            <PopoverContent slot>
-                                {move || {
-                                    let value = value.get();
-                                    match &value_display {
-                                        Some(callback) => callback.consume(value),
-                                        None => format!("{value}"),
-                                    }
-                                }}
-                            </PopoverContent>
-
-                            <div class="knob" class:is-dragged=move || knob.listening.get() tabindex=0 style=move || knob.style.get() />
-                        </Popover>
-                    </div>
+                    <SliderThumb state=state index=0 value=value set_value=set_value bar_el=bar_el popover=popover value_display=value_display/>
                 </div>
             </div>
 
-            <Marks marks=marks/>
+            <Marks marks=marks orientation=orientation/>
 
+            { name.map(|name| view! {
+                <HiddenInputs name=name values=Signal::derive(move || vec![value.get().to_string()]) disabled=disabled/>
+            }) }
         </leptonic-slider>
     }
 }
 
 #[component]
 #[allow(clippy::similar_names)]
-#[allow(clippy::too_many_lines)]
 pub fn RangeSlider(
     #[prop(into)] value_a: MaybeSignal<f64>,
     #[prop(into)] value_b: MaybeSignal<f64>,
@@ -379,6 +435,7 @@ pub fn RangeSlider(
     max: f64,
     #[prop(optional)] step: Option<f64>,
     #[prop(optional)] variant: SliderVariant,
+    #[prop(optional)] orientation: SliderOrientation,
     #[prop(optional)] popover: SliderPopover,
     #[prop(optional)] active: bool,
     #[prop(optional)] disabled: bool,
@@ -387,78 +444,31 @@ pub fn RangeSlider(
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
     #[prop(into, optional)] value_display: Option<Consumer<f64, String>>,
+    /// Submits both values with a surrounding native `<form>` under this name, `value_a` first.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
 ) -> impl IntoView {
     let range = create_memo(move |_| max - min);
 
     let bar_el: NodeRef<html::Div> = create_node_ref();
-    let bar = TrackedElementClientBoundingRect::new(bar_el);
-    let bar_bounds = use_element_bounding(bar_el);
-    let cursor = RelativeMousePosition::new(bar);
-    let knob_a_el: NodeRef<html::Div> = create_node_ref();
-    let knob_b_el: NodeRef<html::Div> = create_node_ref();
-    let knob_a = KnobControl::new(min, max, step, value_a);
-    let knob_b = KnobControl::new(min, max, step, value_b);
-    let show_a_popover = popover.to_maybe_signal(knob_a_el, &knob_a);
-    let show_b_popover = popover.to_maybe_signal(knob_b_el, &knob_b);
+    let UseSliderReturn { props, state } = use_slider(UseSliderInput {
+        track_ref: bar_el,
+        min,
+        max,
+        step,
+        orientation,
+        disabled: disabled.into(),
+    });
 
+    // Thumbs can not be moved past each other. Thumb a always stays on the `min` side of thumb b.
     let range_style = Signal::derive(move || {
-        format!(
-            "left: {}%; width: {}%;",
-            knob_a.clipped_value_percent.get() * 100.0,
-            knob_b
-                .clipped_value_percent
-                .get()
-                .mul_add(100.0, -knob_a.clipped_value_percent.get() * 100.0)
+        range_style(
+            orientation,
+            state.percent(value_a.get()),
+            state.percent(value_b.get()),
         )
     });
 
-    // Stop listening whenever any mouseup event got fired.
-    let GlobalMouseupEvent {
-        read_signal: mouse_up,
-        ..
-    } = expect_context();
-    create_effect(move |_| {
-        if mouse_up.get().is_some() {
-            knob_a.set_listening.set(false);
-            knob_b.set_listening.set(false);
-        }
-    });
-
-    // Project the relative cursor position into the sliders value range.
-    let projected_value_from_cursor = create_memo(move |_| {
-        project_into_range(cursor.rel_mouse_pos.get().0, range.get(), min, step)
-    });
-
-    // While this slider is "listening", propagate the projected value.
-    create_effect(move |_| {
-        if knob_a.listening.get() {
-            let projected_value_from_cursor = projected_value_from_cursor.get();
-            let b = value_b.get_untracked();
-            if projected_value_from_cursor > b {
-                set_value_a.set(b);
-                set_value_b.set(projected_value_from_cursor);
-
-                knob_a.set_listening.set(false);
-                knob_b.set_listening.set(true);
-            } else {
-                set_value_a.set(projected_value_from_cursor);
-            }
-        }
-        if knob_b.listening.get() {
-            let projected_value_from_cursor = projected_value_from_cursor.get();
-            let a = value_a.get_untracked();
-            if projected_value_from_cursor < a {
-                set_value_b.set(a);
-                set_value_a.set(projected_value_from_cursor);
-
-                knob_b.set_listening.set(false);
-                knob_a.set_listening.set(true);
-            } else {
-                set_value_b.set(projected_value_from_cursor);
-            }
-        }
-    });
-
     let marks = create_marks(
         min,
         max,
@@ -472,158 +482,34 @@ pub fn RangeSlider(
         value_display,
     );
 
-    let value_display_a = value_display;
-    let value_display_b = value_display;
-
-    let knob_a_pos_x = Consumer::new(move |pop_bounds: UseElementBoundingReturnReadOnly| {
-        format!(
-            "calc({}px + {}px - {}px)",
-            bar_bounds.x.get(),
-            (knob_a.clipped_value_percent.get() * bar_bounds.width.get()),
-            (pop_bounds.width.get() / 2.0)
-        )
-    });
-    let knob_b_pos_x = Consumer::new(move |pop_bounds: UseElementBoundingReturnReadOnly| {
-        format!(
-            "calc({}px + {}px - {}px)",
-            bar_bounds.x.get(),
-            (knob_b.clipped_value_percent.get() * bar_bounds.width.get()),
-            (pop_bounds.width.get() / 2.0)
-        )
-    });
-
     view! {
         <leptonic-slider
+            {..props.attrs}
             id=id
             data-variant=variant.to_str()
             class=class
             class:active=active
             class:disabled=disabled
             style=style
-            // Note(lukas): Setting set_listening to false is handled though capturing a global mouseup event,
-            // as the user may click, drag and move the cursor outside of this element.
-            on:mousedown=move |_e| {
-                bar.track_client_rect();
-                let could_be = projected_value_from_cursor.get();
-                let distance_to_a = (value_a.get() - could_be).abs();
-                let distance_to_b = (value_b.get() - could_be).abs();
-                if distance_to_a < distance_to_b {
-                    knob_a.set_listening.set(true);
-                } else {
-                    knob_b.set_listening.set(true);
-                }
-            }
-            on:touchstart=move |_e| {
-                bar.track_client_rect();
-            }
-            // Note(lukas): We do not use on:touchstart event here to trigger the listening functionality.
-            // Instead, the code handling it lives in on:touchmove.
-            // The reason for this is that the use_mouse function must receive the initial on:touchstart event FIRST,
-            // so that a correct cursor.clipped_value can be computed. We can only then check whether or not the user
-            // touched more towards the left or right knob.
-            // Limitation: The initial touch event no longer results in a direct value change. But the value is set after touchmove or touchend.
-            on:touchmove=move |e| {
-                if knob_a.listening.get_untracked() || knob_b.listening.get_untracked() {
-                    e.prevent_default();
-                    e.stop_propagation();
-                } else {
-                    let could_be = projected_value_from_cursor.get();
-                    let distance_to_a = (value_a.get() - could_be).abs();
-                    let distance_to_b = (value_b.get() - could_be).abs();
-                    if distance_to_a < distance_to_b {
-                        knob_a.set_listening.set(true);
-                    } else {
-                        knob_b.set_listening.set(true);
-                    }
-                }
-            }
-            on:touchend=move |_e| {
-                knob_a.set_listening.set(false);
-                knob_b.set_listening.set(false);
-            }
+            on:pointerdown=props.on_pointer_down
         >
             <div class="bar-wrapper">
                 <div node_ref=bar_el class="bar">
-                    <div class="knob-wrapper">
-                        <Popover show=show_a_popover position_x=knob_a_pos_x margin=Size::Em(1.2)>
-                            <PopoverContent slot>
-                                {move || {
-                                    let value = value_a.get();
-                                    match &value_display_a {
-                                        Some(callback) => callback.consume(value),
-                                        None => format!("{value}"),
-                                    }
-                                }}
-                            </PopoverContent>
-
-                            <div class="knob" class:is-dragged=move || knob_a.listening.get() tabindex=0 style=move || knob_a.style.get() />
-                        </Popover>
-                    </div>
+                    <SliderThumb state=state index=0 value=value_a set_value=set_value_a bar_el=bar_el popover=popover value_display=value_display/>
                     <div class="range" style=move || range_style.get()></div>
-                    <div class="knob-wrapper">
-                        <Popover show=show_b_popover position_x=knob_b_pos_x margin=Size::Em(1.2)>
-                            <PopoverContent slot>
-                                {move || {
-                                    let value = value_b.get();
-                                    match &value_display_b {
-                                        Some(callback) => callback.consume(value),
-                                        None => format!("{value}"),
-                                    }
-                                }}
-                            </PopoverContent>
-
-                            <div class="knob" class:is-dragged=move || knob_b.listening.get() tabindex=0 style=move || knob_b.style.get() />
-                        </Popover>
-                    </div>
+                    <SliderThumb state=state index=1 value=value_b set_value=set_value_b bar_el=bar_el popover=popover value_display=value_display/>
                 </div>
             </div>
 
-            <Marks marks=marks/>
-        </leptonic-slider>
-    }
-}
-
-struct KnobControl {
-    #[allow(unused)]
-    clipped_value: Signal<f64>,
-    clipped_value_percent: Signal<f64>,
-    style: Signal<String>,
-    listening: ReadSignal<bool>,
-    set_listening: WriteSignal<bool>,
-}
+            <Marks marks=marks orientation=orientation/>
 
-impl KnobControl {
-    pub(crate) fn new(min: f64, max: f64, step: Option<f64>, value: MaybeSignal<f64>) -> Self {
-        let range = create_memo(move |_| max - min);
-        let clipped_value = Signal::derive(move || {
-            let value = value.get();
-            if !(min..=max).contains(&value) && !(max..=min).contains(&value) {
-                tracing::warn!(
-                    "Slider was given the value {value} which is outside the range [{min}, {max}]. Value will be clipped on first use of this slider."
-                );
-            }
-            let clipped: f64 = if min < max {
-                f64::min(f64::max(value, min), max)
-            } else {
-                f64::min(f64::max(value, max), min)
-            };
-            // Round to the nearest step if a step-value was provided.
-            match step {
-                Some(step) => (clipped / step).round() * step,
-                None => clipped,
-            }
-        });
-        let clipped_value_percent =
-            Signal::derive(move || ((min.abs() - clipped_value.get()) / range.get()).abs());
-        let style =
-            Signal::derive(move || format!("left: {}%", clipped_value_percent.get() * 100.0));
-        let (listening, set_listening) = create_signal(false);
-        Self {
-            clipped_value,
-            clipped_value_percent,
-            style,
-            listening,
-            set_listening,
-        }
+            { name.map(|name| view! {
+                <HiddenInputs
+                    name=name
+                    values=Signal::derive(move || vec![value_a.get().to_string(), value_b.get().to_string()])
+                    disabled=disabled
+                />
+            }) }
+        </leptonic-slider>
     }
 }
@@ -21,10 +21,14 @@ pub fn Table(
     #[prop(into, optional)] id: Option<AttributeValue>,
     #[prop(into, optional)] class: Option<AttributeValue>,
     #[prop(into, optional)] style: Option<AttributeValue>,
+    /// Arbitrary additional attributes.
+    #[prop(attrs)]
+    attributes: Vec<(&'static str, Attribute)>,
     children: Children,
 ) -> impl IntoView {
     view! {
         <leptonic-table
+            {..attributes}
             id=id
             class=class
             class:leptonic-table-bordered=bordered.unwrap_or(false)
@@ -37,34 +41,54 @@ pub fn Table(
 }
 
 #[component]
-pub fn TableHeader(children: Children) -> impl IntoView {
+pub fn TableHeader(
+    /// Arbitrary additional attributes.
+    #[prop(attrs)]
+    attributes: Vec<(&'static str, Attribute)>,
+    children: Children,
+) -> impl IntoView {
     view! {
-        <leptonic-table-header>{children()}</leptonic-table-header>
+        <leptonic-table-header {..attributes}>{children()}</leptonic-table-header>
     }
 }
 
 #[component]
-pub fn TableBody(children: Children) -> impl IntoView {
+pub fn TableBody(
+    /// Arbitrary additional attributes.
+    #[prop(attrs)]
+    attributes: Vec<(&'static str, Attribute)>,
+    children: Children,
+) -> impl IntoView {
     view! {
-        <leptonic-table-body>
+        <leptonic-table-body {..attributes}>
             {children()}
         </leptonic-table-body>
     }
 }
 
 #[component]
-pub fn TableFooter(children: Children) -> impl IntoView {
+pub fn TableFooter(
+    /// Arbitrary additional attributes.
+    #[prop(attrs)]
+    attributes: Vec<(&'static str, Attribute)>,
+    children: Children,
+) -> impl IntoView {
     view! {
-        <leptonic-table-footer>
+        <leptonic-table-footer {..attributes}>
             {children()}
         </leptonic-table-footer>
     }
 }
 
 #[component]
-pub fn TableRow(children: Children) -> impl IntoView {
+pub fn TableRow(
+    /// Arbitrary additional attributes.
+    #[prop(attrs)]
+    attributes: Vec<(&'static str, Attribute)>,
+    children: Children,
+) -> impl IntoView {
     view! {
-        <leptonic-table-row>
+        <leptonic-table-row {..attributes}>
             {children()}
         </leptonic-table-row>
     }
@@ -73,19 +97,27 @@ pub fn TableRow(children: Children) -> impl IntoView {
 #[component]
 pub fn TableHeaderCell(
     #[prop(optional)] min_width: Option<bool>,
+    /// Arbitrary additional attributes.
+    #[prop(attrs)]
+    attributes: Vec<(&'static str, Attribute)>,
     children: Children,
 ) -> impl IntoView {
     view! {
-        <leptonic-table-header-cell class:min-width=min_width.unwrap_or(false)>
+        <leptonic-table-header-cell {..attributes} class:min-width=min_width.unwrap_or(false)>
             {children()}
         </leptonic-table-header-cell>
     }
 }
 
 #[component]
-pub fn TableCell(children: Children) -> impl IntoView {
+pub fn TableCell(
+    /// Arbitrary additional attributes.
+    #[prop(attrs)]
+    attributes: Vec<(&'static str, Attribute)>,
+    children: Children,
+) -> impl IntoView {
     view! {
-        <leptonic-table-cell>
+        <leptonic-table-cell {..attributes}>
             {children()}
         </leptonic-table-cell>
     }
@@ -100,6 +100,8 @@ where
             on: on.icon(),
             off: off.icon(),
         }),
+        name: None,
+        value: None,
     });
 
     view! {
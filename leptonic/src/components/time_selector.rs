@@ -0,0 +1,219 @@
+use leptos::*;
+use web_sys::{HtmlInputElement, KeyboardEvent};
+
+use crate::{
+    utils::time::{clamp_date_time, from_12_hour, snap_value, step_value, to_12_hour, HourCycle},
+    Out,
+};
+
+/// Selects the time of `value`, keeping its date.
+/// Every part of the time is a spin button: Use the arrows or the arrow keys to step it, Home and End to jump to its ends,
+/// or type a number. The emitted value always lies between `min` and `max`.
+#[component]
+#[allow(clippy::too_many_lines)]
+pub fn TimeSelector(
+    #[prop(into)] value: Signal<time::OffsetDateTime>,
+    #[prop(into)] on_change: Out<time::OffsetDateTime>,
+    #[prop(optional)] min: Option<time::OffsetDateTime>,
+    #[prop(optional)] max: Option<time::OffsetDateTime>,
+    #[prop(optional)] hour_cycle: HourCycle,
+    /// Minutes are selected in slots of this size, e.g. 15 for quarter hours. Should divide 60.
+    #[prop(optional, default = 1)]
+    minute_step: u8,
+    #[prop(optional)] show_seconds: bool,
+    /// Seconds are selected in slots of this size. Should divide 60.
+    #[prop(optional, default = 1)]
+    second_step: u8,
+) -> impl IntoView {
+    let emit = move |time: time::Time| {
+        let current = value.get_untracked();
+        let new_value = clamp_date_time(current.replace_time(time), min.as_ref(), max.as_ref());
+        if new_value != current {
+            on_change.set(new_value);
+        }
+    };
+
+    let time = Signal::derive(move || value.get().time());
+    let hour = Signal::derive(move || time.get().hour());
+    let minute = Signal::derive(move || time.get().minute());
+    let second = Signal::derive(move || time.get().second());
+    let is_pm = Signal::derive(move || hour.get() >= 12);
+
+    let set_hour = move |h: u8| {
+        if let Ok(time) = time.get_untracked().replace_hour(h) {
+            emit(time);
+        }
+    };
+    let set_minute = move |m: u8| {
+        if let Ok(time) = time.get_untracked().replace_minute(m) {
+            emit(time);
+        }
+    };
+    let set_second = move |s: u8| {
+        if let Ok(time) = time.get_untracked().replace_second(s) {
+            emit(time);
+        }
+    };
+
+    let (hour_text, hour_min, hour_max, hour_now) = match hour_cycle {
+        HourCycle::H24 => (
+            Signal::derive(move || format!("{:02}", hour.get())),
+            0,
+            23,
+            hour,
+        ),
+        HourCycle::H12 => (
+            Signal::derive(move || format!("{:02}", to_12_hour(hour.get()).0)),
+            1,
+            12,
+            Signal::derive(move || to_12_hour(hour.get()).0),
+        ),
+    };
+
+    let toggle_period = move || {
+        let (h, pm) = to_12_hour(hour.get_untracked());
+        set_hour(from_12_hour(h, !pm));
+    };
+
+    view! {
+        <leptonic-datetime>
+            <leptonic-time-selector class="time-selector">
+                <div class="selections">
+                    <TimeSpinButton
+                        class="hour-selection"
+                        label="Hours"
+                        text=hour_text
+                        value_now=hour_now
+                        value_min=hour_min
+                        value_max=hour_max
+                        on_step=move |delta| set_hour(step_value(hour.get_untracked(), 24, 1, delta))
+                        on_enter=move |h: u8| match hour_cycle {
+                            HourCycle::H24 => set_hour(h),
+                            HourCycle::H12 => set_hour(from_12_hour(h, is_pm.get_untracked())),
+                        }
+                    />
+                    <div class="separator">":"</div>
+                    <TimeSpinButton
+                        class="minute-selection"
+                        label="Minutes"
+                        text=Signal::derive(move || format!("{:02}", minute.get()))
+                        value_now=minute
+                        value_min=0
+                        value_max=59
+                        on_step=move |delta| set_minute(step_value(minute.get_untracked(), 60, minute_step, delta))
+                        on_enter=move |m| set_minute(snap_value(m, 60, minute_step))
+                    />
+                    { show_seconds.then(|| view! {
+                        <div class="separator">":"</div>
+                        <TimeSpinButton
+                            class="second-selection"
+                            label="Seconds"
+                            text=Signal::derive(move || format!("{:02}", second.get()))
+                            value_now=second
+                            value_min=0
+                            value_max=59
+                            on_step=move |delta| set_second(step_value(second.get_untracked(), 60, second_step, delta))
+                            on_enter=move |s| set_second(snap_value(s, 60, second_step))
+                        />
+                    }) }
+                    { (hour_cycle == HourCycle::H12).then(|| view! {
+                        <div class="period-selection">
+                            <button
+                                type="button"
+                                aria-label="AM/PM"
+                                on:click=move |_| toggle_period()
+                                on:keydown=move |e: KeyboardEvent| match e.key().as_str() {
+                                    "ArrowUp" | "ArrowDown" => {
+                                        e.prevent_default();
+                                        toggle_period();
+                                    }
+                                    "a" | "A" if is_pm.get_untracked() => toggle_period(),
+                                    "p" | "P" if !is_pm.get_untracked() => toggle_period(),
+                                    _ => {}
+                                }
+                            >
+                                { move || match is_pm.get() {
+                                    true => "PM",
+                                    false => "AM",
+                                } }
+                            </button>
+                        </div>
+                    }) }
+                </div>
+            </leptonic-time-selector>
+        </leptonic-datetime>
+    }
+}
+
+/// A single part of the time, e.g. the hours.
+#[component]
+fn TimeSpinButton(
+    class: &'static str,
+    label: &'static str,
+    #[prop(into)] text: Signal<String>,
+    #[prop(into)] value_now: Signal<u8>,
+    value_min: u8,
+    value_max: u8,
+    /// Called with the number of slots to move the value by.
+    #[prop(into)]
+    on_step: Out<i32>,
+    /// Called with a number typed by the user, if it lies between `value_min` and `value_max`.
+    #[prop(into)]
+    on_enter: Out<u8>,
+) -> impl IntoView {
+    let on_key_down = move |e: KeyboardEvent| {
+        let handled = match e.key().as_str() {
+            "ArrowUp" => {
+                on_step.set(1);
+                true
+            }
+            "ArrowDown" => {
+                on_step.set(-1);
+                true
+            }
+            "Home" => {
+                on_enter.set(value_min);
+                true
+            }
+            "End" => {
+                on_enter.set(value_max);
+                true
+            }
+            _ => false,
+        };
+        if handled {
+            e.prevent_default();
+        }
+    };
+
+    let on_change = move |e: ev::Event| {
+        let input = event_target::<HtmlInputElement>(&e);
+        match input.value().trim().parse::<u8>() {
+            Ok(typed) if (value_min..=value_max).contains(&typed) => on_enter.set(typed),
+            _ => {}
+        }
+        // The value may not have changed, e.g. because the input was invalid. Always show the current value.
+        input.set_value(&text.get_untracked());
+    };
+
+    view! {
+        <div class=class>
+            <div class="arrow-up" on:click=move |_| on_step.set(1)></div>
+            <input
+                type="text"
+                inputmode="numeric"
+                size="2"
+                role="spinbutton"
+                aria-label=label
+                aria-valuemin=value_min
+                aria-valuemax=value_max
+                aria-valuenow=move || value_now.get()
+                aria-valuetext=move || text.get()
+                prop:value=move || text.get()
+                on:keydown=on_key_down
+                on:change=on_change
+            />
+            <div class="arrow-down" on:click=move |_| on_step.set(-1)></div>
+        </div>
+    }
+}
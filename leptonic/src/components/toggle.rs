@@ -1,6 +1,9 @@
 use leptos::*;
 
-use crate::{components::icon::Icon, prelude::*};
+use crate::{
+    components::{form_control::HiddenInputs, icon::Icon},
+    prelude::*,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ToggleSize {
@@ -65,7 +68,27 @@ pub fn Toggle(
     #[prop(optional)] size: ToggleSize,
     #[prop(optional)] variant: ToggleVariant,
     #[prop(into, optional)] icons: Option<ToggleIcons>,
+    /// Submits the toggle with a surrounding native `<form>` under this name, but only when on.
+    #[prop(into, optional)]
+    name: Option<Oco<'static, str>>,
+    /// Value submitted when the toggle is on. Defaults to "on", like a native checkbox.
+    #[prop(into, optional)]
+    value: Option<Oco<'static, str>>,
 ) -> impl IntoView {
+    let hidden_inputs = name.map(|name| {
+        let value = value.unwrap_or(Oco::Borrowed("on"));
+        view! {
+            <HiddenInputs
+                name=name
+                values=Signal::derive(move || match state.get() {
+                    true => vec![value.to_string()],
+                    false => Vec::new(),
+                })
+                disabled=Signal::derive(move || disabled.0.as_ref().map(SignalGet::get).unwrap_or(false))
+            />
+        }
+    });
+
     view! {
         <leptonic-toggle-wrapper class=class style=style>
             <leptonic-toggle
@@ -96,6 +119,7 @@ pub fn Toggle(
                         })
                     }
                 </span>
+                { hidden_inputs }
             </leptonic-toggle>
         </leptonic-toggle-wrapper>
     }
@@ -0,0 +1,173 @@
+use std::{rc::Rc, time::Duration};
+
+use leptos::*;
+use uuid::Uuid;
+use web_sys::{FocusEvent, PointerEvent};
+
+use crate::{
+    components::popover::{PopoverData, PopoverRootContext},
+    hooks::{
+        use_focus_visible, use_tooltip, use_tooltip_trigger, OverlayBoundary, PlacementX,
+        PlacementY, UseTooltipInput, UseTooltipReturn, UseTooltipTriggerInput,
+        UseTooltipTriggerReturn,
+    },
+    utils::{locale::WritingDirection, EventTargetExt, NodeRefExt},
+    OptMaybeSignal,
+};
+
+#[derive(Clone)]
+#[slot]
+pub struct TooltipContent {
+    children: ChildrenFn,
+}
+
+/// Shows `tooltip_content` when the wrapped content is hovered or receives keyboard focus.
+/// The tooltip is rendered through the `PopoverRoot` host, which is part of `Root`.
+#[component]
+pub fn Tooltip(
+    #[prop(into, optional)] id: Option<AttributeValue>,
+    #[prop(into, optional)] class: Option<AttributeValue>,
+    #[prop(into, optional)] style: Option<AttributeValue>,
+
+    /// Disabled tooltips are never shown.
+    #[prop(into, optional)]
+    disabled: OptMaybeSignal<bool>,
+
+    /// Time the content must be hovered before the tooltip is shown.
+    #[prop(default = Duration::from_millis(1500))]
+    delay: Duration,
+
+    /// Time after which the tooltip is hidden when the pointer left the content.
+    #[prop(default = Duration::from_millis(500))]
+    close_delay: Duration,
+
+    /// Only show the tooltip when the content receives focus, never on hover.
+    #[prop(optional)]
+    trigger_on_focus_only: bool,
+
+    #[prop(into, default = PlacementX::Center.into())] placement_x: MaybeSignal<PlacementX>,
+    #[prop(into, default = PlacementY::Above.into())] placement_y: MaybeSignal<PlacementY>,
+    #[prop(into, default = WritingDirection::Ltr.into())] writing_direction: MaybeSignal<
+        WritingDirection,
+    >,
+
+    /// Distance in px between the content and the tooltip.
+    #[prop(into, default = 6.0.into())]
+    offset: MaybeSignal<f64>,
+
+    /// The area the tooltip is kept inside of when being flipped or shifted.
+    #[prop(optional)]
+    boundary: OverlayBoundary,
+
+    /// Render an arrow pointing at the content.
+    #[prop(optional)]
+    arrow: bool,
+
+    tooltip_content: TooltipContent,
+
+    children: Children,
+) -> impl IntoView {
+    let ctx = expect_context::<PopoverRootContext>();
+
+    let trigger_el: NodeRef<html::Custom> = create_node_ref();
+    let tooltip_el: NodeRef<html::Custom> = create_node_ref();
+    let arrow_el: NodeRef<html::Custom> = create_node_ref();
+
+    let UseTooltipTriggerReturn {
+        props: trigger_props,
+        tooltip_props,
+        arrow_props,
+        state,
+    } = use_tooltip_trigger(UseTooltipTriggerInput {
+        trigger_ref: trigger_el,
+        tooltip_ref: tooltip_el,
+        disabled: disabled.or(false),
+        delay,
+        close_delay,
+        trigger_on_focus_only,
+        placement_x,
+        placement_y,
+        writing_direction,
+        offset,
+        boundary,
+        arrow_ref: arrow.then(|| Signal::derive(move || arrow_el.get_element())),
+    });
+
+    let UseTooltipReturn { props } = use_tooltip(UseTooltipInput { state });
+
+    // The trigger element only wraps the focusable content. Focus must therefore be tracked for its whole subtree.
+    // Like the handlers of the hook, only keyboard focus shows the tooltip.
+    let is_disabled = disabled.or(false);
+    let is_focus_visible = use_focus_visible().is_focus_visible;
+    let on_focus_in = move |_e: FocusEvent| {
+        if !is_disabled.get_untracked() && is_focus_visible.get_untracked() {
+            state.open(true);
+        }
+    };
+    let on_focus_out = move |e: FocusEvent| {
+        let focus_stays_within = match (e.current_target(), e.related_target()) {
+            (Some(current), Some(related)) => match (current.as_node(), related.as_node()) {
+                (Some(current), Some(related)) => current.contains(Some(&related)),
+                _ => false,
+            },
+            _ => false,
+        };
+        if !focus_stays_within {
+            state.close(true);
+        }
+    };
+
+    let mut tooltip_attrs = tooltip_props.attrs;
+    tooltip_attrs.merge(props.attrs);
+    let on_pointer_enter: Rc<dyn Fn(PointerEvent)> = Rc::from(props.on_pointer_enter);
+    let on_pointer_leave: Rc<dyn Fn(PointerEvent)> = Rc::from(props.on_pointer_leave);
+    let is_open = state.is_open();
+
+    let key = Uuid::now_v7();
+
+    ctx.push(PopoverData {
+        key,
+        children: Rc::new(move || {
+            let on_pointer_enter = on_pointer_enter.clone();
+            let on_pointer_leave = on_pointer_leave.clone();
+            view! {
+                <leptonic-tooltip
+                    {..tooltip_attrs.clone()}
+                    node_ref=tooltip_el
+                    data-active=move || match is_open.get() { true => "true", false => "false" }
+                    on:pointerenter=move |e| on_pointer_enter(e)
+                    on:pointerleave=move |e| on_pointer_leave(e)
+                >
+                    { (tooltip_content.children)() }
+                    { arrow.then(|| view! {
+                        <leptonic-tooltip-arrow {..arrow_props.attrs.clone()} node_ref=arrow_el />
+                    }) }
+                </leptonic-tooltip>
+            }
+            .into_view()
+            .into()
+        }),
+    });
+
+    on_cleanup(move || {
+        ctx.remove(key);
+    });
+
+    view! {
+        <leptonic-tooltip-trigger
+            {..trigger_props.attrs}
+            node_ref=trigger_el
+            id=id
+            class=class
+            style=style
+            on:pointerenter=trigger_props.on_pointer_enter
+            on:pointerleave=trigger_props.on_pointer_leave
+            on:pointerdown=trigger_props.on_pointer_down
+            on:keydown=trigger_props.on_key_down
+            on:focusin=on_focus_in
+            on:focusout=on_focus_out
+        >
+            { children() }
+        </leptonic-tooltip-trigger>
+    }
+}
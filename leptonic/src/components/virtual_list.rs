@@ -0,0 +1,88 @@
+use std::{hash::Hash, rc::Rc};
+
+use leptos::*;
+
+use crate::hooks::{
+    use_virtualizer, UseVirtualizerInput, UseVirtualizerReturn, VirtualAlign, VirtualItemSize,
+};
+
+/// Renders only those of `each` items which are currently visible in the nearest scroll container (or the window).
+///
+/// No wrapper element is rendered. Items are placed between two spacer elements taking the space of all items not rendered,
+/// so the list can be used inside any container, e.g. a `TableBody`.
+#[component]
+pub fn VirtualList<T, K, KF, CF, IV>(
+    #[prop(into)] each: MaybeSignal<Vec<T>>,
+    /// Uniquely identifies an item. Rendered items are kept while they stay in view, and measured sizes stay with their items.
+    key: KF,
+    children: CF,
+    item_size: VirtualItemSize,
+    /// Number of items rendered in front of and behind the visible ones.
+    #[prop(optional, default = 5)]
+    overscan: usize,
+    /// Whenever this holds an index, the list is scrolled so that the item at this index is visible.
+    #[prop(into, optional)]
+    scroll_to_index: Option<Signal<Option<usize>>>,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    K: Eq + Hash + 'static,
+    KF: Fn(&T) -> K + 'static,
+    CF: Fn(T) -> IV + 'static,
+    IV: IntoView + 'static,
+{
+    let each = store_value(each);
+    let key = Rc::new(key);
+    let anchor_ref: NodeRef<html::Custom> = create_node_ref();
+
+    let UseVirtualizerReturn {
+        range,
+        padding_start,
+        padding_end,
+        scroll_to_index: scroll_to,
+        ..
+    } = use_virtualizer(UseVirtualizerInput {
+        anchor_ref,
+        count: Signal::derive(move || each.with_value(|each| each.with(Vec::len))),
+        key: Callback::new({
+            let key = key.clone();
+            move |index| {
+                each.with_value(|each| each.with(|each| each.get(index).map(|item| key(item))))
+            }
+        }),
+        item_size,
+        overscan,
+    });
+
+    if let Some(scroll_to_index) = scroll_to_index {
+        create_effect(move |_| {
+            if let Some(index) = scroll_to_index.get() {
+                scroll_to.call((index, VirtualAlign::Auto));
+            }
+        });
+    }
+
+    view! {
+        <leptonic-virtual-spacer
+            node_ref=anchor_ref
+            data-virtual-spacer
+            aria-hidden="true"
+            style=move || format!("height: {}px", padding_start.get())
+        />
+        <For
+            each=move || {
+                let range = range.get();
+                each.with_value(|each| {
+                    each.with(|each| each.get(range.start..range.end.min(each.len())).unwrap_or_default().to_vec())
+                })
+            }
+            key=move |item| key(item)
+            children=children
+        />
+        <leptonic-virtual-spacer
+            data-virtual-spacer
+            aria-hidden="true"
+            style=move || format!("height: {}px", padding_end.get())
+        />
+    }
+}
@@ -0,0 +1,120 @@
+use leptos::*;
+use leptos_use::{
+    use_document, use_event_listener_with_options, use_window, UseEventListenerOptions,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{KeyboardEvent, PointerEvent};
+
+/// The kind of input device the user last interacted with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modality {
+    Keyboard,
+    Pointer,
+    /// Focus was moved without a preceding keyboard or pointer interaction, e.g. by a screen reader.
+    Virtual,
+}
+
+/// Tracks the modality of the last user interaction. Provided by `<Root>`.
+#[derive(Debug, Clone, Copy)]
+pub struct GlobalModality {
+    pub read_signal: ReadSignal<Option<Modality>>,
+}
+
+impl GlobalModality {
+    /// Derives the current modality from the global keyboard and pointer events.
+    /// Focus events not preceded by any of these are considered `Modality::Virtual`.
+    pub fn new(
+        keyboard_event: ReadSignal<Option<KeyboardEvent>>,
+        pointer_down_event: ReadSignal<Option<PointerEvent>>,
+    ) -> Self {
+        let (modality, set_modality) = create_signal(None);
+        let had_event_before_focus = store_value(false);
+
+        create_effect(move |_| {
+            let Some(e) = keyboard_event.get() else {
+                return;
+            };
+            had_event_before_focus.set_value(true);
+            if is_valid_key(&e) {
+                set_modality.set(Some(Modality::Keyboard));
+            }
+        });
+
+        create_effect(move |_| {
+            if pointer_down_event.get().is_some() {
+                had_event_before_focus.set_value(true);
+                set_modality.set(Some(Modality::Pointer));
+            }
+        });
+
+        let _ = use_event_listener_with_options(
+            use_document(),
+            ev::focus,
+            move |e| {
+                // Focus events on the window or document itself are not of interest.
+                if e.target()
+                    .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+                    .is_none()
+                {
+                    return;
+                }
+                if !had_event_before_focus.get_value() {
+                    set_modality.set(Some(Modality::Virtual));
+                }
+                had_event_before_focus.set_value(false);
+            },
+            UseEventListenerOptions::default().capture(true),
+        );
+
+        // Switching tabs or windows focuses the previously focused element again. That focus is not virtual.
+        let _ = use_event_listener_with_options(
+            use_window(),
+            ev::blur,
+            move |_| had_event_before_focus.set_value(true),
+            UseEventListenerOptions::default(),
+        );
+
+        Self {
+            read_signal: modality,
+        }
+    }
+}
+
+/// Whether `e` should switch the modality to `Modality::Keyboard`.
+/// Keyboard shortcuts and modifier keys alone do not, neither does typing into a text field.
+fn is_valid_key(e: &KeyboardEvent) -> bool {
+    let key = e.key();
+    if e.meta_key()
+        || e.ctrl_key()
+        || e.alt_key()
+        || matches!(key.as_str(), "Control" | "Shift" | "Meta" | "Alt")
+    {
+        return false;
+    }
+    !is_text_input(e.target()) || matches!(key.as_str(), "Tab" | "Escape")
+}
+
+fn is_text_input(target: Option<web_sys::EventTarget>) -> bool {
+    let Some(el) = target.and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok()) else {
+        return false;
+    };
+    if el.is_content_editable() {
+        return true;
+    }
+    match el.tag_name().as_str() {
+        "TEXTAREA" => true,
+        "INPUT" => !matches!(
+            el.get_attribute("type").unwrap_or_default().as_str(),
+            "checkbox"
+                | "radio"
+                | "range"
+                | "color"
+                | "file"
+                | "image"
+                | "button"
+                | "submit"
+                | "reset"
+        ),
+        _ => false,
+    }
+}
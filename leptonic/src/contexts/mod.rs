@@ -2,6 +2,7 @@ use std::rc::Rc;
 
 pub mod global_click_event;
 pub mod global_keyboard_event;
+pub mod global_modality;
 pub mod global_mouseup_event;
 pub mod global_pointer_event;
 pub mod global_resize_event;
@@ -0,0 +1,155 @@
+use std::{future::Future, pin::Pin, rc::Rc};
+
+use educe::Educe;
+use leptos::spawn_local;
+use leptos_reactive::{
+    create_effect, create_rw_signal, store_value, Callback, Signal, SignalGet, SignalGetUntracked,
+    SignalSet, SignalUpdate, SignalWithUntracked,
+};
+use leptos_use::signal_debounced;
+
+// This is mostly based on work in: https://github.com/TanStack/query/blob/main/packages/query-core/src/infiniteQueryBehavior.ts
+
+/// Describes the page of options to load.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionsQuery {
+    /// What the user searched for. Only options matching this search should be returned.
+    pub search: String,
+
+    /// Number of matching options already loaded, which must be skipped.
+    pub offset: usize,
+
+    /// Maximum number of options to return.
+    pub limit: usize,
+}
+
+pub type OptionsFuture<O> = Pin<Box<dyn Future<Output = Vec<O>>>>;
+
+/// Loads options asynchronously, one page at a time, e.g. by querying a server.
+/// Returning fewer options than the `limit` of a query signals that no further options are available.
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct OptionsLoader<O>(#[educe(Debug(ignore))] Rc<dyn Fn(OptionsQuery) -> OptionsFuture<O>>);
+
+impl<O> Clone for OptionsLoader<O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<O: 'static> OptionsLoader<O> {
+    pub fn new<Fut: Future<Output = Vec<O>> + 'static>(
+        load: impl Fn(OptionsQuery) -> Fut + 'static,
+    ) -> Self {
+        Self(Rc::new(move |query| Box::pin(load(query))))
+    }
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseAsyncOptionsInput<O: 'static> {
+    pub loader: OptionsLoader<O>,
+
+    /// Options are loaded for the current search.
+    pub search: Signal<String>,
+
+    /// Options are only loaded while this is true, e.g. while the options of a select are shown.
+    pub enabled: Signal<bool>,
+
+    /// Milliseconds for which the search must not change before loading options for it.
+    pub debounce_ms: f64,
+
+    /// Number of options loaded at once.
+    pub page_size: usize,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseAsyncOptionsReturn<O: 'static> {
+    /// All options loaded for the current search.
+    pub options: Signal<Vec<O>>,
+
+    /// Whether a page of options is currently loading.
+    pub loading: Signal<bool>,
+
+    /// Whether further options may be available, which can be loaded with `load_more`.
+    pub has_more: Signal<bool>,
+
+    /// Loads the next page of options. Does nothing if options are already loading or no further options are available.
+    #[educe(Debug(ignore))]
+    pub load_more: Callback<()>,
+}
+
+impl<O> Clone for UseAsyncOptionsReturn<O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O> Copy for UseAsyncOptionsReturn<O> {}
+
+pub fn use_async_options<O: Clone + 'static>(
+    input: UseAsyncOptionsInput<O>,
+) -> UseAsyncOptionsReturn<O> {
+    let loader = store_value(input.loader);
+    let page_size = input.page_size;
+    let search = signal_debounced(input.search, input.debounce_ms);
+    let enabled = input.enabled;
+
+    let options = create_rw_signal(Vec::<O>::new());
+    let loading = create_rw_signal(false);
+    let has_more = create_rw_signal(true);
+
+    // The search for which options are currently loaded.
+    let loaded_search = store_value(Option::<String>::None);
+
+    // Pages loaded for a previous search are stale and must be ignored.
+    let generation = store_value(0_u64);
+
+    let load = move |search: String, offset: usize| {
+        let current = generation.get_value();
+        let query = OptionsQuery {
+            search,
+            offset,
+            limit: page_size,
+        };
+        let page = loader.with_value(|loader| (loader.0)(query));
+        loading.set(true);
+        spawn_local(async move {
+            let page = page.await;
+            if generation.get_value() == current {
+                has_more.set(page.len() >= page_size);
+                options.update(|options| options.extend(page));
+                loading.set(false);
+            }
+        });
+    };
+
+    create_effect(move |_| {
+        let search = search.get();
+        if !enabled.get() || loaded_search.with_value(|loaded| loaded.as_ref() == Some(&search)) {
+            return;
+        }
+        generation.update_value(|generation| *generation += 1);
+        loaded_search.set_value(Some(search.clone()));
+        options.set(Vec::new());
+        has_more.set(true);
+        load(search, 0);
+    });
+
+    let load_more = Callback::new(move |()| {
+        if loading.get_untracked() || !has_more.get_untracked() {
+            return;
+        }
+        if let Some(search) = loaded_search.get_value() {
+            load(search, options.with_untracked(Vec::len));
+        }
+    });
+
+    UseAsyncOptionsReturn {
+        options: options.into(),
+        loading: loading.into(),
+        has_more: has_more.into(),
+        load_more,
+    }
+}
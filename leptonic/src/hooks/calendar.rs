@@ -1,12 +1,29 @@
 use leptos::*;
-use time::macros::format_description;
 use uuid::Uuid;
 
-use crate::utils::time::{
-    is_in_range, start_of_next_month, start_of_previous_month, whole_days_in, Day, InMonth, Month,
-    SaveReplaceYear, Week, Year,
+use crate::{
+    prelude::Consumer,
+    utils::{
+        locale::CalendarLocale,
+        time::{
+            is_date_in_range, ordered_dates, start_of_next_month, start_of_previous_month,
+            whole_days_in, Day, InMonth, Month, SaveReplaceYear, Week, Year,
+        },
+    },
 };
 
+#[derive(Debug, Clone, Copy)]
+pub struct UseCalendarInput {
+    pub initial_value: time::OffsetDateTime,
+    pub min: Option<time::OffsetDateTime>,
+    pub max: Option<time::OffsetDateTime>,
+    /// Decides the first day of each week and the names of months and weekdays.
+    pub locale: MaybeSignal<CalendarLocale>,
+    /// Disables days in addition to those outside of `min` and `max`, e.g. weekends or fully booked days.
+    /// Signals read by the predicate are tracked.
+    pub is_date_disabled: Option<Consumer<time::Date, bool>>,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct UseCalendarReturn {
     pub staging: ReadSignal<time::OffsetDateTime>,
@@ -21,6 +38,22 @@ pub struct UseCalendarReturn {
     pub years: Signal<Vec<Year>>,
     pub months: Signal<Vec<Month>>,
     pub weeks: Signal<Vec<Week>>,
+
+    pub locale: Signal<CalendarLocale>,
+    /// Abbreviated names of the weekdays, in the order of the days of each week.
+    pub weekday_names: Signal<Vec<&'static str>>,
+
+    min: Option<time::OffsetDateTime>,
+    max: Option<time::OffsetDateTime>,
+    is_date_disabled: Option<Consumer<time::Date, bool>>,
+
+    range_start: RwSignal<Option<time::Date>>,
+    range_end: RwSignal<Option<time::Date>>,
+    range_preview: RwSignal<Option<time::Date>>,
+    /// The selected range of days, both ends included. `None` until both ends were selected.
+    pub range: Memo<Option<(time::Date, time::Date)>>,
+    /// The days highlighted in `weeks`: The selected range, or the range previewed while selecting one.
+    highlighted_range: Memo<Option<(time::Date, time::Date)>>,
 }
 
 impl UseCalendarReturn {
@@ -88,17 +121,136 @@ impl UseCalendarReturn {
     pub fn select_day(&self, day: Day) {
         self.set_staging.update(|staging| *staging = day.date_time);
     }
+
+    /// Moves the staging date to `date`, keeping its time. Shows the month of `date`.
+    pub fn stage_date(&self, date: time::Date) {
+        self.set_staging
+            .update(|staging| *staging = staging.replace_date(date));
+    }
+
+    /// Whether `date` lies between `min` and `max` and is not disabled by the `is_date_disabled` predicate.
+    pub fn is_date_selectable(&self, date: time::Date) -> bool {
+        is_date_in_range(date, self.min.as_ref(), self.max.as_ref())
+            && !self
+                .is_date_disabled
+                .is_some_and(|is_date_disabled| is_date_disabled.consume(date))
+    }
+
+    /// Whether all days from `start` to `end` are selectable.
+    pub fn is_range_selectable(&self, start: time::Date, end: time::Date) -> bool {
+        let (start, end) = ordered_dates(start, end);
+        let mut date = Some(start);
+        while let Some(day) = date.filter(|day| *day <= end) {
+            if !self.is_date_selectable(day) {
+                return false;
+            }
+            date = day.next_day();
+        }
+        true
+    }
+
+    /// Selects `day` as one end of a range.
+    ///
+    /// The first call selects the start of the range, the second call its end. Selecting an end before the start
+    /// swaps both ends. Further calls start a new range. Ranges never contain days which are not selectable:
+    /// Selecting an end beyond such a day starts a new range instead.
+    pub fn select_range_day(&self, day: Day) {
+        let date = day.date_time.date();
+        match (
+            self.range_start.get_untracked(),
+            self.range_end.get_untracked(),
+        ) {
+            (Some(start), None) if self.is_range_selectable(start, date) => {
+                let (start, end) = ordered_dates(start, date);
+                self.range_start.set(Some(start));
+                self.range_end.set(Some(end));
+            }
+            _ => {
+                self.range_start.set(Some(date));
+                self.range_end.set(None);
+            }
+        }
+        self.range_preview.set(None);
+    }
+
+    /// Highlights the range that would be selected if `day` became its end.
+    /// Only has an effect while the start of a range is selected but its end is not.
+    pub fn preview_range_day(&self, day: Day) {
+        let date = Some(day.date_time.date());
+        if self.range_preview.get_untracked() != date {
+            self.range_preview.set(date);
+        }
+    }
+
+    pub fn end_range_preview(&self) {
+        if self.range_preview.get_untracked().is_some() {
+            self.range_preview.set(None);
+        }
+    }
+
+    /// Replaces the selected range. Ends are swapped if necessary.
+    pub fn set_range(&self, range: Option<(time::Date, time::Date)>) {
+        let range = range.map(|(start, end)| ordered_dates(start, end));
+        self.range_start.set(range.map(|(start, _)| start));
+        self.range_end.set(range.map(|(_, end)| end));
+        self.range_preview.set(None);
+    }
+
+    /// Whether only the start of a range was selected so far. Tracked.
+    pub fn is_selecting_range(&self) -> bool {
+        self.range_start.get().is_some() && self.range_end.get().is_none()
+    }
+
+    /// A date in the month lying `offset` months after the staging month: The staging date itself for an `offset` of 0,
+    /// the first day of that month otherwise. Lets a calendar show multiple months side by side.
+    pub fn staging_after(&self, offset: u8) -> Signal<time::OffsetDateTime> {
+        let staging = self.staging;
+        Signal::derive(move || {
+            (0..offset).fold(staging.get(), |month, _| start_of_next_month(month))
+        })
+    }
+
+    /// The weeks of the month lying `offset` months after the staging month.
+    pub fn weeks_after(&self, offset: u8) -> Signal<Vec<Week>> {
+        let month = self.staging_after(offset);
+        let (min, max, locale, highlighted_range, is_date_disabled) = (
+            self.min,
+            self.max,
+            self.locale,
+            self.highlighted_range,
+            self.is_date_disabled,
+        );
+        Signal::derive(move || {
+            create_weeks(
+                &month.get(),
+                min.as_ref(),
+                max.as_ref(),
+                highlighted_range.get(),
+                locale.with(|locale| locale.first_weekday),
+                is_date_disabled,
+            )
+        })
+    }
 }
 
-pub fn use_calendar(
-    initial_value: time::OffsetDateTime,
-    min: Option<time::OffsetDateTime>,
-    max: Option<time::OffsetDateTime>,
-) -> UseCalendarReturn {
+pub fn use_calendar(input: UseCalendarInput) -> UseCalendarReturn {
+    let UseCalendarInput {
+        initial_value,
+        min,
+        max,
+        locale,
+        is_date_disabled,
+    } = input;
+    let locale = Signal::derive(move || locale.get());
+    let weekday_names =
+        Signal::derive(move || locale.with(CalendarLocale::ordered_short_weekday_names));
+
     let (staging, set_staging) = create_signal(initial_value);
 
     let staging_year = create_memo(move |_| staging.get().year());
-    let staging_month_name = create_memo(move |_| staging.get().month().to_string());
+    let staging_month_name = create_memo(move |_| {
+        locale.with(|locale| locale.month_name(staging.get().month()).to_owned())
+    });
 
     let selected: Memo<time::OffsetDateTime> = create_memo(move |_| staging.get());
 
@@ -117,9 +269,35 @@ pub fn use_calendar(
         })
     });
     let months = Signal::derive(move || {
-        staging.with(|staging| create_months(*staging, min.as_ref(), max.as_ref()))
+        locale.with(|locale| {
+            staging.with(|staging| create_months(*staging, min.as_ref(), max.as_ref(), locale))
+        })
+    });
+
+    let range_start = create_rw_signal(None);
+    let range_end = create_rw_signal(None);
+    let range_preview = create_rw_signal(None);
+    let range = create_memo(move |_| range_start.get().zip(range_end.get()));
+    let highlighted_range =
+        create_memo(
+            move |_| match (range_start.get(), range_end.get(), range_preview.get()) {
+                (Some(start), Some(end), _) => Some((start, end)),
+                (Some(start), None, Some(preview)) => Some(ordered_dates(start, preview)),
+                (Some(start), None, None) => Some((start, start)),
+                (None, _, _) => None,
+            },
+        );
+
+    let weeks = Signal::derive(move || {
+        create_weeks(
+            &staging.get(),
+            min.as_ref(),
+            max.as_ref(),
+            highlighted_range.get(),
+            locale.with(|locale| locale.first_weekday),
+            is_date_disabled,
+        )
     });
-    let weeks = Signal::derive(move || create_weeks(&staging.get(), min.as_ref(), max.as_ref()));
 
     UseCalendarReturn {
         staging,
@@ -132,6 +310,16 @@ pub fn use_calendar(
         years,
         months,
         weeks,
+        locale,
+        weekday_names,
+        min,
+        max,
+        is_date_disabled,
+        range_start,
+        range_end,
+        range_preview,
+        range,
+        highlighted_range,
     }
 }
 
@@ -165,6 +353,7 @@ pub fn create_months(
     staging: time::OffsetDateTime,
     min: Option<&time::OffsetDateTime>,
     max: Option<&time::OffsetDateTime>,
+    locale: &CalendarLocale,
 ) -> Vec<Month> {
     let now = time::OffsetDateTime::now_utc();
     let this_year = now.year();
@@ -178,12 +367,17 @@ pub fn create_months(
             .unwrap();
         let month_year = month.year();
         let month_month = month.month();
+        let first_day = month.date().replace_day(1).expect("always safe");
+        let last_day = first_day
+            .replace_day(whole_days_in(month_year, month_month))
+            .expect("always safe");
         months.push(Month {
             index: i,
-            name: month.format(format_description!("[month]")).unwrap(), // TODO: format_description does not work!
+            name: locale.month_name(month_month).to_owned(),
             is_staging: staging_year == month_year && staging_month == month_month,
             is_now: this_year == month_year && this_month == month_month,
-            disabled: !is_in_range(&month, max, min),
+            disabled: !is_date_in_range(last_day, min, None)
+                || !is_date_in_range(first_day, None, max),
         });
     }
     assert_eq!(months.len(), 12);
@@ -194,6 +388,9 @@ pub fn create_weeks(
     staging: &time::OffsetDateTime,
     min: Option<&time::OffsetDateTime>,
     max: Option<&time::OffsetDateTime>,
+    highlighted_range: Option<(time::Date, time::Date)>,
+    first_weekday: time::Weekday,
+    is_date_disabled: Option<Consumer<time::Date, bool>>,
 ) -> Vec<Week> {
     const WEEKS_TO_DISPLAY: u8 = 6;
     const DAYS_PER_WEEK: u8 = 7;
@@ -206,11 +403,15 @@ pub fn create_weeks(
     let current_day = now.day();
     let staging_day = staging.day();
 
-    let first_weekday_index = (*staging)
-        .replace_day(1)
-        .unwrap()
-        .weekday()
-        .number_days_from_monday(); // in range [0..6]
+    // in range [0..6]
+    let first_weekday_index = (7
+        + (*staging)
+            .replace_day(1)
+            .unwrap()
+            .weekday()
+            .number_days_from_monday()
+        - first_weekday.number_days_from_monday())
+        % 7;
     let number_of_days_in_month = whole_days_in(staging.year(), staging.month());
     let index_of_last_day_in_month = first_weekday_index + number_of_days_in_month;
 
@@ -224,6 +425,7 @@ pub fn create_weeks(
     for w in 0..WEEKS_TO_DISPLAY {
         let mut week = Week {
             id: Uuid::new_v4(),
+            number: 0,
             days: Vec::with_capacity(DAYS_PER_WEEK as usize),
         };
         for d in 0..DAYS_PER_WEEK {
@@ -249,16 +451,25 @@ pub fn create_weeks(
                 InMonth::Next => &next_month,
             };
             let date_time: time::OffsetDateTime = relevant_month.replace_day(day_in_month).unwrap();
-            let disabled = !is_in_range(&date_time, max, min); // TODO: inversion of min/max correct?
+            let date = date_time.date();
+            let disabled = !is_date_in_range(date, min, max)
+                || is_date_disabled.is_some_and(|is_date_disabled| is_date_disabled.consume(date));
             let selected = in_month == InMonth::Current && day_in_month == staging_day; // TODO: Can a day form prev not be selected?
 
+            if date.weekday() == time::Weekday::Thursday {
+                week.number = date.iso_week();
+            }
+
             week.days.push(Day {
                 id: Uuid::new_v4(),
                 index: day_in_month,
                 in_month,
                 date_time,
                 disabled,
-                highlighted: false,
+                highlighted: highlighted_range
+                    .is_some_and(|(start, end)| start <= date && date <= end),
+                is_range_start: highlighted_range.is_some_and(|(start, _)| start == date),
+                is_range_end: highlighted_range.is_some_and(|(_, end)| end == date),
                 is_staging: selected,
                 // TODO: is year check necessary?
                 is_now: current_month == relevant_month.month()
@@ -0,0 +1,2 @@
+pub mod use_combobox;
+pub mod use_combobox_option;
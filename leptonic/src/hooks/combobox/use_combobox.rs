@@ -0,0 +1,559 @@
+use std::{fmt::Debug, rc::Rc};
+
+use educe::Educe;
+use leptos::{html, html::ElementDescriptor, Attribute, NodeRef, Oco};
+use leptos_reactive::{
+    create_memo, create_rw_signal, store_value, untrack, Callable, MaybeSignal, Memo, RwSignal,
+    SignalGet, SignalGetUntracked, SignalSet, SignalWith, SignalWithUntracked, StoredValue,
+};
+use web_sys::{Event, FocusEvent, KeyboardEvent, MouseEvent};
+
+use crate::{
+    hooks::{
+        overlay::use_overlay_position::{
+            use_overlay_position, OverlayBoundary, PlacementX, PlacementY, UseOverlayPositionInput,
+            OVERLAY_PADDING,
+        },
+        use_async_options, OptionsLoader, SearchFilterProvider, UseAsyncOptionsInput,
+        UseAsyncOptionsReturn,
+    },
+    prelude::Consumer,
+    utils::{locale::WritingDirection, options::default_search_filter, props::Attributes},
+    Out,
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/combobox/src/useComboBox.ts
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseComboboxInput<O, ListboxRef>
+where
+    O: 'static,
+    ListboxRef: ElementDescriptor + 'static,
+{
+    /// The text input. Suggestions are positioned relative to it.
+    #[educe(Debug(ignore))]
+    pub input_ref: NodeRef<html::Input>,
+
+    /// The element containing the suggestions.
+    #[educe(Debug(ignore))]
+    pub listbox_ref: NodeRef<ListboxRef>,
+
+    /// The current text of the input.
+    pub text: MaybeSignal<String>,
+
+    /// Receives every change of the text, whether typed, completed or taken from a selected suggestion.
+    #[educe(Debug(ignore))]
+    pub set_text: Out<String>,
+
+    /// Whether the first suggestion starting with the typed text should be completed inline.
+    pub inline_completion: bool,
+
+    pub writing_direction: MaybeSignal<WritingDirection>,
+
+    /// All suggestions. Ignored when a `loader` is given.
+    #[educe(Debug(ignore))]
+    pub options: MaybeSignal<Vec<O>>,
+
+    /// Loads the suggestions matching the typed text asynchronously while the suggestions are shown.
+    pub loader: Option<OptionsLoader<O>>,
+
+    /// Milliseconds the typed text must stay unchanged before suggestions are loaded through the `loader`.
+    pub search_debounce: f64,
+
+    /// Number of suggestions loaded through the `loader` at once.
+    pub page_size: usize,
+
+    /// Text of a suggestion, used to filter suggestions by the typed text and as the text of the input once selected.
+    #[educe(Debug(ignore))]
+    pub search_text_provider: Consumer<O, String>,
+
+    /// Filters the suggestions by the typed text. Defaults to a case-insensitive substring search on the text of each suggestion.
+    #[educe(Debug(ignore))]
+    pub search_filter_provider: Option<SearchFilterProvider<O>>,
+
+    /// Called with the suggestion the user selected.
+    #[educe(Debug(ignore))]
+    pub on_select: Option<Out<O>>,
+}
+
+/// State of a combobox. Use it to render the suggestions, and pass it to `use_combobox_option` for every suggestion.
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct ComboboxState<O: 'static> {
+    id: StoredValue<Oco<'static, str>>,
+    show_suggestions: RwSignal<bool>,
+    #[educe(Debug(ignore))]
+    query: RwSignal<String>,
+    #[educe(Debug(ignore))]
+    options: Memo<Vec<O>>,
+    #[educe(Debug(ignore))]
+    preselected: RwSignal<Option<O>>,
+    #[educe(Debug(ignore))]
+    memoized_preselected: Memo<Option<O>>,
+    async_options: Option<UseAsyncOptionsReturn<O>>,
+    #[educe(Debug(ignore))]
+    input_ref: NodeRef<html::Input>,
+    #[educe(Debug(ignore))]
+    set_text: Out<String>,
+    #[educe(Debug(ignore))]
+    search_text_provider: Consumer<O, String>,
+    #[educe(Debug(ignore))]
+    on_select: Option<Out<O>>,
+    inline_completion: bool,
+}
+
+impl<O: 'static> Clone for ComboboxState<O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O: 'static> Copy for ComboboxState<O> {}
+
+impl<O: Debug + Clone + PartialEq + 'static> ComboboxState<O> {
+    /// Id of the input receiving the `props` of `use_combobox`.
+    pub fn id(&self) -> Oco<'static, str> {
+        self.id.get_value()
+    }
+
+    /// Id of the element receiving the `listbox_props` of `use_combobox`.
+    pub fn listbox_id(&self) -> String {
+        format!("{}-listbox", self.id.get_value())
+    }
+
+    /// Id of the preselected suggestion, referenced by `aria-activedescendant`.
+    pub(super) fn active_option_id(&self) -> String {
+        format!("{}-active", self.id.get_value())
+    }
+
+    /// Whether the suggestions are shown. Tracked.
+    pub fn is_open(&self) -> bool {
+        self.show_suggestions.get()
+    }
+
+    pub fn open(&self) {
+        self.show_suggestions.set(true);
+    }
+
+    pub fn close(&self) {
+        self.show_suggestions.set(false);
+    }
+
+    /// The text typed by the user, excluding any inline completion. Suggestions are filtered by it.
+    pub const fn query(&self) -> RwSignal<String> {
+        self.query
+    }
+
+    /// Suggestions matching the typed text, in the order they are shown.
+    pub const fn options(&self) -> Memo<Vec<O>> {
+        self.options
+    }
+
+    /// Whether any suggestion matches the typed text. Tracked.
+    pub fn has_options(&self) -> bool {
+        !self.options.with(Vec::is_empty)
+    }
+
+    /// Whether suggestions are currently loaded through the `loader`. Tracked.
+    pub fn is_loading(&self) -> bool {
+        self.async_options
+            .is_some_and(|async_options| async_options.loading.get())
+    }
+
+    /// Loads the next page of suggestions through the `loader`, if any.
+    pub fn load_more(&self) {
+        if let Some(async_options) = self.async_options {
+            Callable::call(&async_options.load_more, ());
+        }
+    }
+
+    /// The suggestion highlighted for selection with the keyboard.
+    pub fn preselected(&self) -> leptos_reactive::Signal<Option<O>> {
+        self.memoized_preselected.into()
+    }
+
+    /// Whether the given suggestion is highlighted for selection with the keyboard. Tracked.
+    pub fn is_preselected(&self, option: &O) -> bool {
+        self.memoized_preselected
+            .with(|preselected| preselected.as_ref() == Some(option))
+    }
+
+    pub fn preselect(&self, option: O) {
+        self.preselected.set(Some(option));
+    }
+
+    /// The preselected suggestion, if it matches the typed text and is therefore shown.
+    fn shown_preselected(&self) -> Option<O> {
+        untrack(|| {
+            self.preselected
+                .get()
+                .filter(|preselected| self.options.with(|options| options.contains(preselected)))
+        })
+    }
+
+    /// Whether the preselected suggestion matches the typed text and is therefore shown. Tracked.
+    pub fn preselected_is_shown(&self) -> bool {
+        self.memoized_preselected.with(|preselected| {
+            preselected.as_ref().is_some_and(|preselected| {
+                self.options.with(|options| options.contains(preselected))
+            })
+        })
+    }
+
+    /// Preselects the suggestion at the index chosen by `pick`, given the number of suggestions and the index of the current preselection.
+    fn preselect_by(&self, pick: impl Fn(usize, Option<usize>) -> Option<usize>) {
+        let options = self.options.get_untracked();
+        let current = self.preselected.with_untracked(|preselected| {
+            preselected
+                .as_ref()
+                .and_then(|preselected| options.iter().position(|it| it == preselected))
+        });
+        if let Some(option) = pick(options.len(), current).and_then(|i| options.get(i)) {
+            self.preselected.set(Some(option.clone()));
+        }
+    }
+
+    /// Preselects the next suggestion, wrapping around at the end.
+    pub fn preselect_next(&self) {
+        self.preselect_by(|len, current| match current {
+            Some(current) if current + 1 < len => Some(current + 1),
+            Some(_) | None => (len > 0).then_some(0),
+        });
+    }
+
+    /// Preselects the previous suggestion, wrapping around at the start.
+    pub fn preselect_previous(&self) {
+        self.preselect_by(|len, current| match current {
+            Some(current) if current > 0 => Some(current - 1),
+            Some(_) | None => len.checked_sub(1),
+        });
+    }
+
+    /// Takes the text of the given suggestion into the input and emits the suggestion through `on_select`.
+    pub fn select(&self, option: O) {
+        let text = self.search_text_provider.consume(option.clone());
+        self.query.set(text.clone());
+        self.set_text.set(text);
+        self.preselected.set(None);
+        self.close();
+        if let Some(on_select) = self.on_select {
+            on_select.set(option);
+        }
+    }
+
+    /// Text currently shown in the input, including an inline completion.
+    fn input_value(&self) -> Option<String> {
+        self.input_ref.get_untracked().map(|input| input.value())
+    }
+
+    /// Whether the input currently shows an inline completion of the typed text.
+    fn is_completed(&self) -> bool {
+        self.inline_completion
+            && self
+                .input_value()
+                .is_some_and(|value| self.query.with_untracked(|query| value != *query))
+    }
+
+    /// Handles text typed by the user. Opens the suggestions and, if enabled and the text grew, completes it inline.
+    fn on_typed(&self, value: &str) {
+        let grew = self
+            .query
+            .with_untracked(|query| value.len() > query.len() && value.starts_with(query.as_str()));
+        self.query.set(value.to_owned());
+        self.set_text.set(value.to_owned());
+        self.preselected.set(None);
+        self.open();
+
+        if !self.inline_completion || !grew {
+            return;
+        }
+        let search_text_provider = self.search_text_provider;
+        let completion = self.options.with_untracked(|options| {
+            options.iter().find_map(|option| {
+                complete(value, &search_text_provider.consume(option.clone()))
+                    .map(|completed| (option.clone(), completed))
+            })
+        });
+        if let Some((option, completed)) = completion {
+            self.preselected.set(Some(option));
+            self.set_text.set(completed.clone());
+            if let Some(input) = self.input_ref.get_untracked() {
+                input.set_value(&completed);
+                let start = utf16_len(value);
+                let end = utf16_len(&completed);
+                if let Err(err) = input.set_selection_range(start, end) {
+                    tracing::warn!(?err, "Could not select inline completion.");
+                }
+            }
+        }
+    }
+
+    /// Removes an inline completion, restoring the text typed by the user.
+    fn revert_completion(&self) {
+        if self.is_completed() {
+            self.set_text.set(self.query.get_untracked());
+            if let Some(input) = self.input_ref.get_untracked() {
+                input.set_value(&self.query.get_untracked());
+            }
+        }
+    }
+}
+
+/// Number of UTF-16 code units in `text`, the unit in which selection ranges of inputs are measured.
+fn utf16_len(text: &str) -> u32 {
+    u32::try_from(text.encode_utf16().count()).unwrap_or(u32::MAX)
+}
+
+/// Completes `typed` to `suggestion` if the suggestion starts with the typed text, ignoring case.
+/// The typed characters are kept as they are, only the remainder of the suggestion is appended.
+fn complete(typed: &str, suggestion: &str) -> Option<String> {
+    if typed.is_empty() {
+        return None;
+    }
+    let mut rest = suggestion.char_indices();
+    for typed_char in typed.chars() {
+        let (_, suggested_char) = rest.next()?;
+        if !typed_char.to_lowercase().eq(suggested_char.to_lowercase()) {
+            return None;
+        }
+    }
+    let remainder = rest.next().map(|(i, _)| &suggestion[i..])?;
+    Some(format!("{typed}{remainder}"))
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseComboboxProps {
+    /// These attributes must be spread onto the input: `<input {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the input: `<input on:input=on_input />`
+    #[educe(Debug(ignore))]
+    pub on_input: Box<dyn Fn(Event)>,
+
+    /// This handler must be attached to the input: `<input on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+
+    /// This handler must be attached to the input: `<input on:blur=on_blur />`
+    #[educe(Debug(ignore))]
+    pub on_blur: Box<dyn Fn(FocusEvent)>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseComboboxListboxProps {
+    /// These attributes must be spread onto the element containing the suggestions: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the same element: `<foo on:mousedown=on_mouse_down />`
+    /// It keeps the focus on the input while a suggestion is clicked.
+    #[educe(Debug(ignore))]
+    pub on_mouse_down: Box<dyn Fn(MouseEvent)>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseComboboxReturn<O: 'static> {
+    pub props: UseComboboxProps,
+
+    pub listbox_props: UseComboboxListboxProps,
+
+    /// Pass this to `use_combobox_option` for every suggestion.
+    pub state: ComboboxState<O>,
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn use_combobox<O, ListboxRef>(input: UseComboboxInput<O, ListboxRef>) -> UseComboboxReturn<O>
+where
+    O: Debug + Clone + PartialEq + 'static,
+    ListboxRef: ElementDescriptor + Clone + 'static,
+{
+    let id: Oco<'static, str> = Oco::Owned(format!("cb-{}", uuid::Uuid::new_v4()));
+
+    let show_suggestions = create_rw_signal(false);
+    let text = input.text;
+    let query = create_rw_signal(text.get_untracked());
+    let preselected = create_rw_signal(Option::<O>::None);
+    let memoized_preselected = create_memo(move |_| preselected.get());
+
+    let search_text_provider = input.search_text_provider;
+    let search_filter_provider = input
+        .search_filter_provider
+        .unwrap_or_else(|| default_search_filter(search_text_provider));
+
+    let async_options = input.loader.map(|loader| {
+        use_async_options(UseAsyncOptionsInput {
+            loader,
+            search: query.into(),
+            enabled: show_suggestions.into(),
+            debounce_ms: input.search_debounce,
+            page_size: input.page_size,
+        })
+    });
+
+    let stored_options = store_value(input.options);
+    let options = create_memo(move |_| match async_options {
+        Some(async_options) => async_options.options.get(),
+        None => search_filter_provider.consume((query.get(), stored_options.get_value().get())),
+    });
+
+    let state = ComboboxState {
+        id: store_value(id.clone()),
+        show_suggestions,
+        query,
+        options,
+        preselected,
+        memoized_preselected,
+        async_options,
+        input_ref: input.input_ref,
+        set_text: input.set_text,
+        search_text_provider,
+        on_select: input.on_select,
+        inline_completion: input.inline_completion,
+    };
+
+    let on_input = Box::new(move |e: Event| {
+        state.on_typed(&leptos::event_target_value(&e));
+    });
+
+    let on_key_down = Box::new(move |e: KeyboardEvent| {
+        match (show_suggestions.get_untracked(), e.key().as_str()) {
+            (true, "Escape") => {
+                e.prevent_default();
+                e.stop_propagation();
+                state.revert_completion();
+                state.close();
+            }
+            (true, "ArrowDown") => {
+                e.prevent_default();
+                state.preselect_next();
+            }
+            (true, "ArrowUp") => {
+                e.prevent_default();
+                state.preselect_previous();
+            }
+            (false, "ArrowDown") => {
+                e.prevent_default();
+                query.set(text.get_untracked());
+                state.open();
+                state.preselect_next();
+            }
+            (false, "ArrowUp") => {
+                e.prevent_default();
+                query.set(text.get_untracked());
+                state.open();
+                state.preselect_previous();
+            }
+            // Without a shown preselection, Enter keeps the free text and may submit a surrounding form.
+            (true, "Enter") => match state.shown_preselected() {
+                Some(preselected) => {
+                    e.prevent_default();
+                    state.select(preselected);
+                }
+                None => state.close(),
+            },
+            // Tab accepts an inline completion while moving focus on as usual.
+            (true, "Tab") => match state.shown_preselected().filter(|_| state.is_completed()) {
+                Some(preselected) => state.select(preselected),
+                None => state.close(),
+            },
+            _ => {}
+        }
+    });
+
+    let on_blur = Box::new(move |_e: FocusEvent| state.close());
+
+    let overlay_position = use_overlay_position(UseOverlayPositionInput {
+        overlay_ref: input.listbox_ref,
+        target_ref: input.input_ref,
+        placement_x: PlacementX::Start.into(),
+        placement_y: PlacementY::Below.into(),
+        writing_direction: input.writing_direction,
+        flip: true,
+        shift: true,
+        offset: 0.0.into(),
+        padding: OVERLAY_PADDING.into(),
+        boundary: OverlayBoundary::Viewport,
+        arrow_ref: None,
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert("id", Attribute::String(id));
+    attrs.insert("role", Attribute::String(Oco::Borrowed("combobox")));
+    attrs.insert("autocomplete", Attribute::String(Oco::Borrowed("off")));
+    attrs.insert(
+        "aria-autocomplete",
+        Attribute::String(Oco::Borrowed(match input.inline_completion {
+            true => "both",
+            false => "list",
+        })),
+    );
+    attrs.insert(
+        "aria-controls",
+        Attribute::String(Oco::Owned(state.listbox_id())),
+    );
+    attrs.insert(
+        "aria-expanded",
+        Attribute::Fn(Rc::new(move || match show_suggestions.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::String(Oco::Borrowed("false")),
+        })),
+    );
+    attrs.insert(
+        "aria-activedescendant",
+        Attribute::Fn(Rc::new(move || {
+            match show_suggestions.get() && state.preselected_is_shown() {
+                true => Attribute::String(Oco::Owned(state.active_option_id())),
+                false => Attribute::Option(None),
+            }
+        })),
+    );
+
+    let mut listbox_attrs = Attributes::new();
+    listbox_attrs.insert("id", Attribute::String(Oco::Owned(state.listbox_id())));
+    listbox_attrs.insert("role", Attribute::String(Oco::Borrowed("listbox")));
+    listbox_attrs.insert(
+        "aria-busy",
+        Attribute::Fn(Rc::new(move || match state.is_loading() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::Option(None),
+        })),
+    );
+    listbox_attrs.merge(overlay_position.props.attrs);
+
+    UseComboboxReturn {
+        props: UseComboboxProps {
+            attrs,
+            on_input,
+            on_key_down,
+            on_blur,
+        },
+        listbox_props: UseComboboxListboxProps {
+            attrs: listbox_attrs,
+            on_mouse_down: Box::new(|e: MouseEvent| e.prevent_default()),
+        },
+        state,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::complete;
+
+    #[test]
+    fn completes_suggestions_starting_with_the_typed_text() {
+        assert_eq!(complete("ber", "Berlin"), Some(String::from("berlin")));
+        assert_eq!(complete("Ber", "Berlin"), Some(String::from("Berlin")));
+        assert_eq!(complete("Zür", "Zürich"), Some(String::from("Zürich")));
+    }
+
+    #[test]
+    fn does_not_complete_other_suggestions() {
+        assert_eq!(complete("lin", "Berlin"), None);
+        assert_eq!(complete("Berlin", "Berlin"), None);
+        assert_eq!(complete("Berlinale", "Berlin"), None);
+        assert_eq!(complete("", "Berlin"), None);
+    }
+}
@@ -0,0 +1,81 @@
+use std::{fmt::Debug, rc::Rc};
+
+use educe::Educe;
+use leptos::{Attribute, Oco};
+use leptos_reactive::store_value;
+use web_sys::MouseEvent;
+
+use crate::utils::props::Attributes;
+
+use super::use_combobox::ComboboxState;
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/listbox/src/useOption.ts
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseComboboxOptionInput<O: 'static> {
+    /// The state of the combobox this suggestion belongs to, as returned by `use_combobox`.
+    pub combobox: ComboboxState<O>,
+
+    #[educe(Debug(ignore))]
+    pub option: O,
+}
+
+#[derive(Debug)]
+pub struct UseComboboxOptionReturn {
+    pub props: UseComboboxOptionProps,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseComboboxOptionProps {
+    /// These attributes must be spread onto the suggestion element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the suggestion element: `<foo on:click=on_click />`
+    #[educe(Debug(ignore))]
+    pub on_click: Box<dyn Fn(MouseEvent)>,
+
+    /// This handler must be attached to the suggestion element: `<foo on:mouseenter=on_mouse_enter />`
+    #[educe(Debug(ignore))]
+    pub on_mouse_enter: Box<dyn Fn(MouseEvent)>,
+}
+
+pub fn use_combobox_option<O>(input: UseComboboxOptionInput<O>) -> UseComboboxOptionReturn
+where
+    O: Debug + Clone + PartialEq + 'static,
+{
+    let combobox = input.combobox;
+    let option = store_value(input.option);
+
+    let mut attrs = Attributes::new();
+    attrs.insert("role", Attribute::String(Oco::Borrowed("option")));
+    // Only the preselected suggestion needs an id, as it is referenced through `aria-activedescendant`.
+    attrs.insert(
+        "id",
+        Attribute::Fn(Rc::new(move || {
+            match option.with_value(|option| combobox.is_preselected(option)) {
+                true => Attribute::String(Oco::Owned(combobox.active_option_id())),
+                false => Attribute::Option(None),
+            }
+        })),
+    );
+    // The focus stays on the input, so the preselected suggestion is the one marked as selected.
+    attrs.insert(
+        "aria-selected",
+        Attribute::Fn(Rc::new(move || {
+            match option.with_value(|option| combobox.is_preselected(option)) {
+                true => Attribute::String(Oco::Borrowed("true")),
+                false => Attribute::String(Oco::Borrowed("false")),
+            }
+        })),
+    );
+
+    UseComboboxOptionReturn {
+        props: UseComboboxOptionProps {
+            attrs,
+            on_click: Box::new(move |_e| combobox.select(option.get_value())),
+            on_mouse_enter: Box::new(move |_e| combobox.preselect(option.get_value())),
+        },
+    }
+}
@@ -1 +1,6 @@
 pub mod use_focus;
+pub mod use_focus_manager;
+pub mod use_focus_ring;
+pub mod use_focus_scope;
+pub mod use_focus_visible;
+pub mod use_focus_within;
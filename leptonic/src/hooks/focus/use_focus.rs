@@ -58,7 +58,7 @@ pub fn use_focus(input: UseFocusInput) -> UseFocusReturn {
             }
 
             if let Some(on_focus_change) = input.on_focus_change {
-                Callable::call(&on_focus_change, true);
+                Callable::call(&on_focus_change, false);
             }
         }
     });
@@ -0,0 +1,88 @@
+use leptos::use_context;
+use leptos_reactive::{Signal, SignalGetUntracked};
+
+use crate::utils::focus::{active_element, focus, tabbable_elements};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/focus/src/FocusScope.tsx
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FocusManagerOptions {
+    /// Whether to continue at the other end of the scope when its first or last element is reached.
+    pub wrap: bool,
+}
+
+/// Moves focus between the tabbable elements of a focus scope.
+/// All functions return the element that received focus, if any.
+#[derive(Debug, Clone, Copy)]
+pub struct FocusManager {
+    scope: Signal<Option<web_sys::Element>>,
+}
+
+impl FocusManager {
+    /// Creates a focus manager for the tabbable elements inside of `scope`.
+    pub const fn new(scope: Signal<Option<web_sys::Element>>) -> Self {
+        Self { scope }
+    }
+
+    fn tabbable_elements(self) -> Vec<web_sys::HtmlElement> {
+        self.scope
+            .get_untracked()
+            .map(|scope| tabbable_elements(&scope))
+            .unwrap_or_default()
+    }
+
+    /// Index of the currently focused element inside of `elements`.
+    fn active_index(elements: &[web_sys::HtmlElement]) -> Option<usize> {
+        let active = active_element()?;
+        elements.iter().position(|el| {
+            let el: &web_sys::Element = el.as_ref();
+            *el == active
+        })
+    }
+
+    fn focus_at(elements: &[web_sys::HtmlElement], idx: usize) -> Option<web_sys::HtmlElement> {
+        let el = elements.get(idx)?;
+        focus(el);
+        Some(el.clone())
+    }
+
+    /// Moves focus to the first tabbable element in the scope.
+    pub fn focus_first(self) -> Option<web_sys::HtmlElement> {
+        Self::focus_at(&self.tabbable_elements(), 0)
+    }
+
+    /// Moves focus to the last tabbable element in the scope.
+    pub fn focus_last(self) -> Option<web_sys::HtmlElement> {
+        let elements = self.tabbable_elements();
+        Self::focus_at(&elements, elements.len().checked_sub(1)?)
+    }
+
+    /// Moves focus to the tabbable element following the currently focused one.
+    /// Focuses the first element if focus is currently not inside of the scope.
+    pub fn focus_next(self, options: FocusManagerOptions) -> Option<web_sys::HtmlElement> {
+        let elements = self.tabbable_elements();
+        let next = match Self::active_index(&elements) {
+            Some(idx) if idx + 1 < elements.len() => idx + 1,
+            Some(_) if !options.wrap => return None,
+            _ => 0,
+        };
+        Self::focus_at(&elements, next)
+    }
+
+    /// Moves focus to the tabbable element preceding the currently focused one.
+    /// Focuses the last element if focus is currently not inside of the scope.
+    pub fn focus_previous(self, options: FocusManagerOptions) -> Option<web_sys::HtmlElement> {
+        let elements = self.tabbable_elements();
+        let previous = match Self::active_index(&elements) {
+            Some(idx) if idx > 0 => idx - 1,
+            Some(_) if !options.wrap => return None,
+            _ => elements.len().checked_sub(1)?,
+        };
+        Self::focus_at(&elements, previous)
+    }
+}
+
+/// The focus manager of the nearest enclosing `FocusScope`.
+pub fn use_focus_manager() -> Option<FocusManager> {
+    use_context::<FocusManager>()
+}
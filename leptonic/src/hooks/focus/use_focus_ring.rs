@@ -0,0 +1,115 @@
+use std::rc::Rc;
+
+use educe::Educe;
+use leptos::{ev::FocusEvent, Attribute, Callback, MaybeSignal};
+use leptos_reactive::{create_effect, create_signal, Signal, SignalGet, SignalSet};
+
+use crate::{
+    hooks::focus::{
+        use_focus::{use_focus, UseFocusInput},
+        use_focus_visible::use_focus_visible,
+        use_focus_within::{use_focus_within, UseFocusWithinInput},
+    },
+    utils::props::Attributes,
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/focus/src/useFocusRing.ts
+
+#[derive(Debug, Clone, Copy)]
+pub struct UseFocusRingInput {
+    /// Disables the focus ring when true.
+    pub disabled: MaybeSignal<bool>,
+
+    /// Whether to show the focus ring when something inside the element (not only the element itself) has focus.
+    pub within: bool,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseFocusRingProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    /// They contain `data-focused` and `data-focus-visible`, which can be used for styling.
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the target element: `<foo on:focusin=on_focus_in />`
+    /// Unless `within` is set, focus events of descendants are ignored and only the element itself is tracked.
+    #[educe(Debug(ignore))]
+    pub on_focus_in: Box<dyn Fn(FocusEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:focusout=on_focus_out />`
+    /// Unless `within` is set, focus events of descendants are ignored and only the element itself is tracked.
+    #[educe(Debug(ignore))]
+    pub on_focus_out: Box<dyn Fn(FocusEvent)>,
+}
+
+#[derive(Debug)]
+pub struct UseFocusRingReturn {
+    pub props: UseFocusRingProps,
+
+    /// Whether the element (or something inside it, when `within` is set) is currently focused.
+    pub is_focused: Signal<bool>,
+
+    /// Whether a focus ring should be shown, meaning the element is focused and the user interacts using a keyboard.
+    pub is_focus_visible: Signal<bool>,
+}
+
+pub fn use_focus_ring(input: UseFocusRingInput) -> UseFocusRingReturn {
+    let (is_focused, set_is_focused) = create_signal(false);
+    let on_focus_change = Some(Callback::new(move |focused| set_is_focused.set(focused)));
+
+    let (on_focus_in, on_focus_out) = match input.within {
+        true => {
+            let focus_within = use_focus_within(UseFocusWithinInput {
+                disabled: input.disabled,
+                on_focus_within: None,
+                on_blur_within: None,
+                on_focus_within_change: on_focus_change,
+            });
+            (
+                focus_within.props.on_focus_in,
+                focus_within.props.on_focus_out,
+            )
+        }
+        false => {
+            let focus = use_focus(UseFocusInput {
+                disabled: input.disabled,
+                on_focus: None,
+                on_blur: None,
+                on_focus_change,
+            });
+            (focus.props.on_focus, focus.props.on_blur)
+        }
+    };
+
+    // The focus hooks ignore focus changes while disabled, so that the element might still be considered focused.
+    create_effect(move |_| {
+        if input.disabled.get() {
+            set_is_focused.set(false);
+        }
+    });
+
+    let focus_visible = use_focus_visible();
+    let is_focus_visible = Signal::derive(move || {
+        !input.disabled.get() && is_focused.get() && focus_visible.is_focus_visible.get()
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert(
+        "data-focused",
+        Attribute::Fn(Rc::new(move || Attribute::Bool(is_focused.get()))),
+    );
+    attrs.insert(
+        "data-focus-visible",
+        Attribute::Fn(Rc::new(move || Attribute::Bool(is_focus_visible.get()))),
+    );
+
+    UseFocusRingReturn {
+        props: UseFocusRingProps {
+            attrs,
+            on_focus_in,
+            on_focus_out,
+        },
+        is_focused: is_focused.into(),
+        is_focus_visible,
+    }
+}
@@ -0,0 +1,191 @@
+use std::ops::Deref;
+
+use educe::Educe;
+use leptos::{html::ElementDescriptor, NodeRef};
+use leptos_reactive::{
+    create_effect, create_memo, on_cleanup, queue_microtask, store_value, MaybeSignal, SignalGet,
+    StoredValue,
+};
+use wasm_bindgen::JsCast;
+use web_sys::KeyboardEvent;
+
+use crate::{
+    hooks::focus::use_focus_manager::FocusManager,
+    utils::{
+        focus::{active_element, focus, tabbable_elements},
+        props::Attributes,
+    },
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/focus/src/FocusScope.tsx
+
+#[derive(Clone, Copy, Educe)]
+#[educe(Debug)]
+pub struct UseFocusScopeInput<ScopeRef>
+where
+    ScopeRef: ElementDescriptor + 'static,
+{
+    /// The element containing all elements of the scope.
+    #[educe(Debug(ignore))]
+    pub scope_ref: NodeRef<ScopeRef>,
+
+    /// Whether the scope is currently active. Activating the scope auto-focuses it (if requested),
+    /// deactivating it or unmounting an active scope restores focus (if requested).
+    pub active: MaybeSignal<bool>,
+
+    /// Keep Tab navigation inside of the scope while it is active. Tabbing past the last element continues at the first.
+    pub contain: bool,
+
+    /// Move focus to the first tabbable element of the scope when it becomes active,
+    /// unless focus already is inside of the scope.
+    pub auto_focus: bool,
+
+    /// Give focus back to the element which was focused before the scope became active when it is deactivated,
+    /// but only if focus still is inside of the scope or got lost.
+    pub restore_focus: bool,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseFocusScopeProps {
+    /// These attributes must be spread onto the scope element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the scope element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+}
+
+#[derive(Debug)]
+pub struct UseFocusScopeReturn {
+    pub props: UseFocusScopeProps,
+
+    /// Moves focus between the tabbable elements of this scope.
+    pub focus_manager: FocusManager,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ActiveScope {
+    /// The scope element at the time of activation. Still known when the scope is unmounted.
+    scope: Option<web_sys::Element>,
+
+    /// The element which was focused before the scope became active.
+    previously_focused: Option<web_sys::HtmlElement>,
+}
+
+fn is_inside(scope: Option<&web_sys::Element>, el: &web_sys::Element) -> bool {
+    scope.is_some_and(|scope| scope.contains(Some(el)))
+}
+
+fn deactivate(active_scope: StoredValue<Option<ActiveScope>>, restore_focus: bool) {
+    let Some(ActiveScope {
+        scope,
+        previously_focused,
+    }) = active_scope.get_value()
+    else {
+        return;
+    };
+    active_scope.set_value(None);
+
+    if !restore_focus {
+        return;
+    }
+    let focus_lost = match active_element() {
+        Some(active) => active.node_name() == "BODY" || is_inside(scope.as_ref(), &active),
+        None => true,
+    };
+    if let Some(previously_focused) = previously_focused {
+        if focus_lost && previously_focused.is_connected() {
+            focus(&previously_focused);
+        }
+    }
+}
+
+// Like all hooks, this takes its input by value, even though it only copies fields out of it.
+#[allow(clippy::needless_pass_by_value)]
+pub fn use_focus_scope<ScopeRef>(input: UseFocusScopeInput<ScopeRef>) -> UseFocusScopeReturn
+where
+    ScopeRef: ElementDescriptor + Clone + 'static,
+{
+    let scope_ref = input.scope_ref;
+    let scope = create_memo(move |_| {
+        scope_ref
+            .get()
+            .map(|el| web_sys::Element::from(el.into_any().deref().clone()))
+    });
+    let focus_manager = FocusManager::new(scope.into());
+
+    let active_scope = store_value(Option::<ActiveScope>::None);
+    let auto_focus = input.auto_focus;
+    let restore_focus = input.restore_focus;
+
+    create_effect(move |_| {
+        let scope = scope.get();
+        let is_active = input.active.get() && scope.is_some();
+        match (active_scope.with_value(Option::is_some), is_active) {
+            (false, true) => {
+                active_scope.set_value(Some(ActiveScope {
+                    scope: scope.clone(),
+                    previously_focused: active_element().and_then(|el| el.dyn_into().ok()),
+                }));
+                if auto_focus {
+                    // The scope element might not yet be attached to the document.
+                    queue_microtask(move || {
+                        let focus_inside = active_element()
+                            .is_some_and(|active| is_inside(scope.as_ref(), &active));
+                        if !focus_inside {
+                            focus_manager.focus_first();
+                        }
+                    });
+                }
+            }
+            (true, false) => deactivate(active_scope, restore_focus),
+            _ => {}
+        }
+    });
+
+    on_cleanup(move || deactivate(active_scope, restore_focus));
+
+    let contain = input.contain;
+    let on_key_down = Box::new(move |e: KeyboardEvent| {
+        // A nested scope might already have handled the event.
+        if !contain || e.key().as_str() != "Tab" || e.default_prevented() {
+            return;
+        }
+        let Some(scope) = active_scope.with_value(|s| s.as_ref().and_then(|s| s.scope.clone()))
+        else {
+            return;
+        };
+        let tabbable = tabbable_elements(&scope);
+        let (Some(first), Some(last)) = (tabbable.first(), tabbable.last()) else {
+            // Nothing to move focus to. Focus must stay where it is.
+            e.prevent_default();
+            return;
+        };
+        let active = active_element();
+        let is_active = |el: &web_sys::HtmlElement| {
+            let el: &web_sys::Element = el.as_ref();
+            active.as_ref() == Some(el)
+        };
+        let on_tabbable = tabbable.iter().any(is_active);
+        match e.shift_key() {
+            true if is_active(first) || !on_tabbable => {
+                e.prevent_default();
+                focus(last);
+            }
+            false if is_active(last) || !on_tabbable => {
+                e.prevent_default();
+                focus(first);
+            }
+            _ => {}
+        }
+    });
+
+    UseFocusScopeReturn {
+        props: UseFocusScopeProps {
+            attrs: Attributes::new(),
+            on_key_down,
+        },
+        focus_manager,
+    }
+}
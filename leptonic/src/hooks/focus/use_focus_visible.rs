@@ -0,0 +1,29 @@
+use leptos::use_context;
+use leptos_reactive::{Signal, SignalGet};
+
+use crate::contexts::global_modality::{GlobalModality, Modality};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/interactions/src/useFocusVisible.ts
+
+#[derive(Debug, Clone, Copy)]
+pub struct UseFocusVisibleReturn {
+    /// The modality of the last user interaction. `None` as long as the user did not interact with the page.
+    pub modality: Signal<Option<Modality>>,
+
+    /// Whether focus should currently be visualized, which is the case unless the user interacts using a pointer.
+    pub is_focus_visible: Signal<bool>,
+}
+
+/// Tells keyboard (and virtual) focus apart from focus caused by a pointer.
+/// Relies on the `GlobalModality` context provided by `<Root>`. Without it, focus is always considered visible.
+pub fn use_focus_visible() -> UseFocusVisibleReturn {
+    let modality: Signal<Option<Modality>> = match use_context::<GlobalModality>() {
+        Some(global_modality) => global_modality.read_signal.into(),
+        None => Signal::derive(|| None),
+    };
+
+    UseFocusVisibleReturn {
+        modality,
+        is_focus_visible: Signal::derive(move || modality.get() != Some(Modality::Pointer)),
+    }
+}
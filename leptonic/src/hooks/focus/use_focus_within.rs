@@ -0,0 +1,117 @@
+use educe::Educe;
+use leptos::{ev::FocusEvent, Callable, Callback, MaybeSignal};
+use leptos_reactive::{
+    create_effect, create_signal, store_value, Signal, SignalGet, SignalGetUntracked, SignalSet,
+};
+use wasm_bindgen::JsCast;
+
+use crate::utils::props::Attributes;
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/interactions/src/useFocusWithin.ts
+
+#[derive(Debug, Clone, Copy)]
+pub struct UseFocusWithinInput {
+    /// Disables the handling of focus events when true.
+    /// When focus is within the element when this switches to `true`, `on_blur_within` is not called,
+    /// but `is_focus_within` transitions to `false`.
+    pub disabled: MaybeSignal<bool>,
+
+    /// Called when focus enters the element or one of its descendants.
+    pub on_focus_within: Option<Callback<FocusEvent>>,
+
+    /// Called when focus leaves the element and all of its descendants.
+    pub on_blur_within: Option<Callback<FocusEvent>>,
+
+    /// Called whenever the focus within state changes.
+    pub on_focus_within_change: Option<Callback<bool>>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseFocusWithinProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the target element: `<foo on:focusin=on_focus_in />`
+    #[educe(Debug(ignore))]
+    pub on_focus_in: Box<dyn Fn(FocusEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:focusout=on_focus_out />`
+    #[educe(Debug(ignore))]
+    pub on_focus_out: Box<dyn Fn(FocusEvent)>,
+}
+
+#[derive(Debug)]
+pub struct UseFocusWithinReturn {
+    pub props: UseFocusWithinProps,
+
+    /// Whether the element or one of its descendants currently has focus.
+    pub is_focus_within: Signal<bool>,
+}
+
+pub fn use_focus_within(input: UseFocusWithinInput) -> UseFocusWithinReturn {
+    let (is_focus_within, set_is_focus_within) = create_signal(false);
+    let current_target = store_value(Option::<web_sys::Node>::None);
+
+    let on_focus_in = Box::new(move |e: FocusEvent| {
+        if input.disabled.get_untracked() || is_focus_within.get_untracked() {
+            return;
+        }
+
+        current_target.set_value(e.current_target().and_then(|t| t.dyn_into().ok()));
+        set_is_focus_within.set(true);
+
+        if let Some(on_focus_within) = input.on_focus_within {
+            Callable::call(&on_focus_within, e);
+        }
+
+        if let Some(on_focus_within_change) = input.on_focus_within_change {
+            Callable::call(&on_focus_within_change, true);
+        }
+    });
+
+    let on_focus_out = Box::new(move |e: FocusEvent| {
+        if input.disabled.get_untracked() || !is_focus_within.get_untracked() {
+            return;
+        }
+
+        // Focus moving between descendants is not of interest.
+        let related_target: Option<web_sys::Node> =
+            e.related_target().and_then(|t| t.dyn_into().ok());
+        let stays_within = current_target.with_value(|current_target| {
+            current_target
+                .as_ref()
+                .is_some_and(|current_target| current_target.contains(related_target.as_ref()))
+        });
+        if stays_within {
+            return;
+        }
+
+        current_target.set_value(None);
+        set_is_focus_within.set(false);
+
+        if let Some(on_blur_within) = input.on_blur_within {
+            Callable::call(&on_blur_within, e);
+        }
+
+        if let Some(on_focus_within_change) = input.on_focus_within_change {
+            Callable::call(&on_focus_within_change, false);
+        }
+    });
+
+    create_effect(move |_| {
+        if input.disabled.get() && is_focus_within.get_untracked() {
+            current_target.set_value(None);
+            set_is_focus_within.set(false);
+        }
+    });
+
+    UseFocusWithinReturn {
+        props: UseFocusWithinProps {
+            attrs: Attributes::new(),
+            on_focus_in,
+            on_focus_out,
+        },
+        is_focus_within: is_focus_within.into(),
+    }
+}
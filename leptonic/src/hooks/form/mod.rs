@@ -0,0 +1,2 @@
+pub mod use_form;
+pub mod use_form_field;
@@ -0,0 +1,204 @@
+use std::{future::Future, pin::Pin, rc::Rc};
+
+use educe::Educe;
+use leptos::{spawn_local, Attribute, Oco};
+use leptos_reactive::{
+    create_rw_signal, create_trigger, store_value, RwSignal, Signal, SignalGet, SignalGetUntracked,
+    SignalSet, StoredValue, Trigger,
+};
+use web_sys::{Event, SubmitEvent};
+
+use crate::utils::props::Attributes;
+
+// This is mostly based on work in: https://github.com/TanStack/form/blob/main/packages/form-core/src/FormApi.ts
+
+/// A future produced by a form, e.g. by an async validator or a submit handler.
+pub type FormFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+/// Called with no arguments once a form was submitted and all of its fields are valid.
+#[derive(Clone, Educe)]
+#[educe(Debug)]
+pub struct SubmitHandler(#[educe(Debug(ignore))] Rc<dyn Fn() -> FormFuture<()>>);
+
+impl SubmitHandler {
+    /// The form stays in its submitting state until the future returned by `on_submit` completes.
+    pub fn new<Fut: Future<Output = ()> + 'static>(on_submit: impl Fn() -> Fut + 'static) -> Self {
+        Self(Rc::new(move || Box::pin(on_submit())))
+    }
+}
+
+impl<F: Fn() + 'static> From<F> for SubmitHandler {
+    fn from(on_submit: F) -> Self {
+        Self::new(move || {
+            on_submit();
+            async {}
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UseFormInput {
+    pub on_submit: SubmitHandler,
+}
+
+/// A field registered with a form, as seen by the form. Registered by `use_form_field`.
+#[derive(Clone)]
+pub(super) struct FieldRegistration {
+    pub(super) id: uuid::Uuid,
+    pub(super) has_errors: Signal<bool>,
+    pub(super) is_dirty: Signal<bool>,
+    pub(super) touch: Rc<dyn Fn()>,
+    pub(super) reset: Rc<dyn Fn()>,
+    /// Runs all validators of the field. Resolves to whether the field is valid.
+    pub(super) validate: Rc<dyn Fn() -> FormFuture<bool>>,
+}
+
+/// State shared between a form and its fields. Pass this to `use_form_field`.
+#[derive(Clone, Copy, Educe)]
+#[educe(Debug)]
+pub struct FormState {
+    #[educe(Debug(ignore))]
+    pub(super) fields: StoredValue<Vec<FieldRegistration>>,
+    #[educe(Debug(ignore))]
+    pub(super) fields_changed: Trigger,
+    pub(super) submitting: RwSignal<bool>,
+    pub(super) submit_count: RwSignal<u32>,
+    #[educe(Debug(ignore))]
+    pub(super) on_submit: StoredValue<SubmitHandler>,
+}
+
+impl FormState {
+    /// Whether the form is currently validated for a submit or the submit handler is running.
+    pub fn is_submitting(&self) -> Signal<bool> {
+        self.submitting.into()
+    }
+
+    /// Number of times the form was submitted, regardless of whether the submit succeeded.
+    /// Fields show their errors once the form was submitted, even if they were never touched.
+    pub fn submit_count(&self) -> Signal<u32> {
+        self.submit_count.into()
+    }
+
+    /// Whether no field currently has an error. Async validators still running are not awaited.
+    pub fn is_valid(&self) -> Signal<bool> {
+        let state = *self;
+        Signal::derive(move || {
+            state.fields_changed.track();
+            state
+                .fields
+                .with_value(|fields| fields.iter().map(|f| f.has_errors).collect::<Vec<_>>())
+                .into_iter()
+                .all(|has_errors| !has_errors.get())
+        })
+    }
+
+    /// Whether any field holds a value different from its initial value.
+    pub fn is_dirty(&self) -> Signal<bool> {
+        let state = *self;
+        Signal::derive(move || {
+            state.fields_changed.track();
+            state
+                .fields
+                .with_value(|fields| fields.iter().map(|f| f.is_dirty).collect::<Vec<_>>())
+                .into_iter()
+                .any(|is_dirty| is_dirty.get())
+        })
+    }
+
+    /// Marks all fields as touched and validates them, awaiting async validators.
+    /// Calls the submit handler if all fields are valid. Does nothing while a submit is already in progress.
+    pub fn submit(&self) {
+        if self.submitting.get_untracked() {
+            return;
+        }
+        let state = *self;
+        let fields = self.fields.get_value();
+        for field in &fields {
+            (field.touch)();
+        }
+        self.submit_count.set(self.submit_count.get_untracked() + 1);
+        self.submitting.set(true);
+        spawn_local(async move {
+            let mut valid = true;
+            for field in &fields {
+                valid &= (field.validate)().await;
+            }
+            if valid {
+                let on_submit = state.on_submit.get_value();
+                (on_submit.0)().await;
+            }
+            state.submitting.set(false);
+        });
+    }
+
+    /// Resets all fields to their initial values and forgets that they were touched.
+    pub fn reset(&self) {
+        for field in self.fields.get_value() {
+            (field.reset)();
+        }
+        self.submit_count.set(0);
+    }
+}
+
+#[derive(Debug)]
+pub struct UseFormReturn {
+    pub props: UseFormProps,
+
+    /// Pass this to `use_form_field` for every field of this form.
+    pub form: FormState,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseFormProps {
+    /// These attributes must be spread onto the form element: `<form {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the form element: `<form on:submit=on_submit />`
+    #[educe(Debug(ignore))]
+    pub on_submit: Box<dyn Fn(SubmitEvent)>,
+
+    /// This handler must be attached to the form element: `<form on:reset=on_reset />`
+    #[educe(Debug(ignore))]
+    pub on_reset: Box<dyn Fn(Event)>,
+}
+
+pub fn use_form(input: UseFormInput) -> UseFormReturn {
+    let form = FormState {
+        fields: store_value(Vec::new()),
+        fields_changed: create_trigger(),
+        submitting: create_rw_signal(false),
+        submit_count: create_rw_signal(0),
+        on_submit: store_value(input.on_submit),
+    };
+
+    // Submitting is handled by the form state. The browser must neither navigate nor run its own constraint validation.
+    let on_submit = Box::new(move |e: SubmitEvent| {
+        e.prevent_default();
+        form.submit();
+    });
+
+    let on_reset = Box::new(move |e: Event| {
+        e.prevent_default();
+        form.reset();
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert("novalidate", Attribute::Bool(true));
+    attrs.insert(
+        "aria-busy",
+        Attribute::Fn(Rc::new(move || match form.submitting.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::Option(None),
+        })),
+    );
+
+    UseFormReturn {
+        props: UseFormProps {
+            attrs,
+            on_submit,
+            on_reset,
+        },
+        form,
+    }
+}
@@ -0,0 +1,374 @@
+use std::{future::Future, rc::Rc};
+
+use educe::Educe;
+use leptos::{spawn_local, Oco};
+use leptos_reactive::{
+    create_effect, create_memo, create_rw_signal, on_cleanup, store_value, Callable, Callback,
+    Memo, RwSignal, Signal, SignalGet, SignalGetUntracked, SignalSet, SignalWith,
+    SignalWithUntracked, StoredValue,
+};
+use leptos_use::signal_debounced;
+
+use super::use_form::{FieldRegistration, FormFuture, FormState};
+
+// This is mostly based on work in: https://github.com/TanStack/form/blob/main/packages/form-core/src/FieldApi.ts
+
+/// Describes why a value is invalid. Shown to the user.
+pub type ValidationError = Oco<'static, str>;
+
+/// Outcome of validating a value.
+pub type ValidationResult = Result<(), ValidationError>;
+
+type ValidationFuture = FormFuture<ValidationResult>;
+
+/// Checks the value of a field synchronously, whenever it changes.
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct Validator<T>(#[educe(Debug(ignore))] Rc<dyn Fn(&T) -> ValidationResult>);
+
+impl<T> Clone for Validator<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: 'static> Validator<T> {
+    pub fn new(validate: impl Fn(&T) -> ValidationResult + 'static) -> Self {
+        Self(Rc::new(validate))
+    }
+
+    /// Rejects empty values, e.g. an empty string, `None` or an unchecked checkbox.
+    pub fn required(message: impl Into<ValidationError>) -> Self
+    where
+        T: RequiredValue,
+    {
+        let message = message.into();
+        Self::new(move |value: &T| match value.is_missing() {
+            true => Err(message.clone()),
+            false => Ok(()),
+        })
+    }
+}
+
+/// Checks the value of a field asynchronously, e.g. by asking a server whether a user name is still available.
+/// Only runs when all synchronous validators accepted the value.
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct AsyncValidator<T>(#[educe(Debug(ignore))] Rc<dyn Fn(T) -> ValidationFuture>);
+
+impl<T> Clone for AsyncValidator<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: 'static> AsyncValidator<T> {
+    pub fn new<Fut: Future<Output = ValidationResult> + 'static>(
+        validate: impl Fn(T) -> Fut + 'static,
+    ) -> Self {
+        Self(Rc::new(move |value| Box::pin(validate(value))))
+    }
+}
+
+/// Values which can be missing. Used by `Validator::required`.
+pub trait RequiredValue {
+    fn is_missing(&self) -> bool;
+}
+
+impl RequiredValue for String {
+    fn is_missing(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl RequiredValue for bool {
+    fn is_missing(&self) -> bool {
+        !self
+    }
+}
+
+impl<T> RequiredValue for Option<T> {
+    fn is_missing(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T> RequiredValue for Vec<T> {
+    fn is_missing(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseFormFieldInput<T: 'static> {
+    /// The form this field belongs to, as returned by `use_form`.
+    pub form: FormState,
+
+    /// Value of the field before the user changed it. The field is dirty while its value differs from this value.
+    #[educe(Debug(ignore))]
+    pub initial: T,
+
+    /// Run in order whenever the value changes. Every failing validator contributes an error.
+    pub validators: Vec<Validator<T>>,
+
+    pub async_validators: Vec<AsyncValidator<T>>,
+
+    /// Milliseconds for which the value must not change before async validators run for it.
+    /// Submitting the form validates the current value right away.
+    pub async_debounce_ms: f64,
+}
+
+/// A single, typed value of a form, together with its validation state.
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct FormField<T: 'static> {
+    #[educe(Debug(ignore))]
+    value: RwSignal<T>,
+    #[educe(Debug(ignore))]
+    initial: StoredValue<T>,
+    touched: RwSignal<bool>,
+    sync_errors: Memo<Vec<ValidationError>>,
+    async_errors: RwSignal<Vec<ValidationError>>,
+    validating: RwSignal<bool>,
+    form: FormState,
+}
+
+impl<T: 'static> Clone for FormField<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for FormField<T> {}
+
+impl<T: Clone + PartialEq + 'static> FormField<T> {
+    /// The current value. Pass this to an input to read and write the value of this field.
+    pub const fn value(&self) -> RwSignal<T> {
+        self.value
+    }
+
+    /// Errors of all failing validators. Empty if the value is valid.
+    pub fn errors(&self) -> Signal<Vec<ValidationError>> {
+        let field = *self;
+        Signal::derive(move || {
+            let mut errors = field.sync_errors.get();
+            errors.extend(field.async_errors.get());
+            errors
+        })
+    }
+
+    /// The first error. `None` if the value is valid.
+    pub fn error(&self) -> Signal<Option<ValidationError>> {
+        let errors = self.errors();
+        Signal::derive(move || errors.with(|errors| errors.first().cloned()))
+    }
+
+    pub fn is_valid(&self) -> Signal<bool> {
+        let errors = self.errors();
+        Signal::derive(move || errors.with(Vec::is_empty))
+    }
+
+    /// Whether the value differs from the initial value.
+    pub fn is_dirty(&self) -> Signal<bool> {
+        let field = *self;
+        Signal::derive(move || {
+            field
+                .initial
+                .with_value(|initial| field.value.with(|v| v != initial))
+        })
+    }
+
+    /// Whether the user interacted with this field, e.g. by leaving its input, or the form was submitted.
+    pub fn is_touched(&self) -> Signal<bool> {
+        self.touched.into()
+    }
+
+    /// Whether async validators are currently checking the value.
+    pub fn is_validating(&self) -> Signal<bool> {
+        self.validating.into()
+    }
+
+    pub fn touch(&self) {
+        if !self.touched.get_untracked() {
+            self.touched.set(true);
+        }
+    }
+
+    /// Validation state of this field, independent of its value type. Pass this to a `FormControl`.
+    pub fn status(&self) -> FormFieldStatus {
+        let field = *self;
+        let errors = self.errors();
+        FormFieldStatus {
+            errors,
+            show_errors: Signal::derive(move || {
+                field.touched.get() || field.form.submit_count.get() > 0
+            }),
+            validating: self.validating.into(),
+            touch: Callback::new(move |()| field.touch()),
+        }
+    }
+}
+
+/// Validation state of a form field, independent of its value type.
+#[derive(Debug, Clone, Copy)]
+pub struct FormFieldStatus {
+    pub errors: Signal<Vec<ValidationError>>,
+
+    /// Errors should only be pointed out once the field was touched or the form was submitted.
+    pub show_errors: Signal<bool>,
+
+    pub validating: Signal<bool>,
+
+    /// Marks the field as touched.
+    pub touch: Callback<()>,
+}
+
+impl FormFieldStatus {
+    /// Whether the field has errors which should be shown to the user.
+    pub fn is_invalid(&self) -> bool {
+        self.show_errors.get() && self.errors.with(|errors| !errors.is_empty())
+    }
+
+    pub fn touch(&self) {
+        Callable::call(&self.touch, ());
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> From<FormField<T>> for FormFieldStatus {
+    fn from(field: FormField<T>) -> Self {
+        field.status()
+    }
+}
+
+/// Runs all async validators on `value`, collecting their errors.
+fn run_async_validators<T: Clone + 'static>(
+    validators: Vec<AsyncValidator<T>>,
+    value: T,
+) -> FormFuture<Vec<ValidationError>> {
+    Box::pin(async move {
+        let mut errors = Vec::new();
+        for validator in validators {
+            if let Err(err) = (validator.0)(value.clone()).await {
+                errors.push(err);
+            }
+        }
+        errors
+    })
+}
+
+pub fn use_form_field<T: Clone + PartialEq + 'static>(input: UseFormFieldInput<T>) -> FormField<T> {
+    let form = input.form;
+    let value = create_rw_signal(input.initial.clone());
+    let initial = store_value(input.initial);
+    let touched = create_rw_signal(false);
+
+    let validators = input.validators;
+    let sync_errors = create_memo(move |_| {
+        value.with(|value| {
+            validators
+                .iter()
+                .filter_map(|validator| (validator.0)(value).err())
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let async_validators = store_value(input.async_validators);
+    let async_errors = create_rw_signal(Vec::new());
+    let validating = create_rw_signal(false);
+
+    // Results of async validations started before the latest one are stale and must not be shown.
+    // The future still resolves to whether the value it validated is valid.
+    let generation = store_value(0_u64);
+    let validate_async = move || -> FormFuture<bool> {
+        generation.update_value(|generation| *generation += 1);
+        let current = generation.get_value();
+        let sync_valid = sync_errors.with_untracked(Vec::is_empty);
+        if !sync_valid || async_validators.with_value(Vec::is_empty) {
+            async_errors.set(Vec::new());
+            validating.set(false);
+            return Box::pin(async move { sync_valid });
+        }
+        validating.set(true);
+        let validation = run_async_validators(async_validators.get_value(), value.get_untracked());
+        Box::pin(async move {
+            let errors = validation.await;
+            let is_valid = errors.is_empty();
+            if generation.get_value() == current {
+                async_errors.set(errors);
+                validating.set(false);
+            }
+            is_valid
+        })
+    };
+
+    let debounced_value = signal_debounced(value, input.async_debounce_ms);
+    create_effect(move |_| {
+        debounced_value.track();
+        spawn_local(async move {
+            validate_async().await;
+        });
+    });
+
+    let field = FormField {
+        value,
+        initial,
+        touched,
+        sync_errors,
+        async_errors,
+        validating,
+        form,
+    };
+
+    let id = uuid::Uuid::new_v4();
+    let registration = FieldRegistration {
+        id,
+        has_errors: Signal::derive(move || !field.is_valid().get()),
+        is_dirty: field.is_dirty(),
+        touch: Rc::new(move || field.touch()),
+        reset: Rc::new(move || {
+            value.set(initial.get_value());
+            touched.set(false);
+        }),
+        validate: Rc::new(validate_async),
+    };
+    form.fields.update_value(|fields| fields.push(registration));
+    form.fields_changed.notify();
+
+    on_cleanup(move || {
+        form.fields
+            .update_value(|fields| fields.retain(|field| field.id != id));
+        form.fields_changed.notify();
+    });
+
+    field
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequiredValue, ValidationError, Validator};
+
+    #[test]
+    fn blank_strings_are_missing() {
+        assert!(String::new().is_missing());
+        assert!(String::from("  ").is_missing());
+        assert!(!String::from("a").is_missing());
+    }
+
+    #[test]
+    fn empty_values_are_missing() {
+        assert!(false.is_missing());
+        assert!(!true.is_missing());
+        assert!(None::<u32>.is_missing());
+        assert!(!Some(0).is_missing());
+        assert!(Vec::<u32>::new().is_missing());
+        assert!(!vec![0].is_missing());
+    }
+
+    #[test]
+    fn required_validator_rejects_missing_values() {
+        let validator = Validator::<Option<u32>>::required("Required");
+        assert_eq!((validator.0)(&None), Err(ValidationError::from("Required")));
+        assert_eq!((validator.0)(&Some(1)), Ok(()));
+    }
+}
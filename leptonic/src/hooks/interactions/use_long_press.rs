@@ -1,28 +1,326 @@
-use leptos::Oco;
-use leptos_reactive::{Callback, MaybeSignal};
+use std::{rc::Rc, time::Duration};
 
-pub struct LongPressEvent {}
+use educe::Educe;
+use leptos::{leptos_dom::helpers::TimeoutHandle, set_timeout_with_handle, Attribute, Oco};
+use leptos_reactive::{
+    on_cleanup, store_value, Callable, Callback, MaybeSignal, SignalGet, SignalGetUntracked,
+    StoredValue,
+};
+use web_sys::{KeyboardEvent, MouseEvent, PointerEvent};
 
+use crate::{
+    hooks::interactions::use_press::{use_press, PressEvent, UsePressInput},
+    utils::{
+        description::use_description, pointer_type::PointerType, props::Attributes, EventModifiers,
+        Modifiers,
+    },
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/interactions/src/useLongPress.ts
+
+/// Pointer movement (in px) after which a pending long press is canceled.
+const MOVE_TOLERANCE: f64 = 10.0;
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct LongPressEvent {
+    /// The pointer type that triggered the long press event.
+    pub pointer_type: PointerType,
+
+    /// The target element of the long press event.
+    pub target: Option<web_sys::EventTarget>,
+
+    /// Sates which modifier keys were held during the long press event.
+    pub modifiers: Modifiers,
+}
+
+impl LongPressEvent {
+    fn from_press_event(e: &PressEvent) -> Self {
+        Self {
+            pointer_type: e.pointer_type.clone(),
+            target: e.target.clone(),
+            modifiers: e.modifiers,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct UseLongPressInput {
     /// Whether long press events should be disabled.
-    disabled: MaybeSignal<bool>,
+    pub disabled: MaybeSignal<bool>,
 
     /// Handler that is called when a long press interaction starts.
-    on_long_press_start: Callback<LongPressEvent>,
+    pub on_long_press_start: Option<Callback<LongPressEvent>>,
 
     /// Handler that is called when a long press interaction ends, either
     /// over the target or when the pointer leaves the target.
-    on_long_press_end: Callback<LongPressEvent>,
+    pub on_long_press_end: Option<Callback<LongPressEvent>>,
 
     /// Handler that is called when the threshold time is met while
     /// the press is over the target.
-    on_long_press: Callback<LongPressEvent>,
+    pub on_long_press: Callback<LongPressEvent>,
 
     /// The amount of time in milliseconds to wait before triggering a long press.
     /// Default is 500ms.
-    threshold: u64,
+    pub threshold: u64,
 
     /// A description for assistive technology users indicating that a long press
     /// action is available, e.g. "Long press to open menu".
-    accessibility_description: Oco<'static, str>,
+    pub accessibility_description: Option<Oco<'static, str>>,
+}
+
+impl UseLongPressInput {
+    /// The default threshold of 500ms.
+    pub const DEFAULT_THRESHOLD: u64 = 500;
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseLongPressProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the target element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:click=on_click />`
+    #[educe(Debug(ignore))]
+    pub on_click: Box<dyn Fn(MouseEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:pointerdown=on_pointer_down />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_down: Box<dyn Fn(PointerEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:pointermove=on_pointer_move />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_move: Box<dyn Fn(PointerEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:contextmenu=on_context_menu />`
+    #[educe(Debug(ignore))]
+    pub on_context_menu: Box<dyn Fn(MouseEvent)>,
+}
+
+#[derive(Debug)]
+pub struct UseLongPressDescriptionProps {
+    /// These attributes must be spread onto an element rendered next to the target element:
+    /// `<span {..attrs}>{ description }</span>`. They hide the element visually.
+    pub attrs: Attributes,
+
+    /// The `accessibility_description`, which must be rendered as the content of the element.
+    pub description: Oco<'static, str>,
+}
+
+#[derive(Debug)]
+pub struct UseLongPressReturn {
+    pub props: UseLongPressProps,
+
+    /// Props for the element describing the target element. Only present when an `accessibility_description` was given.
+    pub description_props: Option<UseLongPressDescriptionProps>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct LongPressState {
+    /// Where the pointer went down. `None` for keyboard interactions.
+    origin: Option<(f64, f64)>,
+
+    /// Whether `on_long_press_start` was called and `on_long_press_end` is still due.
+    started: bool,
+
+    /// Whether `on_long_press` was called during the current interaction.
+    triggered: bool,
+}
+
+impl LongPressState {
+    /// A pointer went down. Whatever is left over from the previous interaction no longer matters.
+    fn pointer_down(&mut self, origin: (f64, f64)) {
+        self.origin = Some(origin);
+        self.triggered = false;
+    }
+
+    fn start(&mut self) {
+        self.started = true;
+        self.triggered = false;
+    }
+
+    fn trigger(&mut self) {
+        self.triggered = true;
+    }
+
+    /// Returns whether the long press was started, meaning that `on_long_press_end` is due.
+    fn end(&mut self) -> bool {
+        std::mem::replace(&mut self.started, false)
+    }
+
+    /// Returns whether the context menu should be suppressed.
+    /// A triggered long press only ever suppresses the one context menu following it.
+    fn take_context_menu(&mut self) -> bool {
+        let suppress = self.started || self.triggered;
+        self.triggered = false;
+        suppress
+    }
+}
+
+fn clear_timeout(timeout: StoredValue<Option<TimeoutHandle>>) {
+    if let Some(handle) = timeout.get_value() {
+        handle.clear();
+        timeout.set_value(None);
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn use_long_press(input: UseLongPressInput) -> UseLongPressReturn {
+    let state = store_value(LongPressState::default());
+    let timeout = store_value(Option::<TimeoutHandle>::None);
+
+    let on_long_press_start = input.on_long_press_start;
+    let on_long_press_end = input.on_long_press_end;
+    let on_long_press = input.on_long_press;
+    let threshold = Duration::from_millis(input.threshold);
+
+    let end = move |e: LongPressEvent| {
+        clear_timeout(timeout);
+        let mut was_started = false;
+        state.update_value(|s| was_started = s.end());
+        if was_started {
+            if let Some(on_long_press_end) = on_long_press_end {
+                Callable::call(&on_long_press_end, e);
+            }
+        }
+    };
+
+    let press = use_press(UsePressInput {
+        disabled: input.disabled,
+        force_prevent_default: false,
+        on_press: Callback::new(|_| {}),
+        on_press_up: None,
+        on_press_start: Some(Callback::new(move |e: PressEvent| {
+            // Long presses are only meaningful for pointers. Keyboard users get the action through other means.
+            if !matches!(
+                e.pointer_type,
+                PointerType::Mouse | PointerType::Pen | PointerType::Touch
+            ) {
+                return;
+            }
+            clear_timeout(timeout);
+            state.update_value(LongPressState::start);
+            if let Some(on_long_press_start) = on_long_press_start {
+                Callable::call(&on_long_press_start, LongPressEvent::from_press_event(&e));
+            }
+
+            let long_press_event = LongPressEvent::from_press_event(&e);
+            match set_timeout_with_handle(
+                move || {
+                    timeout.set_value(None);
+                    state.update_value(LongPressState::trigger);
+                    Callable::call(&on_long_press, long_press_event);
+                },
+                threshold,
+            ) {
+                Ok(handle) => timeout.set_value(Some(handle)),
+                Err(err) => tracing::warn!(?err, "Could not schedule long press."),
+            }
+        })),
+        on_press_end: Some(Callback::new(move |e: PressEvent| {
+            end(LongPressEvent::from_press_event(&e));
+        })),
+    });
+
+    on_cleanup(move || clear_timeout(timeout));
+
+    let press_on_pointer_down = press.props.on_pointer_down;
+    let on_pointer_down = Box::new(move |e: PointerEvent| {
+        state.update_value(|s| s.pointer_down((f64::from(e.client_x()), f64::from(e.client_y()))));
+        press_on_pointer_down(e);
+    });
+
+    // Moving the pointer means the user is most likely scrolling or dragging. That should not end in a long press.
+    let on_pointer_move = Box::new(move |e: PointerEvent| {
+        let Some((x, y)) = state.with_value(|s| s.started.then_some(s.origin).flatten()) else {
+            return;
+        };
+        let dx = f64::from(e.client_x()) - x;
+        let dy = f64::from(e.client_y()) - y;
+        if timeout.with_value(Option::is_some) && dx.hypot(dy) > MOVE_TOLERANCE {
+            end(LongPressEvent {
+                pointer_type: PointerType::from(e.pointer_type()),
+                target: e.target(),
+                modifiers: e.modifiers(),
+            });
+        }
+    });
+
+    // Touch devices open the native context menu on long press. Suppress it, as the long press is handled by us.
+    let disabled = input.disabled;
+    let on_context_menu = Box::new(move |e: MouseEvent| {
+        let mut suppress = false;
+        state.update_value(|s| suppress = s.take_context_menu());
+        if suppress && !disabled.get_untracked() {
+            e.prevent_default();
+        }
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.merge(press.props.attrs);
+    let description_props = input.accessibility_description.map(|description| {
+        let (description_id, description_attrs) = use_description();
+        attrs.insert(
+            "aria-describedby",
+            Attribute::Fn(Rc::new(move || match disabled.get() {
+                true => Attribute::Option(None),
+                false => Attribute::String(description_id.clone()),
+            })),
+        );
+        UseLongPressDescriptionProps {
+            attrs: description_attrs,
+            description,
+        }
+    });
+
+    UseLongPressReturn {
+        props: UseLongPressProps {
+            attrs,
+            on_key_down: press.props.on_key_down,
+            on_click: press.props.on_click,
+            on_pointer_down,
+            on_pointer_move,
+            on_context_menu,
+        },
+        description_props,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LongPressState;
+
+    #[test]
+    fn long_press_suppresses_only_the_following_context_menu() {
+        let mut state = LongPressState::default();
+
+        state.pointer_down((0.0, 0.0));
+        state.start();
+        state.trigger();
+        assert!(state.take_context_menu());
+        assert!(state.end());
+
+        // A right click afterwards does not start a long press. Its context menu must open.
+        state.pointer_down((0.0, 0.0));
+        assert!(!state.take_context_menu());
+    }
+
+    #[test]
+    fn pointer_down_forgets_a_previous_long_press() {
+        let mut state = LongPressState::default();
+
+        // No context menu follows the long press, e.g. when using a mouse.
+        state.pointer_down((0.0, 0.0));
+        state.start();
+        state.trigger();
+        assert!(state.end());
+        assert!(!state.end());
+
+        state.pointer_down((10.0, 10.0));
+        assert!(!state.take_context_menu());
+    }
 }
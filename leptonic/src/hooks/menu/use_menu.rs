@@ -0,0 +1,296 @@
+use std::rc::Rc;
+
+use educe::Educe;
+use leptos::{html::ElementDescriptor, Attribute, IntoAttribute, NodeRef, Oco};
+use leptos_reactive::{
+    create_effect, create_rw_signal, store_value, Callable, Callback, MaybeSignal, RwSignal,
+    Signal, SignalGet, SignalGetUntracked, SignalSet, SignalWithUntracked, StoredValue,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{FocusEvent, KeyboardEvent};
+
+use crate::utils::{props::Attributes, typeahead::Typeahead, EventTargetExt, NodeRefExt};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/menu/src/useMenu.ts
+
+const MENU_ITEM_SELECTOR: &str =
+    r#"[role="menuitem"], [role="menuitemcheckbox"], [role="menuitemradio"]"#;
+
+/// Which item should receive focus when a menu is opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MenuFocusStrategy {
+    First,
+    Last,
+}
+
+#[derive(Clone, Educe)]
+#[educe(Debug)]
+pub struct UseMenuInput<E: ElementDescriptor + 'static> {
+    /// The menu element. All non-disabled menu items rendered inside it are navigable in document order.
+    #[educe(Debug(ignore))]
+    pub node_ref: NodeRef<E>,
+
+    /// Id of the menu element. A random id is generated when `None`.
+    /// Pass the id given to `use_menu_trigger` when the menu is opened through a trigger.
+    pub id: Option<Oco<'static, str>>,
+
+    /// Disables keyboard navigation and item interactions when true.
+    pub disabled: MaybeSignal<bool>,
+
+    /// The item which should be focused when the menu is mounted.
+    /// When `None`, the menu element itself is focused, so that arrow keys can be used right away.
+    pub auto_focus: MaybeSignal<Option<MenuFocusStrategy>>,
+
+    /// Whether keyboard navigation should wrap around when reaching the first or last item.
+    pub should_focus_wrap: bool,
+
+    /// Id of the element labelling this menu, for example the id of its trigger.
+    pub aria_labelledby: Option<Oco<'static, str>>,
+
+    /// Called when the menu should be closed, e.g. after pressing Escape or Tab or after an item was activated.
+    pub on_close: Option<Callback<()>>,
+}
+
+/// State shared between a menu and its items. Pass this to `use_menu_item`.
+#[derive(Clone, Copy, Educe)]
+#[educe(Debug)]
+pub struct MenuState {
+    pub(super) id: StoredValue<Oco<'static, str>>,
+    pub(super) disabled: MaybeSignal<bool>,
+    pub(super) focused_item: RwSignal<Option<Oco<'static, str>>>,
+    pub(super) on_close: Option<Callback<()>>,
+}
+
+impl MenuState {
+    /// Id of the menu element.
+    pub fn id(&self) -> Oco<'static, str> {
+        self.id.get_value()
+    }
+
+    /// Id of the item currently focused. `None` if no item has focus.
+    pub fn focused_item(&self) -> Signal<Option<Oco<'static, str>>> {
+        self.focused_item.into()
+    }
+
+    /// Requests the menu to be closed.
+    pub fn close(&self) {
+        if let Some(on_close) = self.on_close {
+            Callable::call(&on_close, ());
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UseMenuReturn {
+    pub props: UseMenuProps,
+
+    /// Pass this to `use_menu_item` for every item of this menu.
+    pub state: MenuState,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseMenuProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the target element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:focusin=on_focus_in />`
+    #[educe(Debug(ignore))]
+    pub on_focus_in: Box<dyn Fn(FocusEvent)>,
+}
+
+/// All enabled menu items of the given menu element, in document order.
+fn enabled_items(menu: &web_sys::HtmlElement) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = menu.query_selector_all(MENU_ITEM_SELECTOR) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .filter(|el| el.get_attribute("aria-disabled").as_deref() != Some("true"))
+        .collect()
+}
+
+/// Selects the index of the item to focus, given all enabled items and the index of the currently focused item.
+type ItemSelector<'a> = &'a dyn Fn(&[web_sys::HtmlElement], Option<usize>) -> Option<usize>;
+
+fn focus_item(item: &web_sys::HtmlElement) {
+    if let Err(err) = item.focus() {
+        tracing::warn!(?err, "Could not focus menu item.");
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn use_menu<E: ElementDescriptor + Clone + 'static>(input: UseMenuInput<E>) -> UseMenuReturn {
+    let id: Oco<'static, str> = input
+        .id
+        .unwrap_or_else(|| Oco::Owned(format!("menu-{}", uuid::Uuid::new_v4())));
+
+    let state = MenuState {
+        id: store_value(id.clone()),
+        disabled: input.disabled,
+        focused_item: create_rw_signal(None),
+        on_close: input.on_close,
+    };
+
+    let typeahead = store_value(Typeahead::default());
+
+    let current_index = move |items: &[web_sys::HtmlElement]| {
+        state.focused_item.with_untracked(|focused| {
+            focused
+                .as_ref()
+                .and_then(|focused| items.iter().position(|it| it.id() == focused.as_str()))
+        })
+    };
+
+    let focus_by = move |select: ItemSelector<'_>| {
+        if let Some(menu) = input.node_ref.get_html_element_untracked() {
+            let items = enabled_items(&menu);
+            if let Some(item) = select(&items, current_index(&items)).and_then(|i| items.get(i)) {
+                focus_item(item);
+            }
+        }
+    };
+
+    let should_focus_wrap = input.should_focus_wrap;
+
+    let focus_next = move || {
+        focus_by(&|items, current| match current {
+            Some(current) if current + 1 < items.len() => Some(current + 1),
+            Some(current) if !should_focus_wrap => Some(current),
+            Some(_) | None => (!items.is_empty()).then_some(0),
+        });
+    };
+
+    let focus_previous = move || {
+        focus_by(&|items, current| match current {
+            Some(current) if current > 0 => Some(current - 1),
+            Some(current) if !should_focus_wrap => Some(current),
+            Some(_) | None => items.len().checked_sub(1),
+        });
+    };
+
+    let focus_first = move || focus_by(&|items, _| (!items.is_empty()).then_some(0));
+
+    let focus_last = move || focus_by(&|items, _| items.len().checked_sub(1));
+
+    let focus_matching = move |search: String| {
+        focus_by(&|items, current| {
+            // Start searching at the current item, so that repeatedly typing the same letter cycles through all matches.
+            let start = current.map_or(0, |current| match search.chars().count() {
+                1 => current + 1,
+                _ => current,
+            });
+            (0..items.len())
+                .map(|offset| (start + offset) % items.len())
+                .find(|i| {
+                    items[*i]
+                        .text_content()
+                        .unwrap_or_default()
+                        .trim()
+                        .to_lowercase()
+                        .starts_with(search.as_str())
+                })
+        });
+    };
+
+    let auto_focus = input.auto_focus;
+    create_effect(move |_| {
+        let strategy = auto_focus.get();
+        if input.node_ref.get().is_none() {
+            return;
+        }
+        match strategy {
+            Some(MenuFocusStrategy::First) => focus_first(),
+            Some(MenuFocusStrategy::Last) => focus_last(),
+            None => {
+                if let Some(menu) = input.node_ref.get_html_element_untracked() {
+                    focus_item(&menu);
+                }
+            }
+        }
+    });
+
+    let on_key_down = Box::new(move |e: KeyboardEvent| {
+        if input.disabled.get_untracked() {
+            return;
+        }
+        match e.key().as_str() {
+            "ArrowDown" => {
+                e.prevent_default();
+                focus_next();
+            }
+            "ArrowUp" => {
+                e.prevent_default();
+                focus_previous();
+            }
+            "Home" | "PageUp" => {
+                e.prevent_default();
+                focus_first();
+            }
+            "End" | "PageDown" => {
+                e.prevent_default();
+                focus_last();
+            }
+            "Escape" => {
+                e.prevent_default();
+                e.stop_propagation();
+                state.close();
+            }
+            // Tab must not be prevented. Focus should move on naturally while the menu closes.
+            "Tab" => state.close(),
+            key if key.chars().count() == 1 && !e.ctrl_key() && !e.meta_key() && !e.alt_key() => {
+                // Space is only part of the search if a search is already in progress. It otherwise activates the focused item.
+                let now = js_sys::Date::now();
+                let in_progress = typeahead.with_value(|t| t.is_in_progress(now));
+                if key == " " && !in_progress {
+                    return;
+                }
+                e.prevent_default();
+                e.stop_propagation();
+                let mut search = String::new();
+                typeahead.update_value(|t| t.push(key, now).clone_into(&mut search));
+                focus_matching(search);
+            }
+            _ => {}
+        }
+    });
+
+    // Keeps track of the focused item, regardless of how it received focus.
+    let on_focus_in = Box::new(move |e: FocusEvent| {
+        let focused = e
+            .target()
+            .and_then(|t| t.as_element())
+            .filter(|el| el.matches(MENU_ITEM_SELECTOR).unwrap_or(false))
+            .map(|el| Oco::Owned(el.id()));
+        state.focused_item.set(focused);
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert("id", Attribute::String(id));
+    attrs.insert("role", Attribute::String(Oco::Borrowed("menu")));
+    attrs.insert("tabindex", Attribute::String(Oco::Borrowed("-1")));
+    attrs.insert(
+        "aria-disabled",
+        Attribute::Fn(Rc::new(move || match input.disabled.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::Option(None),
+        })),
+    );
+    if let Some(labelledby) = input.aria_labelledby {
+        attrs.insert("aria-labelledby", labelledby.into_attribute());
+    }
+
+    UseMenuReturn {
+        props: UseMenuProps {
+            attrs,
+            on_key_down,
+            on_focus_in,
+        },
+        state,
+    }
+}
@@ -0,0 +1,134 @@
+use std::rc::Rc;
+
+use educe::Educe;
+use leptos::{Attribute, Oco};
+use leptos_reactive::{
+    Callable, Callback, MaybeSignal, Signal, SignalGet, SignalGetUntracked, SignalWith,
+};
+use web_sys::{KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+    hooks::interactions::use_press::{use_press, PressEvent, UsePressInput},
+    utils::{props::Attributes, EventTargetExt},
+};
+
+use super::use_menu::MenuState;
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/menu/src/useMenuItem.ts
+
+#[derive(Debug, Clone, Copy)]
+pub struct UseMenuItemInput {
+    /// The state of the menu this item belongs to, as returned by `use_menu`.
+    pub menu: MenuState,
+
+    /// Whether the item is disabled. Disabled items are skipped by keyboard navigation.
+    pub disabled: MaybeSignal<bool>,
+
+    /// Called when the item is activated, either through a pointer or by pressing Enter or Space.
+    pub on_action: Option<Callback<PressEvent>>,
+
+    /// Whether the menu should be closed after this item was activated.
+    pub close_on_select: bool,
+}
+
+#[derive(Debug)]
+pub struct UseMenuItemReturn {
+    pub props: UseMenuItemProps,
+
+    /// Id of the item element.
+    pub id: Oco<'static, str>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseMenuItemProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the target element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:click=on_click />`
+    #[educe(Debug(ignore))]
+    pub on_click: Box<dyn Fn(MouseEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:pointerdown=on_pointer_down />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_down: Box<dyn Fn(PointerEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:pointerenter=on_pointer_enter />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_enter: Box<dyn Fn(PointerEvent)>,
+}
+
+pub fn use_menu_item(input: UseMenuItemInput) -> UseMenuItemReturn {
+    let id: Oco<'static, str> = Oco::Owned(format!("menu-item-{}", uuid::Uuid::new_v4()));
+
+    let menu = input.menu;
+    let disabled = Signal::derive(move || menu.disabled.get() || input.disabled.get());
+
+    let press = use_press(UsePressInput {
+        disabled: disabled.into(),
+        force_prevent_default: true,
+        on_press: Callback::new(move |e| {
+            if let Some(on_action) = input.on_action {
+                Callable::call(&on_action, e);
+            }
+            if input.close_on_select {
+                menu.close();
+            }
+        }),
+        on_press_up: None,
+        on_press_start: None,
+        on_press_end: None,
+    });
+
+    // Hovering an item moves focus onto it, so that keyboard navigation continues from the hovered item.
+    let on_pointer_enter = Box::new(move |e: PointerEvent| {
+        if disabled.get_untracked() {
+            return;
+        }
+        if let Some(el) = e.current_target().and_then(|t| t.as_html_element()) {
+            if let Err(err) = el.focus() {
+                tracing::warn!(?err, "Could not focus menu item.");
+            }
+        }
+    });
+
+    let focused_id = id.clone();
+    let is_focused = move || {
+        menu.focused_item
+            .with(|f| f.as_deref() == Some(focused_id.as_str()))
+    };
+
+    let mut attrs = Attributes::new();
+    attrs.insert("id", Attribute::String(id.clone()));
+    attrs.insert("role", Attribute::String(Oco::Borrowed("menuitem")));
+    attrs.insert(
+        "tabindex",
+        Attribute::Fn(Rc::new(move || match is_focused() {
+            true => Attribute::String(Oco::Borrowed("0")),
+            false => Attribute::String(Oco::Borrowed("-1")),
+        })),
+    );
+    attrs.insert(
+        "aria-disabled",
+        Attribute::Fn(Rc::new(move || match disabled.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::Option(None),
+        })),
+    );
+    attrs.merge(press.props.attrs);
+
+    UseMenuItemReturn {
+        props: UseMenuItemProps {
+            attrs,
+            on_key_down: press.props.on_key_down,
+            on_click: press.props.on_click,
+            on_pointer_down: press.props.on_pointer_down,
+            on_pointer_enter,
+        },
+        id,
+    }
+}
@@ -0,0 +1,86 @@
+use educe::Educe;
+use leptos::{Attribute, Oco};
+
+use crate::utils::props::Attributes;
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/menu/src/useMenuSection.ts
+
+#[derive(Debug, Clone)]
+pub struct UseMenuSectionInput {
+    /// Whether the section renders a visible heading.
+    /// If so, the group is labelled by that heading.
+    pub has_heading: bool,
+
+    /// Accessible label of the section. Required when no heading is rendered.
+    pub aria_label: Option<Oco<'static, str>>,
+}
+
+#[derive(Debug)]
+pub struct UseMenuSectionReturn {
+    /// Props for the element wrapping the items of this section.
+    pub group_props: UseMenuSectionGroupProps,
+
+    /// Props for the heading of this section. Only relevant when `has_heading` is true.
+    pub heading_props: UseMenuSectionHeadingProps,
+
+    /// Props for an optional separator, visually dividing this section from its predecessor.
+    pub separator_props: UseMenuSectionSeparatorProps,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseMenuSectionGroupProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseMenuSectionHeadingProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseMenuSectionSeparatorProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+}
+
+pub fn use_menu_section(input: UseMenuSectionInput) -> UseMenuSectionReturn {
+    let heading_id: Oco<'static, str> =
+        Oco::Owned(format!("menu-section-heading-{}", uuid::Uuid::new_v4()));
+
+    #[cfg(debug_assertions)]
+    if !input.has_heading && input.aria_label.is_none() {
+        tracing::warn!("use_menu_section received neither a heading nor an aria_label. The section will be unlabelled for assistive technology.");
+    }
+
+    let mut group_attrs = Attributes::new();
+    group_attrs.insert("role", Attribute::String(Oco::Borrowed("group")));
+    if input.has_heading {
+        group_attrs.insert("aria-labelledby", Attribute::String(heading_id.clone()));
+    }
+    if let Some(aria_label) = input.aria_label {
+        group_attrs.insert("aria-label", Attribute::String(aria_label));
+    }
+
+    // The heading is only referenced by the group. It must not be read out as an additional item.
+    let mut heading_attrs = Attributes::new();
+    heading_attrs.insert("id", Attribute::String(heading_id));
+    heading_attrs.insert("role", Attribute::String(Oco::Borrowed("presentation")));
+
+    let mut separator_attrs = Attributes::new();
+    separator_attrs.insert("role", Attribute::String(Oco::Borrowed("separator")));
+
+    UseMenuSectionReturn {
+        group_props: UseMenuSectionGroupProps { attrs: group_attrs },
+        heading_props: UseMenuSectionHeadingProps {
+            attrs: heading_attrs,
+        },
+        separator_props: UseMenuSectionSeparatorProps {
+            attrs: separator_attrs,
+        },
+    }
+}
@@ -0,0 +1,217 @@
+use educe::Educe;
+use leptos::{html::ElementDescriptor, Attribute, NodeRef, Oco};
+use leptos_reactive::{
+    create_effect, create_signal, Callable, Callback, MaybeSignal, Signal, SignalGet,
+    SignalGetUntracked, SignalSet,
+};
+use leptos_use::use_document;
+use web_sys::{KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+    hooks::{
+        interactions::use_press::{use_press, PressEvent, UsePressInput},
+        overlay::{
+            use_overlay_position::{
+                use_overlay_position, OverlayBoundary, PlacementX, PlacementY,
+                UseOverlayPositionInput, OVERLAY_PADDING,
+            },
+            use_overlay_trigger::{use_overlay_trigger, UseOverlayTriggerInput},
+        },
+    },
+    utils::{
+        aria::AriaHasPopup, locale::WritingDirection, pointer_type::PointerType, props::Attributes,
+        NodeRefExt,
+    },
+};
+
+use super::use_menu::MenuFocusStrategy;
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/menu/src/useMenuTrigger.ts
+
+#[derive(Clone, Educe)]
+#[educe(Debug)]
+pub struct UseMenuTriggerInput<TriggerRef, MenuRef>
+where
+    TriggerRef: ElementDescriptor + 'static,
+    MenuRef: ElementDescriptor + 'static,
+{
+    /// The trigger element.
+    #[educe(Debug(ignore))]
+    pub trigger_ref: NodeRef<TriggerRef>,
+
+    /// The menu element. The menu is positioned relative to the trigger.
+    #[educe(Debug(ignore))]
+    pub menu_ref: NodeRef<MenuRef>,
+
+    /// Whether the trigger is disabled.
+    pub disabled: MaybeSignal<bool>,
+
+    /// Id of the menu, for example the id returned by `use_overlay`.
+    pub menu_id: Oco<'static, str>,
+
+    /// Whether the menu is currently shown.
+    pub show: Signal<bool>,
+
+    /// Called whenever the trigger wants to open or close the menu.
+    pub on_open_change: Callback<bool>,
+
+    pub placement_x: MaybeSignal<PlacementX>,
+    pub placement_y: MaybeSignal<PlacementY>,
+    pub writing_direction: MaybeSignal<WritingDirection>,
+}
+
+#[derive(Debug)]
+pub struct UseMenuTriggerReturn {
+    /// Props for the trigger element.
+    pub props: UseMenuTriggerProps,
+
+    /// Props for the menu element.
+    pub menu_props: UseMenuTriggerMenuProps,
+
+    /// The item which should be focused when the menu opens. Pass this to `UseMenuInput::auto_focus`.
+    pub focus_strategy: Signal<Option<MenuFocusStrategy>>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseMenuTriggerProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the target element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:click=on_click />`
+    #[educe(Debug(ignore))]
+    pub on_click: Box<dyn Fn(MouseEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:pointerdown=on_pointer_down />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_down: Box<dyn Fn(PointerEvent)>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseMenuTriggerMenuProps {
+    /// These attributes must be spread onto the menu element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+}
+
+pub fn use_menu_trigger<TriggerRef, MenuRef>(
+    input: UseMenuTriggerInput<TriggerRef, MenuRef>,
+) -> UseMenuTriggerReturn
+where
+    TriggerRef: ElementDescriptor + Clone + 'static,
+    MenuRef: ElementDescriptor + Clone + 'static,
+{
+    let trigger_id: Oco<'static, str> =
+        Oco::Owned(format!("menu-trigger-{}", uuid::Uuid::new_v4()));
+
+    let (focus_strategy, set_focus_strategy) = create_signal(Option::<MenuFocusStrategy>::None);
+
+    let show = input.show;
+    let on_open_change = input.on_open_change;
+    let open = move |strategy: Option<MenuFocusStrategy>| {
+        set_focus_strategy.set(strategy);
+        Callable::call(&on_open_change, true);
+    };
+
+    let press = use_press(UsePressInput {
+        disabled: input.disabled,
+        force_prevent_default: false,
+        on_press: Callback::new(move |e: PressEvent| match show.get_untracked() {
+            true => Callable::call(&on_open_change, false),
+            false => open(match e.pointer_type {
+                PointerType::Keyboard | PointerType::Virtual => Some(MenuFocusStrategy::First),
+                _ => None,
+            }),
+        }),
+        on_press_up: None,
+        on_press_start: None,
+        on_press_end: None,
+    });
+
+    let press_on_key_down = press.props.on_key_down;
+    let disabled = input.disabled;
+    let on_key_down = Box::new(move |e: KeyboardEvent| {
+        if !disabled.get_untracked() {
+            match e.key().as_str() {
+                "ArrowDown" => {
+                    e.prevent_default();
+                    open(Some(MenuFocusStrategy::First));
+                    return;
+                }
+                "ArrowUp" => {
+                    e.prevent_default();
+                    open(Some(MenuFocusStrategy::Last));
+                    return;
+                }
+                _ => {}
+            }
+        }
+        press_on_key_down(e);
+    });
+
+    // Closing the menu unmounts the element which had focus. Give focus back to the trigger in that case.
+    let trigger_ref = input.trigger_ref;
+    create_effect(move |was_shown: Option<bool>| {
+        let is_shown = show.get();
+        if was_shown == Some(true) && !is_shown {
+            let focus_lost = use_document().as_ref().map_or(true, |doc| {
+                match (doc.active_element(), doc.body()) {
+                    (Some(active), Some(body)) => active == *body.as_ref(),
+                    _ => true,
+                }
+            });
+            if focus_lost {
+                if let Some(trigger) = trigger_ref.get_html_element_untracked() {
+                    if let Err(err) = trigger.focus() {
+                        tracing::warn!(?err, "Could not restore focus to menu trigger.");
+                    }
+                }
+            }
+        }
+        is_shown
+    });
+
+    let overlay_trigger = use_overlay_trigger(UseOverlayTriggerInput {
+        show,
+        overlay_id: input.menu_id,
+        overlay_type: AriaHasPopup::Menu,
+    });
+
+    let overlay_position = use_overlay_position(UseOverlayPositionInput {
+        overlay_ref: input.menu_ref,
+        target_ref: input.trigger_ref,
+        placement_x: input.placement_x,
+        placement_y: input.placement_y,
+        writing_direction: input.writing_direction,
+        flip: true,
+        shift: true,
+        offset: 0.0.into(),
+        padding: OVERLAY_PADDING.into(),
+        boundary: OverlayBoundary::Viewport,
+        arrow_ref: None,
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert("id", Attribute::String(trigger_id.clone()));
+    attrs.merge(overlay_trigger.props.attrs);
+    attrs.merge(press.props.attrs);
+
+    let mut menu_attrs = Attributes::new();
+    menu_attrs.insert("aria-labelledby", Attribute::String(trigger_id));
+    menu_attrs.merge(overlay_position.props.attrs);
+
+    UseMenuTriggerReturn {
+        props: UseMenuTriggerProps {
+            attrs,
+            on_key_down,
+            on_click: press.props.on_click,
+            on_pointer_down: press.props.on_pointer_down,
+        },
+        menu_props: UseMenuTriggerMenuProps { attrs: menu_attrs },
+        focus_strategy: focus_strategy.into(),
+    }
+}
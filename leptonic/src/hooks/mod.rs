@@ -1,31 +1,93 @@
 pub mod anchor_link;
+pub mod async_options;
 pub mod button;
 pub mod calendar;
+pub mod combobox;
 pub mod focus;
+pub mod form;
 pub mod interactions;
 pub mod menu;
 pub mod overlay;
 pub mod popover;
+pub mod radio;
+pub mod select;
+pub mod slider;
 pub mod tooltip;
+pub mod virtualizer;
 
 pub use anchor_link::use_anchor_link;
 pub use anchor_link::UseAnchorLinkInput;
 pub use anchor_link::UseAnchorLinkProps;
 pub use anchor_link::UseAnchorLinkReturn;
+pub use async_options::use_async_options;
+pub use async_options::OptionsFuture;
+pub use async_options::OptionsLoader;
+pub use async_options::OptionsQuery;
+pub use async_options::UseAsyncOptionsInput;
+pub use async_options::UseAsyncOptionsReturn;
 pub use button::use_button;
 pub use button::UseButtonInput;
 pub use button::UseButtonProps;
 pub use button::UseButtonReturn;
+pub use combobox::use_combobox::use_combobox;
+pub use combobox::use_combobox::ComboboxState;
+pub use combobox::use_combobox::UseComboboxInput;
+pub use combobox::use_combobox::UseComboboxListboxProps;
+pub use combobox::use_combobox::UseComboboxProps;
+pub use combobox::use_combobox::UseComboboxReturn;
+pub use combobox::use_combobox_option::use_combobox_option;
+pub use combobox::use_combobox_option::UseComboboxOptionInput;
+pub use combobox::use_combobox_option::UseComboboxOptionProps;
+pub use combobox::use_combobox_option::UseComboboxOptionReturn;
 pub use focus::use_focus::use_focus;
 pub use focus::use_focus::UseFocusInput;
 pub use focus::use_focus::UseFocusProps;
 pub use focus::use_focus::UseFocusReturn;
+pub use focus::use_focus_manager::use_focus_manager;
+pub use focus::use_focus_manager::FocusManager;
+pub use focus::use_focus_manager::FocusManagerOptions;
+pub use focus::use_focus_ring::use_focus_ring;
+pub use focus::use_focus_ring::UseFocusRingInput;
+pub use focus::use_focus_ring::UseFocusRingProps;
+pub use focus::use_focus_ring::UseFocusRingReturn;
+pub use focus::use_focus_scope::use_focus_scope;
+pub use focus::use_focus_scope::UseFocusScopeInput;
+pub use focus::use_focus_scope::UseFocusScopeProps;
+pub use focus::use_focus_scope::UseFocusScopeReturn;
+pub use focus::use_focus_visible::use_focus_visible;
+pub use focus::use_focus_visible::UseFocusVisibleReturn;
+pub use focus::use_focus_within::use_focus_within;
+pub use focus::use_focus_within::UseFocusWithinInput;
+pub use focus::use_focus_within::UseFocusWithinProps;
+pub use focus::use_focus_within::UseFocusWithinReturn;
+pub use form::use_form::use_form;
+pub use form::use_form::FormFuture;
+pub use form::use_form::FormState;
+pub use form::use_form::SubmitHandler;
+pub use form::use_form::UseFormInput;
+pub use form::use_form::UseFormProps;
+pub use form::use_form::UseFormReturn;
+pub use form::use_form_field::use_form_field;
+pub use form::use_form_field::AsyncValidator;
+pub use form::use_form_field::FormField;
+pub use form::use_form_field::FormFieldStatus;
+pub use form::use_form_field::RequiredValue;
+pub use form::use_form_field::UseFormFieldInput;
+pub use form::use_form_field::ValidationError;
+pub use form::use_form_field::ValidationResult;
+pub use form::use_form_field::Validator;
 pub use interactions::use_hover::use_hover;
 pub use interactions::use_hover::HoverEndEvent;
 pub use interactions::use_hover::HoverStartEvent;
 pub use interactions::use_hover::UseHoverInput;
 pub use interactions::use_hover::UseHoverProps;
 pub use interactions::use_hover::UseHoverReturn;
+pub use interactions::use_long_press::use_long_press;
+pub use interactions::use_long_press::LongPressEvent;
+pub use interactions::use_long_press::UseLongPressDescriptionProps;
+pub use interactions::use_long_press::UseLongPressInput;
+pub use interactions::use_long_press::UseLongPressProps;
+pub use interactions::use_long_press::UseLongPressReturn;
 pub use interactions::use_move::use_move;
 pub use interactions::use_move::MoveEndEvent;
 pub use interactions::use_move::MoveEvent;
@@ -42,17 +104,96 @@ pub use interactions::use_prevent_scroll::use_prevent_scroll;
 pub use interactions::use_prevent_scroll::UsePreventScrollInput;
 pub use interactions::use_prevent_scroll::UsePreventScrollProps;
 pub use interactions::use_prevent_scroll::UsePreventScrollReturn;
+pub use menu::use_menu::use_menu;
+pub use menu::use_menu::MenuFocusStrategy;
+pub use menu::use_menu::MenuState;
+pub use menu::use_menu::UseMenuInput;
+pub use menu::use_menu::UseMenuProps;
+pub use menu::use_menu::UseMenuReturn;
+pub use menu::use_menu_item::use_menu_item;
+pub use menu::use_menu_item::UseMenuItemInput;
+pub use menu::use_menu_item::UseMenuItemProps;
+pub use menu::use_menu_item::UseMenuItemReturn;
+pub use menu::use_menu_section::use_menu_section;
+pub use menu::use_menu_section::UseMenuSectionGroupProps;
+pub use menu::use_menu_section::UseMenuSectionHeadingProps;
+pub use menu::use_menu_section::UseMenuSectionInput;
+pub use menu::use_menu_section::UseMenuSectionReturn;
+pub use menu::use_menu_section::UseMenuSectionSeparatorProps;
+pub use menu::use_menu_trigger::use_menu_trigger;
+pub use menu::use_menu_trigger::UseMenuTriggerInput;
+pub use menu::use_menu_trigger::UseMenuTriggerMenuProps;
+pub use menu::use_menu_trigger::UseMenuTriggerProps;
+pub use menu::use_menu_trigger::UseMenuTriggerReturn;
 pub use overlay::use_overlay::use_overlay;
 pub use overlay::use_overlay::UseOverlayInput;
 pub use overlay::use_overlay::UseOverlayProps;
 pub use overlay::use_overlay::UseOverlayReturn;
 pub use overlay::use_overlay_position::use_overlay_position;
+pub use overlay::use_overlay_position::OverlayBoundary;
+pub use overlay::use_overlay_position::PhysicalPlacementX;
 pub use overlay::use_overlay_position::PlacementX;
 pub use overlay::use_overlay_position::PlacementY;
+pub use overlay::use_overlay_position::UseOverlayPositionArrowProps;
 pub use overlay::use_overlay_position::UseOverlayPositionInput;
 pub use overlay::use_overlay_position::UseOverlayPositionProps;
 pub use overlay::use_overlay_position::UseOverlayPositionReturn;
+pub use overlay::use_overlay_position::OVERLAY_PADDING;
 pub use overlay::use_overlay_trigger::use_overlay_trigger;
 pub use overlay::use_overlay_trigger::UseOverlayTriggerInput;
 pub use overlay::use_overlay_trigger::UseOverlayTriggerProps;
 pub use overlay::use_overlay_trigger::UseOverlayTriggerReturn;
+pub use popover::use_popover;
+pub use popover::UsePopoverInput;
+pub use popover::UsePopoverProps;
+pub use popover::UsePopoverReturn;
+pub use popover::UsePopoverTriggerProps;
+pub use radio::use_radio::use_radio;
+pub use radio::use_radio::UseRadioInput;
+pub use radio::use_radio::UseRadioProps;
+pub use radio::use_radio::UseRadioReturn;
+pub use radio::use_radio_group::use_radio_group;
+pub use radio::use_radio_group::RadioGroupState;
+pub use radio::use_radio_group::UseRadioGroupInput;
+pub use radio::use_radio_group::UseRadioGroupProps;
+pub use radio::use_radio_group::UseRadioGroupReturn;
+pub use select::use_select::use_select;
+pub use select::use_select::SearchFilterProvider;
+pub use select::use_select::SelectRow;
+pub use select::use_select::SelectState;
+pub use select::use_select::SelectionModel;
+pub use select::use_select::UseSelectInput;
+pub use select::use_select::UseSelectListboxProps;
+pub use select::use_select::UseSelectProps;
+pub use select::use_select::UseSelectReturn;
+pub use select::use_select_option::use_select_option;
+pub use select::use_select_option::UseSelectOptionInput;
+pub use select::use_select_option::UseSelectOptionProps;
+pub use select::use_select_option::UseSelectOptionReturn;
+pub use slider::use_slider::use_slider;
+pub use slider::use_slider::SliderOrientation;
+pub use slider::use_slider::SliderState;
+pub use slider::use_slider::UseSliderInput;
+pub use slider::use_slider::UseSliderProps;
+pub use slider::use_slider::UseSliderReturn;
+pub use slider::use_slider_thumb::use_slider_thumb;
+pub use slider::use_slider_thumb::UseSliderThumbInput;
+pub use slider::use_slider_thumb::UseSliderThumbProps;
+pub use slider::use_slider_thumb::UseSliderThumbReturn;
+pub use tooltip::use_tooltip;
+pub use tooltip::use_tooltip_trigger;
+pub use tooltip::TooltipState;
+pub use tooltip::UseTooltipInput;
+pub use tooltip::UseTooltipProps;
+pub use tooltip::UseTooltipReturn;
+pub use tooltip::UseTooltipTriggerInput;
+pub use tooltip::UseTooltipTriggerProps;
+pub use tooltip::UseTooltipTriggerReturn;
+pub use tooltip::UseTooltipTriggerTooltipProps;
+pub use virtualizer::use_virtualizer;
+pub use virtualizer::UseVirtualizerInput;
+pub use virtualizer::UseVirtualizerReturn;
+pub use virtualizer::VirtualAlign;
+pub use virtualizer::VirtualItem;
+pub use virtualizer::VirtualItemSize;
+pub use virtualizer::VIRTUAL_SPACER_ATTRIBUTE;
@@ -1,9 +1,15 @@
-use std::rc::Rc;
+use std::{ops::Deref, rc::Rc};
 
 use educe::Educe;
-use leptos::{html::ElementDescriptor, Attribute, NodeRef, Oco};
-use leptos_reactive::{create_memo, MaybeSignal, SignalGet};
-use leptos_use::{use_document, use_element_bounding};
+use leptos::{ev, html::ElementDescriptor, Attribute, NodeRef, Oco};
+use leptos_reactive::{
+    create_effect, create_memo, create_trigger, MaybeSignal, Signal, SignalGet, SignalWith,
+};
+use leptos_use::{
+    use_element_bounding, use_element_bounding_with_options, use_event_listener_with_options,
+    use_window, use_window_size_with_options, UseElementBoundingOptions, UseEventListenerOptions,
+    UseWindowSizeOptions,
+};
 
 use crate::utils::{locale::WritingDirection, props::Attributes};
 
@@ -40,8 +46,38 @@ pub enum PlacementY {
     Below,
 }
 
+impl PhysicalPlacementX {
+    /// Whether the overlay is placed next to the target instead of overlapping it on this axis.
+    const fn is_outer(self) -> bool {
+        matches!(self, Self::OuterLeft | Self::OuterRight)
+    }
+
+    const fn flipped(self) -> Self {
+        match self {
+            Self::OuterLeft => Self::OuterRight,
+            Self::OuterRight => Self::OuterLeft,
+            other => other,
+        }
+    }
+}
+
+impl PlacementY {
+    /// Whether the overlay is placed next to the target instead of overlapping it on this axis.
+    const fn is_outer(self) -> bool {
+        matches!(self, Self::Above | Self::Below)
+    }
+
+    const fn flipped(self) -> Self {
+        match self {
+            Self::Above => Self::Below,
+            Self::Below => Self::Above,
+            other => other,
+        }
+    }
+}
+
 impl PlacementX {
-    fn direction_aware(self, direction: WritingDirection) -> PhysicalPlacementX {
+    const fn direction_aware(self, direction: WritingDirection) -> PhysicalPlacementX {
         match self {
             Self::OuterLeft => PhysicalPlacementX::OuterLeft,
             Self::OuterStart => match direction {
@@ -68,6 +104,24 @@ impl PlacementX {
     }
 }
 
+/// Default distance in px kept between overlays and the edges of their boundary.
+pub const OVERLAY_PADDING: f64 = 8.0;
+
+/// The area an overlay should stay inside of when being flipped or shifted.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum OverlayBoundary {
+    /// The visible area of the browser window.
+    #[default]
+    Viewport,
+
+    /// The bounding box of an element. The viewport is used as long as the element is not mounted.
+    Element(Signal<Option<web_sys::Element>>),
+
+    /// The part of the viewport that is visible through all scrolling or clipping ancestors of the target,
+    /// e.g. the visible area of a scrollable panel the target is rendered in.
+    ClippingAncestors,
+}
+
 #[derive(Clone, Copy, Educe)]
 #[educe(Debug)]
 pub struct UseOverlayPositionInput<OverlayRef, TargetRef>
@@ -87,11 +141,39 @@ where
     pub placement_y: MaybeSignal<PlacementY>,
 
     pub writing_direction: MaybeSignal<WritingDirection>,
+
+    /// Move the overlay to the opposite side of the target (e.g. `Below` -> `Above` or `OuterEnd` -> `OuterStart`)
+    /// when it would not fit into the boundary on the requested side but fits better on the other one.
+    pub flip: bool,
+
+    /// Slide the overlay along the axes on which it overlaps the target, so that it stays inside the boundary.
+    pub shift: bool,
+
+    /// Distance in px between the target and the overlay, applied on axes with an outer placement.
+    pub offset: MaybeSignal<f64>,
+
+    /// Minimal distance in px that is kept between the overlay and the edges of the boundary when flipping or shifting.
+    pub padding: MaybeSignal<f64>,
+
+    /// The area in which the overlay should stay.
+    pub boundary: OverlayBoundary,
+
+    /// Optional arrow element rendered inside the overlay. Its size is used to keep the arrow pointed at the
+    /// center of the target, even if the overlay was shifted. Spread `arrow_props` onto this element.
+    pub arrow_ref: Option<Signal<Option<web_sys::Element>>>,
 }
 
 #[derive(Debug)]
 pub struct UseOverlayPositionReturn {
     pub props: UseOverlayPositionProps,
+
+    /// The horizontal placement actually used, after flipping was applied.
+    pub placement_x: Signal<PhysicalPlacementX>,
+
+    /// The vertical placement actually used, after flipping was applied.
+    pub placement_y: Signal<PlacementY>,
+
+    pub arrow_props: UseOverlayPositionArrowProps,
 }
 
 #[derive(Debug)]
@@ -99,6 +181,302 @@ pub struct UseOverlayPositionProps {
     pub attrs: Attributes,
 }
 
+#[derive(Debug)]
+pub struct UseOverlayPositionArrowProps {
+    /// These attributes must be spread onto the arrow element: `<foo {..attrs} />`
+    /// The arrow is positioned absolutely, so the overlay must establish a containing block, e.g. through `position: fixed`.
+    pub attrs: Attributes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rect {
+    top: f64,
+    left: f64,
+    width: f64,
+    height: f64,
+}
+
+impl Rect {
+    fn bottom(&self) -> f64 {
+        self.top + self.height
+    }
+
+    fn right(&self) -> f64 {
+        self.left + self.width
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CollisionOptions {
+    flip: bool,
+    shift: bool,
+    offset: f64,
+    padding: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Position {
+    top: f64,
+    left: f64,
+    placement_x: PhysicalPlacementX,
+    placement_y: PlacementY,
+}
+
+/// Start coordinate of an overlay of `size` on one axis, given the targets `start` and `end` coordinates.
+/// `before`, `start`, `center`, `end` and `after` denote the possible placements along that axis.
+fn position_on_axis(start: f64, end: f64, size: f64, offset: f64, placement: AxisPlacement) -> f64 {
+    match placement {
+        AxisPlacement::Before => start - size - offset,
+        AxisPlacement::Start => start,
+        AxisPlacement::Center => start + ((end - start) / 2.0) - (size / 2.0),
+        AxisPlacement::End => end - size,
+        AxisPlacement::After => end + offset,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AxisPlacement {
+    Before,
+    Start,
+    Center,
+    End,
+    After,
+}
+
+impl From<PhysicalPlacementX> for AxisPlacement {
+    fn from(value: PhysicalPlacementX) -> Self {
+        match value {
+            PhysicalPlacementX::OuterLeft => Self::Before,
+            PhysicalPlacementX::Left => Self::Start,
+            PhysicalPlacementX::Center => Self::Center,
+            PhysicalPlacementX::Right => Self::End,
+            PhysicalPlacementX::OuterRight => Self::After,
+        }
+    }
+}
+
+impl From<PlacementY> for AxisPlacement {
+    fn from(value: PlacementY) -> Self {
+        match value {
+            PlacementY::Above => Self::Before,
+            PlacementY::Top => Self::Start,
+            PlacementY::Center => Self::Center,
+            PlacementY::Bottom => Self::End,
+            PlacementY::Below => Self::After,
+        }
+    }
+}
+
+/// Whether an outer placement should be flipped, because the overlay does not fit on the requested side
+/// but the opposite side offers more space.
+fn should_flip(space_requested: f64, space_opposite: f64, required: f64) -> bool {
+    space_requested < required && space_opposite > space_requested
+}
+
+/// Moves `pos` so that `[pos, pos + size]` lies within `[min, max]`. Prefers the start if it can not fit at all.
+fn shift_into(pos: f64, size: f64, min: f64, max: f64) -> f64 {
+    pos.min(max - size).max(min)
+}
+
+/// Offset of an arrow of `arrow_size` along the overlays edge, so that it points at the center of the target.
+/// The arrow never leaves the overlay, even if the overlay was shifted away from the target.
+fn arrow_offset(
+    target_start: f64,
+    target_end: f64,
+    overlay_start: f64,
+    overlay_size: f64,
+    arrow_size: f64,
+) -> f64 {
+    let target_center = target_start + ((target_end - target_start) / 2.0);
+    (target_center - overlay_start - (arrow_size / 2.0))
+        .min(overlay_size - arrow_size)
+        .max(0.0)
+}
+
+/// Whether an element with the computed `style` clips its content to its padding box.
+fn is_clipping(style: &web_sys::CssStyleDeclaration) -> bool {
+    ["overflow", "overflow-x", "overflow-y"].iter().any(|prop| {
+        style.get_property_value(prop).is_ok_and(|value| {
+            value
+                .split_whitespace()
+                .any(|v| matches!(v, "auto" | "scroll" | "overlay" | "hidden" | "clip"))
+        })
+    })
+}
+
+/// Whether an element with the computed `style` becomes the containing block of `position: fixed` descendants,
+/// which are then no longer positioned relative to the viewport.
+fn is_containing_block(style: &web_sys::CssStyleDeclaration) -> bool {
+    let prop = |name: &str| style.get_property_value(name).unwrap_or_default();
+    ["transform", "perspective", "filter", "backdrop-filter"]
+        .iter()
+        .any(|name| !matches!(prop(name).as_str(), "" | "none"))
+        || prop("will-change")
+            .split(',')
+            .any(|v| matches!(v.trim(), "transform" | "perspective" | "filter"))
+        || prop("contain")
+            .split_whitespace()
+            .any(|v| matches!(v, "paint" | "layout" | "strict" | "content"))
+}
+
+fn ancestors(el: &web_sys::Element) -> impl Iterator<Item = web_sys::Element> {
+    std::iter::successors(el.parent_element(), |el| el.parent_element())
+}
+
+fn computed_style(el: &web_sys::Element) -> Option<web_sys::CssStyleDeclaration> {
+    web_sys::window()?.get_computed_style(el).ok().flatten()
+}
+
+/// All ancestors of `el` which scroll or clip their content, nearest first.
+fn clipping_ancestors(el: &web_sys::Element) -> Vec<web_sys::Element> {
+    ancestors(el)
+        .filter(|ancestor| computed_style(ancestor).is_some_and(|style| is_clipping(&style)))
+        .collect()
+}
+
+/// The padding box of `el` in viewport coordinates. This is the area in which its content is visible.
+fn padding_box(el: &web_sys::Element) -> Rect {
+    let rect = el.get_bounding_client_rect();
+    Rect {
+        top: rect.top() + f64::from(el.client_top()),
+        left: rect.left() + f64::from(el.client_left()),
+        width: f64::from(el.client_width()),
+        height: f64::from(el.client_height()),
+    }
+}
+
+/// Origin (top, left) of the containing block `position: fixed` coordinates of `el` are relative to.
+/// This is the viewport, unless an ancestor is transformed or otherwise establishes a containing block.
+fn fixed_origin(el: &web_sys::Element) -> (f64, f64) {
+    ancestors(el)
+        .find(|ancestor| computed_style(ancestor).is_some_and(|style| is_containing_block(&style)))
+        .map_or((0.0, 0.0), |block| {
+            let padding_box = padding_box(&block);
+            (
+                padding_box.top - f64::from(block.scroll_top()),
+                padding_box.left - f64::from(block.scroll_left()),
+            )
+        })
+}
+
+fn intersect(a: Rect, b: Rect) -> Rect {
+    let top = a.top.max(b.top);
+    let left = a.left.max(b.left);
+    Rect {
+        top,
+        left,
+        width: (a.right().min(b.right()) - left).max(0.0),
+        height: (a.bottom().min(b.bottom()) - top).max(0.0),
+    }
+}
+
+fn compute_position(
+    target: Rect,
+    overlay_width: f64,
+    overlay_height: f64,
+    boundary: Rect,
+    placement_x: PhysicalPlacementX,
+    placement_y: PlacementY,
+    options: CollisionOptions,
+) -> Position {
+    let CollisionOptions {
+        flip,
+        shift,
+        offset,
+        padding,
+    } = options;
+
+    let placement_x = match placement_x {
+        PhysicalPlacementX::OuterLeft
+            if flip
+                && should_flip(
+                    target.left - boundary.left,
+                    boundary.right() - target.right(),
+                    overlay_width + offset + padding,
+                ) =>
+        {
+            placement_x.flipped()
+        }
+        PhysicalPlacementX::OuterRight
+            if flip
+                && should_flip(
+                    boundary.right() - target.right(),
+                    target.left - boundary.left,
+                    overlay_width + offset + padding,
+                ) =>
+        {
+            placement_x.flipped()
+        }
+        other => other,
+    };
+
+    let placement_y = match placement_y {
+        PlacementY::Above
+            if flip
+                && should_flip(
+                    target.top - boundary.top,
+                    boundary.bottom() - target.bottom(),
+                    overlay_height + offset + padding,
+                ) =>
+        {
+            placement_y.flipped()
+        }
+        PlacementY::Below
+            if flip
+                && should_flip(
+                    boundary.bottom() - target.bottom(),
+                    target.top - boundary.top,
+                    overlay_height + offset + padding,
+                ) =>
+        {
+            placement_y.flipped()
+        }
+        other => other,
+    };
+
+    let mut left = position_on_axis(
+        target.left,
+        target.right(),
+        overlay_width,
+        offset,
+        placement_x.into(),
+    );
+    let mut top = position_on_axis(
+        target.top,
+        target.bottom(),
+        overlay_height,
+        offset,
+        placement_y.into(),
+    );
+
+    // Shifting along an outer axis would move the overlay over the target. Only shift along the other axis.
+    if shift && !placement_x.is_outer() {
+        left = shift_into(
+            left,
+            overlay_width,
+            boundary.left + padding,
+            boundary.right() - padding,
+        );
+    }
+    if shift && !placement_y.is_outer() {
+        top = shift_into(
+            top,
+            overlay_height,
+            boundary.top + padding,
+            boundary.bottom() - padding,
+        );
+    }
+
+    Position {
+        top,
+        left,
+        placement_x,
+        placement_y,
+    }
+}
+
+// Like all hooks, this takes its input by value, even though it only copies fields out of it.
+#[allow(clippy::too_many_lines, clippy::needless_pass_by_value)]
 pub fn use_overlay_position<OverlayRef, TargetRef>(
     input: UseOverlayPositionInput<OverlayRef, TargetRef>,
 ) -> UseOverlayPositionReturn
@@ -106,101 +484,420 @@ where
     OverlayRef: ElementDescriptor + Clone + 'static,
     TargetRef: ElementDescriptor + Clone + 'static,
 {
-    let overlay_bounding = use_element_bounding(input.overlay_ref);
-    let target_bounding = use_element_bounding(input.target_ref);
+    // Scrolling is tracked through the scroll events of the targets clipping ancestors and the window only.
+    // Listening to every scroll event on the page in the capture phase is not necessary.
+    let bounding_options = || UseElementBoundingOptions::default().window_scroll(false);
+    let overlay_bounding = use_element_bounding_with_options(input.overlay_ref, bounding_options());
+    let target_bounding = use_element_bounding_with_options(input.target_ref, bounding_options());
 
-    let container_width = move || match use_document().as_ref() {
-        Some(document) => match document.body() {
-            Some(body) => body.client_width() as f64,
-            None => 0.0,
-        },
-        None => 0.0,
-    };
+    let viewport =
+        use_window_size_with_options(UseWindowSizeOptions::default().include_scrollbar(false));
 
-    let container_height = move || match use_document().as_ref() {
-        Some(document) => match document.body() {
-            Some(body) => body.client_height() as f64,
-            None => 0.0,
-        },
-        None => 0.0,
-    };
+    // Notified whenever the target or any of its clipping ancestors moved.
+    let scrolled = create_trigger();
 
-    let placement_x = create_memo(move |_| {
-        match input
-            .placement_x
+    let target_ref = input.target_ref;
+    let target_el = create_memo(move |_| {
+        target_ref
             .get()
-            .direction_aware(input.writing_direction.get())
-        {
-            original @ PhysicalPlacementX::OuterLeft => {
-                let space_left = target_bounding.left.get();
-                match overlay_bounding.width.get() > space_left {
-                    true => PhysicalPlacementX::OuterRight,
-                    false => original,
-                }
-            }
-            original @ PhysicalPlacementX::OuterRight => {
-                let space_right = container_width() - target_bounding.right.get();
-                match overlay_bounding.width.get() > space_right {
-                    true => PhysicalPlacementX::OuterLeft,
-                    false => original,
-                }
-            }
-            other => other,
-        }
+            .map(|el| web_sys::Element::from(el.into_any().deref().clone()))
+    });
+    let scroll_parents = create_memo(move |_| {
+        target_el
+            .get()
+            .map(|el| clipping_ancestors(&el))
+            .unwrap_or_default()
     });
 
-    let placement_y = create_memo(move |_| match input.placement_y.get() {
-        original @ PlacementY::Above => {
-            let space_top = target_bounding.top.get();
-            match overlay_bounding.height.get() > space_top {
-                true => PlacementY::Below,
-                false => original,
-            }
+    let on_scroll = {
+        let update_overlay = overlay_bounding.update.clone();
+        let update_target = target_bounding.update.clone();
+        move || {
+            update_overlay();
+            update_target();
+            scrolled.notify();
         }
-        original @ PlacementY::Below => {
-            let space_bottom = container_height() - target_bounding.bottom.get();
-            match overlay_bounding.height.get() > space_bottom {
-                true => PlacementY::Above,
-                false => original,
-            }
+    };
+
+    let _ = use_event_listener_with_options(
+        use_window(),
+        ev::scroll,
+        {
+            let on_scroll = on_scroll.clone();
+            move |_| on_scroll()
+        },
+        UseEventListenerOptions::default().passive(true),
+    );
+
+    // Listeners registered in an effect are removed when the effect reruns.
+    create_effect(move |_| {
+        for parent in scroll_parents.get() {
+            let on_scroll = on_scroll.clone();
+            let _ = use_event_listener_with_options(
+                parent,
+                ev::scroll,
+                move |_| on_scroll(),
+                UseEventListenerOptions::default().passive(true),
+            );
         }
-        other => other,
     });
 
-    let top = create_memo(move |_| match placement_y.get() {
-        PlacementY::Above => target_bounding.top.get() - overlay_bounding.height.get(),
-        PlacementY::Top => target_bounding.top.get(),
-        PlacementY::Center => {
-            target_bounding.top.get() + (target_bounding.height.get() / 2.0)
-                - (overlay_bounding.height.get() / 2.0)
+    let viewport_rect = move || Rect {
+        top: 0.0,
+        left: 0.0,
+        width: viewport.width.get(),
+        height: viewport.height.get(),
+    };
+
+    let boundary: Signal<Rect> = match input.boundary {
+        OverlayBoundary::Viewport => Signal::derive(viewport_rect),
+        OverlayBoundary::Element(element) => {
+            let element_bounding = use_element_bounding(element);
+            Signal::derive(move || match element.get() {
+                Some(_) => Rect {
+                    top: element_bounding.top.get(),
+                    left: element_bounding.left.get(),
+                    width: element_bounding.width.get(),
+                    height: element_bounding.height.get(),
+                },
+                None => viewport_rect(),
+            })
         }
-        PlacementY::Bottom => target_bounding.bottom.get() - overlay_bounding.height.get(),
-        PlacementY::Below => target_bounding.bottom.get(),
+        OverlayBoundary::ClippingAncestors => Signal::derive(move || {
+            scrolled.track();
+            // Also recompute when the target is resized, as this usually affects the layout of its ancestors.
+            let _ = (target_bounding.width.get(), target_bounding.height.get());
+            scroll_parents.with(|parents| {
+                parents
+                    .iter()
+                    .map(padding_box)
+                    .fold(viewport_rect(), intersect)
+            })
+        }),
+    };
+
+    let overlay_ref = input.overlay_ref;
+    let fixed_origin = create_memo(move |_| {
+        scrolled.track();
+        let _ = (viewport.width.get(), viewport.height.get());
+        overlay_ref.get().map_or((0.0, 0.0), |el| {
+            fixed_origin(&web_sys::Element::from(el.into_any().deref().clone()))
+        })
     });
 
-    let left = create_memo(move |_| match placement_x.get() {
-        PhysicalPlacementX::OuterLeft => target_bounding.left.get() - overlay_bounding.width.get(),
-        PhysicalPlacementX::Left => target_bounding.left.get(),
-        PhysicalPlacementX::Center => {
-            target_bounding.left.get() + (target_bounding.width.get() / 2.0)
-                - (overlay_bounding.width.get() / 2.0)
-        }
-        PhysicalPlacementX::Right => target_bounding.right.get() - overlay_bounding.width.get(),
-        PhysicalPlacementX::OuterRight => target_bounding.right.get(),
+    let position = create_memo(move |_| {
+        compute_position(
+            Rect {
+                top: target_bounding.top.get(),
+                left: target_bounding.left.get(),
+                width: target_bounding.width.get(),
+                height: target_bounding.height.get(),
+            },
+            overlay_bounding.width.get(),
+            overlay_bounding.height.get(),
+            boundary.get(),
+            input
+                .placement_x
+                .get()
+                .direction_aware(input.writing_direction.get()),
+            input.placement_y.get(),
+            CollisionOptions {
+                flip: input.flip,
+                shift: input.shift,
+                offset: input.offset.get(),
+                padding: input.padding.get(),
+            },
+        )
     });
 
     let mut attrs = Attributes::new();
     attrs.insert(
         "style",
         Attribute::Fn(Rc::new(move || {
-            let top = top.get();
-            let left = left.get();
+            let Position { top, left, .. } = position.get();
+            let (origin_top, origin_left) = fixed_origin.get();
+            let (top, left) = (top - origin_top, left - origin_left);
             let style = format!("position: fixed; z-index: 100000; top: {top}px; left: {left}px");
             Attribute::String(Oco::Owned(style))
         })),
     );
 
+    let arrow_size = input.arrow_ref.map(|arrow_ref| {
+        let arrow_bounding = use_element_bounding(arrow_ref);
+        (arrow_bounding.width, arrow_bounding.height)
+    });
+
+    let mut arrow_attrs = Attributes::new();
+    arrow_attrs.insert(
+        "data-placement",
+        Attribute::Fn(Rc::new(move || {
+            let Position {
+                placement_x,
+                placement_y,
+                ..
+            } = position.get();
+            Attribute::String(Oco::Borrowed(match (placement_x, placement_y) {
+                (_, PlacementY::Above) => "above",
+                (_, PlacementY::Below) => "below",
+                (PhysicalPlacementX::OuterLeft, _) => "left",
+                (PhysicalPlacementX::OuterRight, _) => "right",
+                _ => "none",
+            }))
+        })),
+    );
+    arrow_attrs.insert(
+        "style",
+        Attribute::Fn(Rc::new(move || {
+            let Position {
+                top,
+                left,
+                placement_x,
+                placement_y,
+            } = position.get();
+            let (arrow_width, arrow_height) =
+                arrow_size.map_or((0.0, 0.0), |(width, height)| (width.get(), height.get()));
+            let x = || {
+                arrow_offset(
+                    target_bounding.left.get(),
+                    target_bounding.right.get(),
+                    left,
+                    overlay_bounding.width.get(),
+                    arrow_width,
+                )
+            };
+            let y = || {
+                arrow_offset(
+                    target_bounding.top.get(),
+                    target_bounding.bottom.get(),
+                    top,
+                    overlay_bounding.height.get(),
+                    arrow_height,
+                )
+            };
+            // The arrow sits on the edge of the overlay facing the target.
+            let style = match (placement_x, placement_y) {
+                (_, PlacementY::Above) => format!("position: absolute; top: 100%; left: {}px", x()),
+                (_, PlacementY::Below) => {
+                    format!("position: absolute; bottom: 100%; left: {}px", x())
+                }
+                (PhysicalPlacementX::OuterLeft, _) => {
+                    format!("position: absolute; left: 100%; top: {}px", y())
+                }
+                (PhysicalPlacementX::OuterRight, _) => {
+                    format!("position: absolute; right: 100%; top: {}px", y())
+                }
+                // The overlay covers the target. There is nothing to point at.
+                _ => String::from("display: none"),
+            };
+            Attribute::String(Oco::Owned(style))
+        })),
+    );
+
     UseOverlayPositionReturn {
         props: UseOverlayPositionProps { attrs },
+        placement_x: Signal::derive(move || position.get().placement_x),
+        placement_y: Signal::derive(move || position.get().placement_y),
+        arrow_props: UseOverlayPositionArrowProps { attrs: arrow_attrs },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        arrow_offset, compute_position, intersect, CollisionOptions, PhysicalPlacementX,
+        PlacementY, Position, Rect,
+    };
+
+    const VIEWPORT: Rect = Rect {
+        top: 0.0,
+        left: 0.0,
+        width: 1000.0,
+        height: 800.0,
+    };
+
+    const NO_COLLISION_HANDLING: CollisionOptions = CollisionOptions {
+        flip: false,
+        shift: false,
+        offset: 0.0,
+        padding: 0.0,
+    };
+
+    const COLLISION_HANDLING: CollisionOptions = CollisionOptions {
+        flip: true,
+        shift: true,
+        offset: 0.0,
+        padding: 0.0,
+    };
+
+    fn target(top: f64, left: f64) -> Rect {
+        Rect {
+            top,
+            left,
+            width: 100.0,
+            height: 20.0,
+        }
+    }
+
+    #[test]
+    fn test_places_below_and_centered() {
+        let position = compute_position(
+            target(100.0, 450.0),
+            200.0,
+            50.0,
+            VIEWPORT,
+            PhysicalPlacementX::Center,
+            PlacementY::Below,
+            NO_COLLISION_HANDLING,
+        );
+        assert_eq!(
+            Position {
+                top: 120.0,
+                left: 400.0,
+                placement_x: PhysicalPlacementX::Center,
+                placement_y: PlacementY::Below,
+            },
+            position
+        );
+    }
+
+    #[test]
+    fn test_offset_is_applied_on_outer_axes() {
+        let position = compute_position(
+            target(100.0, 450.0),
+            200.0,
+            50.0,
+            VIEWPORT,
+            PhysicalPlacementX::OuterRight,
+            PlacementY::Above,
+            CollisionOptions {
+                offset: 8.0,
+                ..NO_COLLISION_HANDLING
+            },
+        );
+        assert_eq!(42.0, position.top);
+        assert_eq!(558.0, position.left);
+    }
+
+    #[test]
+    fn test_flips_below_to_above_at_bottom_of_viewport() {
+        let position = compute_position(
+            target(760.0, 450.0),
+            200.0,
+            50.0,
+            VIEWPORT,
+            PhysicalPlacementX::Center,
+            PlacementY::Below,
+            COLLISION_HANDLING,
+        );
+        assert_eq!(PlacementY::Above, position.placement_y);
+        assert_eq!(710.0, position.top);
+    }
+
+    #[test]
+    fn test_does_not_flip_when_opposite_side_is_worse() {
+        let position = compute_position(
+            target(10.0, 450.0),
+            200.0,
+            1000.0,
+            VIEWPORT,
+            PhysicalPlacementX::Center,
+            PlacementY::Below,
+            COLLISION_HANDLING,
+        );
+        assert_eq!(PlacementY::Below, position.placement_y);
+    }
+
+    #[test]
+    fn test_flips_outer_right_to_outer_left() {
+        let position = compute_position(
+            target(100.0, 850.0),
+            200.0,
+            50.0,
+            VIEWPORT,
+            PhysicalPlacementX::OuterRight,
+            PlacementY::Top,
+            COLLISION_HANDLING,
+        );
+        assert_eq!(PhysicalPlacementX::OuterLeft, position.placement_x);
+        assert_eq!(650.0, position.left);
+    }
+
+    #[test]
+    fn test_shifts_into_boundary_respecting_padding() {
+        let position = compute_position(
+            target(100.0, 0.0),
+            200.0,
+            50.0,
+            VIEWPORT,
+            PhysicalPlacementX::Center,
+            PlacementY::Below,
+            CollisionOptions {
+                padding: 8.0,
+                ..COLLISION_HANDLING
+            },
+        );
+        assert_eq!(8.0, position.left);
+
+        let position = compute_position(
+            target(100.0, 900.0),
+            200.0,
+            50.0,
+            VIEWPORT,
+            PhysicalPlacementX::Center,
+            PlacementY::Below,
+            CollisionOptions {
+                padding: 8.0,
+                ..COLLISION_HANDLING
+            },
+        );
+        assert_eq!(792.0, position.left);
+    }
+
+    #[test]
+    fn test_does_not_shift_along_outer_axis() {
+        let position = compute_position(
+            target(100.0, 900.0),
+            200.0,
+            50.0,
+            VIEWPORT,
+            PhysicalPlacementX::OuterRight,
+            PlacementY::Center,
+            CollisionOptions {
+                flip: false,
+                ..COLLISION_HANDLING
+            },
+        );
+        assert_eq!(1000.0, position.left);
+    }
+
+    #[test]
+    fn test_arrow_points_at_target_center() {
+        // Target spans 100..200, overlay 50..350. The 10px arrow must be centered at 150.
+        assert_eq!(95.0, arrow_offset(100.0, 200.0, 50.0, 300.0, 10.0));
+    }
+
+    #[test]
+    fn test_arrow_stays_inside_shifted_overlay() {
+        assert_eq!(290.0, arrow_offset(900.0, 1000.0, 50.0, 300.0, 10.0));
+        assert_eq!(0.0, arrow_offset(0.0, 10.0, 50.0, 300.0, 10.0));
+    }
+
+    #[test]
+    fn test_intersect_clips_to_scroll_container() {
+        let container = Rect {
+            top: 100.0,
+            left: -50.0,
+            width: 300.0,
+            height: 1000.0,
+        };
+        assert_eq!(
+            Rect {
+                top: 100.0,
+                left: 0.0,
+                width: 250.0,
+                height: 700.0,
+            },
+            intersect(VIEWPORT, container)
+        );
     }
 }
@@ -0,0 +1,239 @@
+use std::rc::Rc;
+
+use educe::Educe;
+use leptos::{ev, html::ElementDescriptor, window_event_listener, Attribute, NodeRef, Oco};
+use leptos_reactive::{
+    create_effect, on_cleanup, Callable, Callback, MaybeSignal, Signal, SignalGet,
+    SignalGetUntracked,
+};
+use wasm_bindgen::JsCast;
+use web_sys::KeyboardEvent;
+
+use crate::{
+    hooks::{
+        focus::use_focus_scope::{use_focus_scope, UseFocusScopeInput},
+        overlay::{
+            use_overlay_position::{
+                use_overlay_position, OverlayBoundary, PhysicalPlacementX, PlacementX, PlacementY,
+                UseOverlayPositionArrowProps, UseOverlayPositionInput, OVERLAY_PADDING,
+            },
+            use_overlay_trigger::{use_overlay_trigger, UseOverlayTriggerInput},
+        },
+    },
+    utils::{aria::AriaHasPopup, locale::WritingDirection, props::Attributes, NodeRefExt},
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/overlays/src/usePopover.ts
+// and: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/overlays/src/useOverlay.ts
+
+#[derive(Clone, Educe)]
+#[educe(Debug)]
+pub struct UsePopoverInput<TriggerRef, PopoverRef>
+where
+    TriggerRef: ElementDescriptor + 'static,
+    PopoverRef: ElementDescriptor + 'static,
+{
+    /// The element the popover is attached to.
+    #[educe(Debug(ignore))]
+    pub trigger_ref: NodeRef<TriggerRef>,
+
+    /// The popover element. It is positioned relative to the trigger.
+    #[educe(Debug(ignore))]
+    pub popover_ref: NodeRef<PopoverRef>,
+
+    /// Prevents the popover from being opened when true. An open popover is closed once it gets disabled.
+    pub disabled: MaybeSignal<bool>,
+
+    /// Whether the popover is currently shown.
+    pub show: Signal<bool>,
+
+    /// Called whenever the popover wants to be closed, e.g. after a click-away or a press of Escape.
+    pub on_open_change: Callback<bool>,
+
+    /// The type of popover. Prefer `AriaHasPopup::Dialog` if you are unsure what to use.
+    pub popover_type: AriaHasPopup,
+
+    /// Close the popover when the user presses anywhere outside of the trigger and the popover.
+    pub dismiss_on_click_away: bool,
+
+    /// Close the popover when the user presses Escape.
+    pub dismiss_on_escape: bool,
+
+    /// Move focus into the popover when it opens and keep Tab navigation inside of it
+    /// until it is closed. Focus is given back to the previously focused element, usually the trigger, afterwards.
+    pub contain_focus: bool,
+
+    pub placement_x: MaybeSignal<PlacementX>,
+    pub placement_y: MaybeSignal<PlacementY>,
+    pub writing_direction: MaybeSignal<WritingDirection>,
+
+    /// Distance in px between the trigger and the popover.
+    pub offset: MaybeSignal<f64>,
+
+    /// The area the popover is kept inside of when being flipped or shifted.
+    pub boundary: OverlayBoundary,
+
+    /// Optional arrow element rendered inside the popover. Spread `arrow_props` onto this element.
+    pub arrow_ref: Option<Signal<Option<web_sys::Element>>>,
+}
+
+#[derive(Debug)]
+pub struct UsePopoverReturn {
+    /// Id of the popover element.
+    pub id: Oco<'static, str>,
+
+    /// Props for the trigger element.
+    pub trigger_props: UsePopoverTriggerProps,
+
+    /// Props for the popover element.
+    pub props: UsePopoverProps,
+
+    /// The horizontal placement actually used, after the popover was flipped to fit into the viewport.
+    pub placement_x: Signal<PhysicalPlacementX>,
+
+    /// The vertical placement actually used, after the popover was flipped to fit into the viewport.
+    pub placement_y: Signal<PlacementY>,
+
+    /// Props for the arrow element, if an `arrow_ref` was given.
+    pub arrow_props: UseOverlayPositionArrowProps,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UsePopoverTriggerProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UsePopoverProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the target element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+}
+
+fn contains(el: Option<web_sys::HtmlElement>, node: Option<&web_sys::Node>) -> bool {
+    match el {
+        Some(el) => el.contains(node),
+        None => false,
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn use_popover<TriggerRef, PopoverRef>(
+    input: UsePopoverInput<TriggerRef, PopoverRef>,
+) -> UsePopoverReturn
+where
+    TriggerRef: ElementDescriptor + Clone + 'static,
+    PopoverRef: ElementDescriptor + Clone + 'static,
+{
+    let id: Oco<'static, str> = Oco::Owned(format!("popover-{}", uuid::Uuid::new_v4()));
+
+    let show = input.show;
+    let disabled = input.disabled;
+    let on_open_change = input.on_open_change;
+    let trigger_ref = input.trigger_ref;
+    let popover_ref = input.popover_ref;
+
+    let close = move || {
+        if show.get_untracked() {
+            Callable::call(&on_open_change, false);
+        }
+    };
+
+    create_effect(move |_| {
+        if disabled.get() && show.get() {
+            Callable::call(&on_open_change, false);
+        }
+    });
+
+    if input.dismiss_on_click_away {
+        let handle = window_event_listener(ev::pointerdown, move |e| {
+            if !show.get_untracked() {
+                return;
+            }
+            let target = e.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+            let inside = contains(trigger_ref.get_html_element_untracked(), target.as_ref())
+                || contains(popover_ref.get_html_element_untracked(), target.as_ref());
+            if !inside {
+                close();
+            }
+        });
+        on_cleanup(move || handle.remove());
+    }
+
+    if input.dismiss_on_escape {
+        // Handlers closer to the focused element take precedence. They can claim the event by preventing its default.
+        let handle = window_event_listener(ev::keydown, move |e| {
+            if e.key().as_str() == "Escape" && !e.default_prevented() && show.get_untracked() {
+                e.prevent_default();
+                close();
+            }
+        });
+        on_cleanup(move || handle.remove());
+    }
+
+    let contain_focus = input.contain_focus;
+    let focus_scope = use_focus_scope(UseFocusScopeInput {
+        scope_ref: popover_ref,
+        active: Signal::derive(move || contain_focus && show.get()).into(),
+        contain: contain_focus,
+        auto_focus: true,
+        restore_focus: true,
+    });
+
+    let overlay_trigger = use_overlay_trigger(UseOverlayTriggerInput {
+        show,
+        overlay_id: id.clone(),
+        overlay_type: input.popover_type,
+    });
+
+    let overlay_position = use_overlay_position(UseOverlayPositionInput {
+        overlay_ref: input.popover_ref,
+        target_ref: input.trigger_ref,
+        placement_x: input.placement_x,
+        placement_y: input.placement_y,
+        writing_direction: input.writing_direction,
+        flip: true,
+        shift: true,
+        offset: input.offset,
+        padding: OVERLAY_PADDING.into(),
+        boundary: input.boundary,
+        arrow_ref: input.arrow_ref,
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert("id", Attribute::String(id.clone()));
+    if input.popover_type == AriaHasPopup::Dialog {
+        attrs.insert("role", Attribute::String(Oco::Borrowed("dialog")));
+    }
+    if contain_focus {
+        attrs.insert("tabindex", Attribute::String(Oco::Borrowed("-1")));
+    }
+    attrs.insert(
+        "aria-hidden",
+        Attribute::Fn(Rc::new(move || match show.get() {
+            true => Attribute::Option(None),
+            false => Attribute::String(Oco::Borrowed("true")),
+        })),
+    );
+    attrs.merge(overlay_position.props.attrs);
+
+    UsePopoverReturn {
+        id,
+        trigger_props: UsePopoverTriggerProps {
+            attrs: overlay_trigger.props.attrs,
+        },
+        props: UsePopoverProps {
+            attrs,
+            on_key_down: focus_scope.props.on_key_down,
+        },
+        placement_x: overlay_position.placement_x,
+        placement_y: overlay_position.placement_y,
+        arrow_props: overlay_position.arrow_props,
+    }
+}
@@ -0,0 +1,2 @@
+pub mod use_radio;
+pub mod use_radio_group;
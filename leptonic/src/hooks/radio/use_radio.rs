@@ -0,0 +1,142 @@
+use std::{ops::Deref, rc::Rc};
+
+use educe::Educe;
+use leptos::{html::ElementDescriptor, Attribute, NodeRef, Oco};
+use leptos_reactive::{
+    create_memo, on_cleanup, store_value, MaybeSignal, Signal, SignalGet, SignalGetUntracked,
+    SignalWith,
+};
+use web_sys::{KeyboardEvent, MouseEvent};
+
+use crate::{
+    hooks::radio::use_radio_group::{RadioGroupState, RadioRegistration},
+    utils::props::Attributes,
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/radio/src/useRadio.ts
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseRadioInput<T, RadioRef>
+where
+    T: 'static,
+    RadioRef: ElementDescriptor + 'static,
+{
+    /// The radio element.
+    #[educe(Debug(ignore))]
+    pub radio_ref: NodeRef<RadioRef>,
+
+    /// State of the group this radio belongs to, as returned by `use_radio_group`.
+    pub state: RadioGroupState<T>,
+
+    /// The value the group takes when this radio is selected.
+    #[educe(Debug(ignore))]
+    pub value: T,
+
+    /// Disables this radio when true. The radio is also disabled when the whole group is.
+    pub disabled: MaybeSignal<bool>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseRadioProps {
+    /// These attributes must be spread onto the radio element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the radio element: `<foo on:click=on_click />`
+    #[educe(Debug(ignore))]
+    pub on_click: Box<dyn Fn(MouseEvent)>,
+
+    /// This handler must be attached to the radio element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+}
+
+#[derive(Debug)]
+pub struct UseRadioReturn {
+    pub props: UseRadioProps,
+
+    /// Whether this radio is the selected one of its group.
+    pub is_selected: Signal<bool>,
+
+    /// Whether this radio or its whole group is disabled.
+    pub is_disabled: Signal<bool>,
+}
+
+pub fn use_radio<T, RadioRef>(input: UseRadioInput<T, RadioRef>) -> UseRadioReturn
+where
+    T: Clone + PartialEq + 'static,
+    RadioRef: ElementDescriptor + Clone + 'static,
+{
+    let state = input.state;
+    let value = store_value(input.value);
+
+    let radio_ref = input.radio_ref;
+    let element = create_memo(move |_| radio_ref.get().map(|el| el.into_any().deref().clone()));
+
+    let radio_disabled = input.disabled;
+    let is_disabled = Signal::derive(move || state.disabled.get() || radio_disabled.get());
+    let is_selected = Signal::derive(move || {
+        state
+            .value
+            .with(|selected| value.with_value(|value| selected.as_ref() == Some(value)))
+    });
+
+    let id = uuid::Uuid::now_v7();
+    state.register(RadioRegistration {
+        id,
+        value: value.get_value(),
+        disabled: is_disabled,
+        element: element.into(),
+    });
+    on_cleanup(move || state.unregister(id));
+
+    let select = move || {
+        if !is_disabled.get_untracked() {
+            state.select(value.get_value());
+        }
+    };
+
+    let on_click = Box::new(move |_e: MouseEvent| select());
+
+    let on_key_down = Box::new(move |e: KeyboardEvent| {
+        if e.key().as_str() == " " {
+            e.prevent_default();
+            select();
+        }
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert("role", Attribute::String(Oco::Borrowed("radio")));
+    attrs.insert(
+        "tabindex",
+        Attribute::Fn(Rc::new(move || match state.tab_stop() == Some(id) {
+            true => Attribute::String(Oco::Borrowed("0")),
+            false => Attribute::String(Oco::Borrowed("-1")),
+        })),
+    );
+    attrs.insert(
+        "aria-checked",
+        Attribute::Fn(Rc::new(move || match is_selected.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::String(Oco::Borrowed("false")),
+        })),
+    );
+    attrs.insert(
+        "aria-disabled",
+        Attribute::Fn(Rc::new(move || match is_disabled.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::String(Oco::Borrowed("false")),
+        })),
+    );
+
+    UseRadioReturn {
+        props: UseRadioProps {
+            attrs,
+            on_click,
+            on_key_down,
+        },
+        is_selected,
+        is_disabled,
+    }
+}
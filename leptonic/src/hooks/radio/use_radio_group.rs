@@ -0,0 +1,283 @@
+use std::{cmp::Ordering, ops::Deref, rc::Rc};
+
+use educe::Educe;
+use leptos::{html::ElementDescriptor, Attribute, NodeRef, Oco};
+use leptos_reactive::{
+    create_memo, create_trigger, store_value, MaybeSignal, Signal, SignalGet, SignalGetUntracked,
+    SignalWith, StoredValue, Trigger,
+};
+use wasm_bindgen::JsCast;
+use web_sys::KeyboardEvent;
+
+use crate::{
+    utils::{focus::focus, locale::WritingDirection, props::Attributes, EventTargetExt},
+    Out,
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/radio/src/useRadioGroup.ts
+
+const RADIO_SELECTOR: &str = r#"[role="radio"]"#;
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseRadioGroupInput<T, GroupRef>
+where
+    T: 'static,
+    GroupRef: ElementDescriptor + 'static,
+{
+    /// The group element. All enabled radios rendered inside it are navigable in document order.
+    #[educe(Debug(ignore))]
+    pub group_ref: NodeRef<GroupRef>,
+
+    /// Value of the selected radio. `None` when no radio is selected.
+    #[educe(Debug(ignore))]
+    pub value: Signal<Option<T>>,
+
+    /// Called with the value of a radio whenever the user selects it.
+    #[educe(Debug(ignore))]
+    pub set_value: Out<Option<T>>,
+
+    /// Disables all radios of the group when true.
+    pub disabled: MaybeSignal<bool>,
+
+    /// Horizontal arrow keys follow the writing direction. In right-to-left layouts, `ArrowLeft` moves to the next radio.
+    pub writing_direction: MaybeSignal<WritingDirection>,
+}
+
+#[derive(Clone)]
+pub(super) struct RadioRegistration<T> {
+    pub(super) id: uuid::Uuid,
+    pub(super) value: T,
+    pub(super) disabled: Signal<bool>,
+    pub(super) element: Signal<Option<web_sys::HtmlElement>>,
+}
+
+/// State shared between a radio group and its radios. Pass this to `use_radio`.
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct RadioGroupState<T: 'static> {
+    #[educe(Debug(ignore))]
+    pub(super) value: Signal<Option<T>>,
+    #[educe(Debug(ignore))]
+    pub(super) set_value: Out<Option<T>>,
+    pub(super) disabled: MaybeSignal<bool>,
+    #[educe(Debug(ignore))]
+    pub(super) group: Signal<Option<web_sys::HtmlElement>>,
+    #[educe(Debug(ignore))]
+    pub(super) radios: StoredValue<Vec<RadioRegistration<T>>>,
+    #[educe(Debug(ignore))]
+    pub(super) radios_changed: Trigger,
+}
+
+impl<T: 'static> Clone for RadioGroupState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for RadioGroupState<T> {}
+
+impl<T: Clone + PartialEq + 'static> RadioGroupState<T> {
+    /// Value of the selected radio.
+    pub const fn value(&self) -> Signal<Option<T>> {
+        self.value
+    }
+
+    /// Whether the whole group is disabled. Tracked.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled.get()
+    }
+
+    /// Selects the radio with the given value, unless the group is disabled.
+    pub fn select(&self, value: T) {
+        if !self.disabled.get_untracked() {
+            self.set_value.set(Some(value));
+        }
+    }
+
+    pub(super) fn register(&self, registration: RadioRegistration<T>) {
+        self.radios.update_value(|radios| radios.push(registration));
+        self.radios_changed.notify();
+    }
+
+    pub(super) fn unregister(&self, id: uuid::Uuid) {
+        self.radios
+            .update_value(|radios| radios.retain(|r| r.id != id));
+        self.radios_changed.notify();
+    }
+
+    /// Id of the only radio reachable using Tab: The selected radio if it is enabled,
+    /// the first enabled radio in document order otherwise.
+    pub(super) fn tab_stop(&self) -> Option<uuid::Uuid> {
+        self.radios_changed.track();
+        if self.disabled.get() {
+            return None;
+        }
+        self.value.with(|value| {
+            self.radios.with_value(|radios| {
+                let enabled = radios.iter().filter(|r| !r.disabled.get());
+                let selected = enabled
+                    .clone()
+                    .find(|r| value.as_ref() == Some(&r.value))
+                    .map(|r| r.id);
+                // Radios may be registered out of document order, e.g. when rendered conditionally.
+                selected.or_else(|| {
+                    enabled
+                        .min_by(|a, b| {
+                            document_order(a.element.get().as_ref(), b.element.get().as_ref())
+                        })
+                        .map(|r| r.id)
+                })
+            })
+        })
+    }
+
+    /// Moves focus to the radio reachable using Tab, e.g. when the label of the group is pressed.
+    pub fn focus_tab_stop(&self) {
+        let Some(id) = self.tab_stop() else {
+            return;
+        };
+        let element = self.radios.with_value(|radios| {
+            radios
+                .iter()
+                .find(|r| r.id == id)
+                .and_then(|r| r.element.get_untracked())
+        });
+        if let Some(element) = element {
+            focus(&element);
+        }
+    }
+
+    /// All enabled radios of the group, in document order.
+    fn enabled_radios(&self) -> Vec<web_sys::HtmlElement> {
+        let Some(group) = self.group.get_untracked() else {
+            return Vec::new();
+        };
+        let Ok(nodes) = group.query_selector_all(RADIO_SELECTOR) else {
+            return Vec::new();
+        };
+        (0..nodes.length())
+            .filter_map(|i| nodes.item(i))
+            .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+            .filter(|el| el.get_attribute("aria-disabled").as_deref() != Some("true"))
+            .collect()
+    }
+
+    /// Focuses and selects the enabled radio following (or preceding) `from`, wrapping around at the ends of the group.
+    fn move_selection(&self, from: &web_sys::HtmlElement, forward: bool) {
+        let radios = self.enabled_radios();
+        let Some(current) = radios.iter().position(|el| el == from) else {
+            return;
+        };
+        let next = match forward {
+            true => (current + 1) % radios.len(),
+            false => (current + radios.len() - 1) % radios.len(),
+        };
+        let next = &radios[next];
+        focus(next);
+        let value = self.radios.with_value(|radios| {
+            radios
+                .iter()
+                .find(|r| r.element.get_untracked().as_ref() == Some(next))
+                .map(|r| r.value.clone())
+        });
+        if let Some(value) = value {
+            self.select(value);
+        }
+    }
+}
+
+/// Orders elements by their position in the document. Elements which are not rendered yet are considered equal.
+fn document_order(a: Option<&web_sys::HtmlElement>, b: Option<&web_sys::HtmlElement>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) if a != b => {
+            match b.compare_document_position(a) & web_sys::Node::DOCUMENT_POSITION_PRECEDING {
+                0 => Ordering::Greater,
+                _ => Ordering::Less,
+            }
+        }
+        _ => Ordering::Equal,
+    }
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseRadioGroupProps {
+    /// These attributes must be spread onto the group element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the group element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseRadioGroupReturn<T: 'static> {
+    pub props: UseRadioGroupProps,
+
+    /// Pass this to `use_radio` for every radio of this group.
+    pub state: RadioGroupState<T>,
+}
+
+// Like all hooks, this takes its input by value, even though it only copies fields out of it.
+#[allow(clippy::needless_pass_by_value)]
+pub fn use_radio_group<T, GroupRef>(
+    input: UseRadioGroupInput<T, GroupRef>,
+) -> UseRadioGroupReturn<T>
+where
+    T: Clone + PartialEq + 'static,
+    GroupRef: ElementDescriptor + Clone + 'static,
+{
+    let group_ref = input.group_ref;
+    let group = create_memo(move |_| group_ref.get().map(|el| el.into_any().deref().clone()));
+
+    let state = RadioGroupState {
+        value: input.value,
+        set_value: input.set_value,
+        disabled: input.disabled,
+        group: group.into(),
+        radios: store_value(Vec::new()),
+        radios_changed: create_trigger(),
+    };
+
+    let writing_direction = input.writing_direction;
+    let on_key_down = Box::new(move |e: KeyboardEvent| {
+        if state.disabled.get_untracked() {
+            return;
+        }
+        let forward = match (e.key().as_str(), writing_direction.get_untracked()) {
+            ("ArrowDown", _)
+            | ("ArrowRight", WritingDirection::Ltr)
+            | ("ArrowLeft", WritingDirection::Rtl) => true,
+            ("ArrowUp", _)
+            | ("ArrowLeft", WritingDirection::Ltr)
+            | ("ArrowRight", WritingDirection::Rtl) => false,
+            _ => return,
+        };
+        let Some(target) = e.target().and_then(|t| t.as_html_element()) else {
+            return;
+        };
+        if target.get_attribute("role").as_deref() != Some("radio") {
+            return;
+        }
+        e.prevent_default();
+        state.move_selection(&target, forward);
+    });
+
+    let disabled = input.disabled;
+    let mut attrs = Attributes::new();
+    attrs.insert("role", Attribute::String(Oco::Borrowed("radiogroup")));
+    attrs.insert(
+        "aria-disabled",
+        Attribute::Fn(Rc::new(move || match disabled.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::Option(None),
+        })),
+    );
+
+    UseRadioGroupReturn {
+        props: UseRadioGroupProps { attrs, on_key_down },
+        state,
+    }
+}
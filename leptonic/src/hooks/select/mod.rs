@@ -0,0 +1,2 @@
+pub mod use_select;
+pub mod use_select_option;
@@ -0,0 +1,811 @@
+use std::{fmt::Debug, rc::Rc};
+
+use educe::Educe;
+use leptos::{Attribute, Oco};
+use leptos_reactive::{
+    create_effect, create_memo, create_rw_signal, store_value, untrack, use_context, Callable,
+    MaybeSignal, Memo, RwSignal, Signal, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate,
+    SignalWith, SignalWithUntracked, StoredValue,
+};
+use wasm_bindgen::JsCast;
+use web_sys::KeyboardEvent;
+
+use crate::{
+    hooks::{use_async_options, OptionsLoader, UseAsyncOptionsInput, UseAsyncOptionsReturn},
+    prelude::{Consumer, GlobalClickEvent},
+    utils::{
+        options::default_search_filter, props::Attributes, typeahead::Typeahead, EventTargetExt,
+    },
+    Out,
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/select/src/useSelect.ts
+
+/// Filters the given options by the given search.
+pub type SearchFilterProvider<O> = Consumer<(String, Vec<O>), Vec<O>>;
+
+/// How the options of a select are selected.
+pub enum SelectionModel<O: 'static> {
+    /// Exactly one option is selected at all times.
+    Single {
+        selected: Signal<O>,
+        set_selected: Out<O>,
+    },
+
+    /// At most one option is selected.
+    Optional {
+        selected: Signal<Option<O>>,
+        set_selected: Out<Option<O>>,
+    },
+
+    /// Up to `max` options are selected. Newly selected options are appended.
+    Multiple {
+        selected: Signal<Vec<O>>,
+        set_selected: Out<Vec<O>>,
+        max: u64,
+    },
+}
+
+impl<O: 'static> Debug for SelectionModel<O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Single { .. } => f.write_str("Single"),
+            Self::Optional { .. } => f.write_str("Optional"),
+            Self::Multiple { max, .. } => f
+                .debug_struct("Multiple")
+                .field("max", max)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl<O: 'static> Clone for SelectionModel<O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O: 'static> Copy for SelectionModel<O> {}
+
+impl<O: Clone + PartialEq + 'static> SelectionModel<O> {
+    /// All selected options. Tracked.
+    pub fn selected(&self) -> Vec<O> {
+        match self {
+            Self::Single { selected, .. } => vec![selected.get()],
+            Self::Optional { selected, .. } => selected.get().into_iter().collect(),
+            Self::Multiple { selected, .. } => selected.get(),
+        }
+    }
+
+    /// Whether the given option is selected. Tracked.
+    pub fn is_selected(&self, option: &O) -> bool {
+        match self {
+            Self::Single { selected, .. } => selected.with(|selected| selected == option),
+            Self::Optional { selected, .. } => {
+                selected.with(|selected| selected.as_ref() == Some(option))
+            }
+            Self::Multiple { selected, .. } => selected.with(|selected| selected.contains(option)),
+        }
+    }
+
+    /// Whether no further option can be selected without deselecting another one first. Tracked.
+    pub fn is_full(&self) -> bool {
+        match self {
+            Self::Single { .. } | Self::Optional { .. } => false,
+            Self::Multiple { selected, max, .. } => {
+                selected.with(|selected| selected.len() as u64 >= *max)
+            }
+        }
+    }
+
+    fn select(&self, option: O) {
+        match self {
+            Self::Single { set_selected, .. } => set_selected.set(option),
+            Self::Optional { set_selected, .. } => set_selected.set(Some(option)),
+            Self::Multiple {
+                selected,
+                set_selected,
+                ..
+            } => {
+                let mut options = selected.get_untracked();
+                if !options.contains(&option) {
+                    options.push(option);
+                }
+                set_selected.set(options);
+            }
+        }
+    }
+
+    fn deselect(&self, option: &O) {
+        match self {
+            Self::Single { .. } => {}
+            Self::Optional {
+                selected,
+                set_selected,
+            } => {
+                if selected.with_untracked(|selected| selected.as_ref() == Some(option)) {
+                    set_selected.set(None);
+                }
+            }
+            Self::Multiple {
+                selected,
+                set_selected,
+                ..
+            } => {
+                let mut options = selected.get_untracked();
+                options.retain(|it| it != option);
+                set_selected.set(options);
+            }
+        }
+    }
+
+    fn clear(&self) {
+        match self {
+            Self::Single { .. } => {}
+            Self::Optional { set_selected, .. } => set_selected.set(None),
+            Self::Multiple { set_selected, .. } => set_selected.set(Vec::new()),
+        }
+    }
+}
+
+/// An entry in the list of options of a select.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectRow<O> {
+    /// Header of a group of options, followed by the options of that group.
+    Group(String),
+    Option(O),
+}
+
+impl<O> SelectRow<O> {
+    pub const fn option(&self) -> Option<&O> {
+        match self {
+            Self::Group(_) => None,
+            Self::Option(option) => Some(option),
+        }
+    }
+}
+
+/// Orders `options` by their group, placing a header in front of each group.
+/// Groups keep the order in which they first appear. Options without a group come first and get no header.
+fn group_rows<O>(options: Vec<O>, group_of: impl Fn(&O) -> Option<String>) -> Vec<SelectRow<O>> {
+    let mut ungrouped = Vec::new();
+    let mut groups = Vec::<(String, Vec<O>)>::new();
+    for option in options {
+        match group_of(&option) {
+            None => ungrouped.push(SelectRow::Option(option)),
+            Some(group) => match groups.iter_mut().find(|(name, _)| *name == group) {
+                Some((_, members)) => members.push(option),
+                None => groups.push((group, vec![option])),
+            },
+        }
+    }
+    ungrouped
+        .into_iter()
+        .chain(groups.into_iter().flat_map(|(group, members)| {
+            std::iter::once(SelectRow::Group(group))
+                .chain(members.into_iter().map(SelectRow::Option))
+        }))
+        .collect()
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseSelectInput<O: 'static> {
+    pub selection: SelectionModel<O>,
+
+    /// All options. Ignored when a `loader` is given.
+    #[educe(Debug(ignore))]
+    pub options: MaybeSignal<Vec<O>>,
+
+    /// Loads the options matching the current search asynchronously while the options are shown.
+    pub loader: Option<OptionsLoader<O>>,
+
+    /// Milliseconds the search must stay unchanged before options are loaded through the `loader`.
+    pub search_debounce: f64,
+
+    /// Number of options loaded through the `loader` at once.
+    pub page_size: usize,
+
+    /// Text of an option, used to filter options by the search and for typeahead.
+    #[educe(Debug(ignore))]
+    pub search_text_provider: Consumer<O, String>,
+
+    /// Filters the options by the search. Defaults to a case-insensitive substring search on the text of each option.
+    #[educe(Debug(ignore))]
+    pub search_filter_provider: Option<SearchFilterProvider<O>>,
+
+    /// Options for which this returns true are shown, but can not be selected.
+    #[educe(Debug(ignore))]
+    pub disabled_provider: Option<Consumer<O, bool>>,
+
+    /// Groups options below a header naming their group.
+    #[educe(Debug(ignore))]
+    pub group_provider: Option<Consumer<O, Option<String>>>,
+
+    /// Lets the user create a new option from the search text, when no option matches it exactly.
+    #[educe(Debug(ignore))]
+    pub on_create: Option<Out<String>>,
+
+    /// Whether the options should be closed after an option was selected.
+    pub close_on_select: bool,
+}
+
+/// State of a select. Use it to render the options and the selection, and pass it to `use_select_option` for every option.
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct SelectState<O: 'static> {
+    id: StoredValue<Oco<'static, str>>,
+    selection: SelectionModel<O>,
+    show_options: RwSignal<bool>,
+    #[educe(Debug(ignore))]
+    search: RwSignal<String>,
+    #[educe(Debug(ignore))]
+    rows: Memo<Vec<SelectRow<O>>>,
+    #[educe(Debug(ignore))]
+    options: Memo<Vec<O>>,
+    #[educe(Debug(ignore))]
+    preselected: RwSignal<Option<O>>,
+    #[educe(Debug(ignore))]
+    memoized_preselected: Memo<Option<O>>,
+    can_create: Memo<bool>,
+    async_options: Option<UseAsyncOptionsReturn<O>>,
+    #[educe(Debug(ignore))]
+    search_text_provider: Consumer<O, String>,
+    #[educe(Debug(ignore))]
+    disabled_provider: Option<Consumer<O, bool>>,
+    #[educe(Debug(ignore))]
+    on_create: Option<Out<String>>,
+    close_on_select: bool,
+    typeahead: StoredValue<Typeahead>,
+}
+
+impl<O: 'static> Clone for SelectState<O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O: 'static> Copy for SelectState<O> {}
+
+impl<O: Debug + Clone + PartialEq + 'static> SelectState<O> {
+    /// Id of the element receiving the `props` of `use_select`.
+    pub fn id(&self) -> Oco<'static, str> {
+        self.id.get_value()
+    }
+
+    /// Id of the element receiving the `listbox_props` of `use_select`.
+    pub fn listbox_id(&self) -> String {
+        format!("{}-listbox", self.id.get_value())
+    }
+
+    /// Id of the preselected option, referenced by `aria-activedescendant`.
+    pub(super) fn active_option_id(&self) -> String {
+        format!("{}-active", self.id.get_value())
+    }
+
+    pub const fn selection(&self) -> SelectionModel<O> {
+        self.selection
+    }
+
+    /// Whether the options are shown. Tracked.
+    pub fn is_open(&self) -> bool {
+        self.show_options.get()
+    }
+
+    pub fn open(&self) {
+        self.show_options.set(true);
+    }
+
+    pub fn close(&self) {
+        self.show_options.set(false);
+    }
+
+    pub fn toggle(&self) {
+        self.show_options.update(|shown| *shown = !*shown);
+    }
+
+    /// The text the options are currently filtered by.
+    pub const fn search(&self) -> RwSignal<String> {
+        self.search
+    }
+
+    /// Options matching the search, ordered by their group and interleaved with group headers.
+    pub const fn rows(&self) -> Memo<Vec<SelectRow<O>>> {
+        self.rows
+    }
+
+    /// Options matching the search, in the order they are shown.
+    pub const fn options(&self) -> Memo<Vec<O>> {
+        self.options
+    }
+
+    /// Whether any option matches the search. Tracked.
+    pub fn has_options(&self) -> bool {
+        !self.options.with(Vec::is_empty)
+    }
+
+    /// Whether options are currently loaded through the `loader`. Tracked.
+    pub fn is_loading(&self) -> bool {
+        self.async_options
+            .is_some_and(|async_options| async_options.loading.get())
+    }
+
+    /// Loads the next page of options through the `loader`, if any.
+    pub fn load_more(&self) {
+        if let Some(async_options) = self.async_options {
+            Callable::call(&async_options.load_more, ());
+        }
+    }
+
+    /// Whether the given option is selected. Tracked.
+    pub fn is_selected(&self, option: &O) -> bool {
+        self.selection.is_selected(option)
+    }
+
+    /// Whether the given option is disabled, either explicitly or because no further options can be selected. Tracked.
+    pub fn is_disabled(&self, option: &O) -> bool {
+        self.disabled_provider
+            .is_some_and(|disabled_provider| disabled_provider.consume(option.clone()))
+            || (self.selection.is_full() && !self.selection.is_selected(option))
+    }
+
+    /// Whether selecting the given option would change the selection. Tracked.
+    pub fn is_selectable(&self, option: &O) -> bool {
+        !self.is_selected(option) && !self.is_disabled(option)
+    }
+
+    /// Selects the given option, unless it is not selectable.
+    pub fn select(&self, option: O) {
+        if untrack(|| self.is_selectable(&option)) {
+            self.selection.select(option);
+            if self.close_on_select {
+                self.close();
+            }
+        }
+    }
+
+    pub fn deselect(&self, option: &O) {
+        self.selection.deselect(option);
+    }
+
+    /// Deselects all options. Does nothing when exactly one option must be selected.
+    pub fn clear(&self) {
+        self.selection.clear();
+    }
+
+    /// The option highlighted for selection with the keyboard.
+    pub fn preselected(&self) -> Signal<Option<O>> {
+        self.memoized_preselected.into()
+    }
+
+    /// Whether the given option is highlighted for selection with the keyboard. Tracked.
+    pub fn is_preselected(&self, option: &O) -> bool {
+        self.memoized_preselected
+            .with(|preselected| preselected.as_ref() == Some(option))
+    }
+
+    pub fn preselect(&self, option: O) {
+        self.preselected.set(Some(option));
+    }
+
+    /// Whether the preselected option matches the search and is therefore shown. Tracked.
+    pub fn preselected_is_shown(&self) -> bool {
+        self.memoized_preselected.with(|preselected| {
+            preselected.as_ref().is_some_and(|preselected| {
+                self.options.with(|options| options.contains(preselected))
+            })
+        })
+    }
+
+    /// Index of the preselected option in the `rows`. Tracked.
+    pub fn preselected_index(&self) -> Option<usize> {
+        self.memoized_preselected.with(|preselected| {
+            let preselected = preselected.as_ref()?;
+            self.rows.with(|rows| {
+                rows.iter()
+                    .position(|row| row.option() == Some(preselected))
+            })
+        })
+    }
+
+    /// Preselects the shown, enabled option at the index chosen by `pick`, given all these options and the index of the current preselection.
+    fn preselect_by(&self, pick: impl Fn(&[O], Option<usize>) -> Option<usize>) {
+        let enabled = untrack(|| {
+            self.options.with(|options| {
+                options
+                    .iter()
+                    .filter(|option| !self.is_disabled(option))
+                    .cloned()
+                    .collect::<Vec<O>>()
+            })
+        });
+        let current = self.preselected.with_untracked(|preselected| {
+            preselected
+                .as_ref()
+                .and_then(|preselected| enabled.iter().position(|it| it == preselected))
+        });
+        if let Some(option) = pick(&enabled, current).and_then(|i| enabled.get(i)) {
+            self.preselected.set(Some(option.clone()));
+        }
+    }
+
+    /// Preselects the next enabled option, wrapping around at the end.
+    pub fn preselect_next(&self) {
+        self.preselect_by(|options, current| match current {
+            Some(current) if current + 1 < options.len() => Some(current + 1),
+            Some(_) | None => (!options.is_empty()).then_some(0),
+        });
+    }
+
+    /// Preselects the previous enabled option, wrapping around at the start.
+    pub fn preselect_previous(&self) {
+        self.preselect_by(|options, current| match current {
+            Some(current) if current > 0 => Some(current - 1),
+            Some(_) | None => options.len().checked_sub(1),
+        });
+    }
+
+    pub fn preselect_first(&self) {
+        self.preselect_by(|options, _| (!options.is_empty()).then_some(0));
+    }
+
+    pub fn preselect_last(&self) {
+        self.preselect_by(|options, _| options.len().checked_sub(1));
+    }
+
+    /// Preselects the next enabled option whose text starts with the characters typed in quick succession.
+    fn preselect_matching(&self, key: &str, now: f64) {
+        let mut search = String::new();
+        self.typeahead
+            .update_value(|typeahead| typeahead.push(key, now).clone_into(&mut search));
+        let search_text_provider = self.search_text_provider;
+        self.preselect_by(|options, current| {
+            // Start searching at the current option, so that repeatedly typing the same letter cycles through all matches.
+            let start = current.map_or(0, |current| match search.chars().count() {
+                1 => current + 1,
+                _ => current,
+            });
+            (0..options.len())
+                .map(|offset| (start + offset) % options.len())
+                .find(|i| {
+                    search_text_provider
+                        .consume(options[*i].clone())
+                        .trim()
+                        .to_lowercase()
+                        .starts_with(search.as_str())
+                })
+        });
+    }
+
+    /// Whether a new option can be created from the search. Tracked.
+    pub fn can_create(&self) -> bool {
+        self.can_create.get()
+    }
+
+    /// Emits the search through `on_create`, if a new option can be created from it.
+    pub fn create(&self) {
+        if let Some(on_create) = self.on_create {
+            on_create.set(self.search.get_untracked().trim().to_owned());
+            self.search.set(String::new());
+            self.close();
+        }
+    }
+
+    /// Selects the preselected option if it is shown. Creates a new option from the search otherwise, if possible.
+    pub fn activate_preselected(&self) {
+        let shown = untrack(|| {
+            self.preselected
+                .get()
+                .filter(|preselected| self.options.with(|options| options.contains(preselected)))
+        });
+        match shown {
+            Some(preselected) => self.select(preselected),
+            None => {
+                if self.can_create.get_untracked() {
+                    self.create();
+                }
+            }
+        }
+    }
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseSelectProps {
+    /// These attributes must be spread onto the focusable element containing the trigger and the options: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the same element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+}
+
+#[derive(Debug)]
+pub struct UseSelectListboxProps {
+    /// These attributes must be spread onto the element containing the options: `<foo {..attrs} />`
+    pub attrs: Attributes,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseSelectReturn<O: 'static> {
+    pub props: UseSelectProps,
+
+    pub listbox_props: UseSelectListboxProps,
+
+    /// Pass this to `use_select_option` for every option.
+    pub state: SelectState<O>,
+}
+
+/// Whether the keyboard event targets a text input, in which typed characters must not be used for typeahead.
+fn targets_text_input(e: &KeyboardEvent) -> bool {
+    e.target()
+        .and_then(|target| target.as_element())
+        .is_some_and(|el| el.tag_name().eq_ignore_ascii_case("input"))
+}
+
+/// # Panics
+///
+/// Panics when not used inside of the `Root` component.
+#[allow(clippy::too_many_lines)]
+pub fn use_select<O>(input: UseSelectInput<O>) -> UseSelectReturn<O>
+where
+    O: Debug + Clone + PartialEq + 'static,
+{
+    let id: Oco<'static, str> = Oco::Owned(format!("s-{}", uuid::Uuid::new_v4()));
+
+    let show_options = create_rw_signal(false);
+    let search = create_rw_signal(String::new());
+    let preselected = create_rw_signal(Option::<O>::None);
+    let memoized_preselected = create_memo(move |_| preselected.get());
+
+    let search_text_provider = input.search_text_provider;
+    let search_filter_provider = input
+        .search_filter_provider
+        .unwrap_or_else(|| default_search_filter(search_text_provider));
+
+    let async_options = input.loader.map(|loader| {
+        use_async_options(UseAsyncOptionsInput {
+            loader,
+            search: search.into(),
+            enabled: show_options.into(),
+            debounce_ms: input.search_debounce,
+            page_size: input.page_size,
+        })
+    });
+
+    let stored_options = store_value(input.options);
+    let group_provider = input.group_provider;
+    let rows = create_memo(move |_| {
+        let options = match async_options {
+            Some(async_options) => async_options.options.get(),
+            None => {
+                search_filter_provider.consume((search.get(), stored_options.get_value().get()))
+            }
+        };
+        match group_provider {
+            Some(group_provider) => {
+                group_rows(options, |option| group_provider.consume(option.clone()))
+            }
+            None => options.into_iter().map(SelectRow::Option).collect(),
+        }
+    });
+
+    let options = create_memo(move |_| {
+        rows.with(|rows| {
+            rows.iter()
+                .filter_map(SelectRow::option)
+                .cloned()
+                .collect::<Vec<O>>()
+        })
+    });
+
+    // A new option can only be created when no shown option matches the search exactly.
+    let on_create = input.on_create;
+    let can_create = create_memo(move |_| {
+        on_create.is_some()
+            && search.with(|search| {
+                let search = search.trim();
+                !search.is_empty()
+                    && options.with(|options| {
+                        !options.iter().any(|option| {
+                            search_text_provider
+                                .consume(option.clone())
+                                .trim()
+                                .eq_ignore_ascii_case(search)
+                        })
+                    })
+            })
+    });
+
+    let state = SelectState {
+        id: store_value(id.clone()),
+        selection: input.selection,
+        show_options,
+        search,
+        rows,
+        options,
+        preselected,
+        memoized_preselected,
+        can_create,
+        async_options,
+        search_text_provider,
+        disabled_provider: input.disabled_provider,
+        on_create,
+        close_on_select: input.close_on_select,
+        typeahead: store_value(Typeahead::default()),
+    };
+
+    // The options are closed when the user clicks anywhere outside of the select.
+    let g_mouse_event =
+        use_context::<GlobalClickEvent>().expect("Must be a child of the Root component.");
+    let id_selector = format!("#{id}");
+    create_effect(move |_| {
+        let last_mouse_event = g_mouse_event.read_signal.get();
+        if !show_options.get_untracked() {
+            return;
+        }
+        let Some(target) = last_mouse_event
+            .and_then(|e| e.target())
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        else {
+            return;
+        };
+        match target.closest(&id_selector) {
+            Ok(Some(_)) => {}
+            Ok(None) => state.close(),
+            Err(err) => tracing::error!("Error processing latest mouse event: {err:?}"),
+        }
+    });
+
+    let on_key_down = Box::new(move |e: KeyboardEvent| {
+        let key = e.key();
+        let is_typeahead = key.chars().count() == 1
+            && !e.ctrl_key()
+            && !e.meta_key()
+            && !e.alt_key()
+            && !targets_text_input(&e);
+        match (show_options.get_untracked(), key.as_str()) {
+            (true, "Escape") => {
+                e.prevent_default();
+                e.stop_propagation();
+                state.close();
+            }
+            (true, "Backspace") if !targets_text_input(&e) => state.close(),
+            (true, "ArrowUp") => {
+                e.prevent_default();
+                e.stop_propagation();
+                state.preselect_previous();
+            }
+            (true, "ArrowDown") => {
+                e.prevent_default();
+                e.stop_propagation();
+                state.preselect_next();
+            }
+            (true, "Home") if !targets_text_input(&e) => {
+                e.prevent_default();
+                state.preselect_first();
+            }
+            (true, "End") if !targets_text_input(&e) => {
+                e.prevent_default();
+                state.preselect_last();
+            }
+            (true, "Enter") => {
+                e.prevent_default();
+                e.stop_propagation();
+                state.activate_preselected();
+            }
+            (false, "Enter" | "ArrowDown") => {
+                e.prevent_default();
+                e.stop_propagation();
+                state.open();
+            }
+            (_, key) if is_typeahead => {
+                // Space is only part of the search if a search is already in progress.
+                let now = js_sys::Date::now();
+                if key == " " && !state.typeahead.with_value(|t| t.is_in_progress(now)) {
+                    return;
+                }
+                e.prevent_default();
+                e.stop_propagation();
+                state.open();
+                state.preselect_matching(key, now);
+            }
+            _ => {}
+        }
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert("id", Attribute::String(id));
+    attrs.insert("role", Attribute::String(Oco::Borrowed("combobox")));
+    attrs.insert("aria-haspopup", Attribute::String(Oco::Borrowed("listbox")));
+    attrs.insert(
+        "aria-controls",
+        Attribute::String(Oco::Owned(state.listbox_id())),
+    );
+    attrs.insert(
+        "aria-expanded",
+        Attribute::Fn(Rc::new(move || match show_options.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::String(Oco::Borrowed("false")),
+        })),
+    );
+    attrs.insert(
+        "aria-activedescendant",
+        Attribute::Fn(Rc::new(move || {
+            match show_options.get() && state.preselected_is_shown() {
+                true => Attribute::String(Oco::Owned(state.active_option_id())),
+                false => Attribute::Option(None),
+            }
+        })),
+    );
+
+    let mut listbox_attrs = Attributes::new();
+    listbox_attrs.insert("id", Attribute::String(Oco::Owned(state.listbox_id())));
+    listbox_attrs.insert("role", Attribute::String(Oco::Borrowed("listbox")));
+    if let SelectionModel::Multiple { .. } = state.selection {
+        listbox_attrs.insert(
+            "aria-multiselectable",
+            Attribute::String(Oco::Borrowed("true")),
+        );
+    }
+    listbox_attrs.insert(
+        "aria-busy",
+        Attribute::Fn(Rc::new(move || match state.is_loading() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::Option(None),
+        })),
+    );
+
+    UseSelectReturn {
+        props: UseSelectProps { attrs, on_key_down },
+        listbox_props: UseSelectListboxProps {
+            attrs: listbox_attrs,
+        },
+        state,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_rows, SelectRow::Group, SelectRow::Option};
+
+    #[test]
+    fn places_ungrouped_options_first() {
+        let group_of = |n: &u32| (*n >= 10).then(|| String::from("Large"));
+        assert_eq!(
+            group_rows(vec![12, 1, 15, 2], group_of),
+            vec![
+                Option(1),
+                Option(2),
+                Group(String::from("Large")),
+                Option(12),
+                Option(15)
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_groups_in_order_of_first_appearance() {
+        let group_of = |word: &&str| Some(word[..1].to_owned());
+        assert_eq!(
+            group_rows(vec!["banana", "apple", "blueberry", "avocado"], group_of),
+            vec![
+                Group(String::from("b")),
+                Option("banana"),
+                Option("blueberry"),
+                Group(String::from("a")),
+                Option("apple"),
+                Option("avocado"),
+            ]
+        );
+    }
+
+    #[test]
+    fn adds_no_headers_without_groups() {
+        assert_eq!(
+            group_rows(vec![3, 1, 2], |_| None),
+            vec![Option(3), Option(1), Option(2)]
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use std::{fmt::Debug, rc::Rc};
+
+use educe::Educe;
+use leptos::{Attribute, Oco};
+use leptos_reactive::store_value;
+use web_sys::MouseEvent;
+
+use crate::utils::props::Attributes;
+
+use super::use_select::SelectState;
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/listbox/src/useOption.ts
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseSelectOptionInput<O: 'static> {
+    /// The state of the select this option belongs to, as returned by `use_select`.
+    pub select: SelectState<O>,
+
+    #[educe(Debug(ignore))]
+    pub option: O,
+}
+
+#[derive(Debug)]
+pub struct UseSelectOptionReturn {
+    pub props: UseSelectOptionProps,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseSelectOptionProps {
+    /// These attributes must be spread onto the option element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the option element: `<foo on:click=on_click />`
+    #[educe(Debug(ignore))]
+    pub on_click: Box<dyn Fn(MouseEvent)>,
+
+    /// This handler must be attached to the option element: `<foo on:mouseenter=on_mouse_enter />`
+    #[educe(Debug(ignore))]
+    pub on_mouse_enter: Box<dyn Fn(MouseEvent)>,
+}
+
+pub fn use_select_option<O>(input: UseSelectOptionInput<O>) -> UseSelectOptionReturn
+where
+    O: Debug + Clone + PartialEq + 'static,
+{
+    let select = input.select;
+    let option = store_value(input.option);
+
+    let mut attrs = Attributes::new();
+    attrs.insert("role", Attribute::String(Oco::Borrowed("option")));
+    // Only the preselected option needs an id, as it is referenced through `aria-activedescendant`.
+    attrs.insert(
+        "id",
+        Attribute::Fn(Rc::new(move || {
+            match option.with_value(|option| select.is_preselected(option)) {
+                true => Attribute::String(Oco::Owned(select.active_option_id())),
+                false => Attribute::Option(None),
+            }
+        })),
+    );
+    attrs.insert(
+        "aria-selected",
+        Attribute::Fn(Rc::new(move || {
+            match option.with_value(|option| select.is_selected(option)) {
+                true => Attribute::String(Oco::Borrowed("true")),
+                false => Attribute::String(Oco::Borrowed("false")),
+            }
+        })),
+    );
+    attrs.insert(
+        "aria-disabled",
+        Attribute::Fn(Rc::new(move || {
+            match option.with_value(|option| select.is_disabled(option)) {
+                true => Attribute::String(Oco::Borrowed("true")),
+                false => Attribute::Option(None),
+            }
+        })),
+    );
+
+    UseSelectOptionReturn {
+        props: UseSelectOptionProps {
+            attrs,
+            on_click: Box::new(move |_e| select.select(option.get_value())),
+            on_mouse_enter: Box::new(move |_e| select.preselect(option.get_value())),
+        },
+    }
+}
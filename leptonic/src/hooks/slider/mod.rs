@@ -0,0 +1,2 @@
+pub mod use_slider;
+pub mod use_slider_thumb;
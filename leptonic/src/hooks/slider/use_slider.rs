@@ -0,0 +1,334 @@
+use std::{ops::Deref, rc::Rc};
+
+use educe::Educe;
+use leptos::{ev, html::ElementDescriptor, window_event_listener, Attribute, NodeRef, Oco};
+use leptos_reactive::{
+    create_memo, create_rw_signal, on_cleanup, store_value, Callable, Callback, MaybeSignal,
+    RwSignal, Signal, SignalGet, SignalGetUntracked, SignalSet, StoredValue,
+};
+use web_sys::PointerEvent;
+
+use crate::utils::{focus::focus, props::Attributes};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/slider/src/useSlider.ts
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SliderOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl SliderOrientation {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Educe)]
+#[educe(Debug)]
+pub struct UseSliderInput<TrackRef>
+where
+    TrackRef: ElementDescriptor + 'static,
+{
+    /// The track element. Pointer positions are projected onto this element
+    /// to determine the value they represent.
+    #[educe(Debug(ignore))]
+    pub track_ref: NodeRef<TrackRef>,
+
+    /// The value at the start of the track (left or bottom). May be greater than `max`.
+    pub min: f64,
+
+    /// The value at the end of the track (right or top). May be less than `min`.
+    pub max: f64,
+
+    /// All values are rounded to a multiple of `step` when set. Keyboard interactions use a hundredth
+    /// of the range when no step is given.
+    pub step: Option<f64>,
+
+    pub orientation: SliderOrientation,
+
+    /// Disables all interactions when true.
+    pub disabled: MaybeSignal<bool>,
+}
+
+#[derive(Clone, Copy, Educe)]
+#[educe(Debug)]
+pub(super) struct SliderThumbRegistration {
+    pub(super) value: Signal<f64>,
+    #[educe(Debug(ignore))]
+    pub(super) set_value: Callback<f64>,
+    #[educe(Debug(ignore))]
+    pub(super) element: Signal<Option<web_sys::HtmlElement>>,
+}
+
+/// State shared between a slider and its thumbs. Pass this to `use_slider_thumb`.
+#[derive(Clone, Copy, Educe)]
+#[educe(Debug)]
+pub struct SliderState {
+    pub(super) min: f64,
+    pub(super) max: f64,
+    pub(super) step: Option<f64>,
+    pub(super) orientation: SliderOrientation,
+    pub(super) disabled: MaybeSignal<bool>,
+    #[educe(Debug(ignore))]
+    pub(super) track: Signal<Option<web_sys::Element>>,
+    pub(super) thumbs: StoredValue<Vec<Option<SliderThumbRegistration>>>,
+    pub(super) dragged_thumb: RwSignal<Option<usize>>,
+}
+
+impl SliderState {
+    pub const fn orientation(&self) -> SliderOrientation {
+        self.orientation
+    }
+
+    /// Index of the thumb currently dragged using a pointer.
+    pub fn dragged_thumb(&self) -> Signal<Option<usize>> {
+        self.dragged_thumb.into()
+    }
+
+    /// Position of `value` on the track, in 0..1 range. Values outside of the sliders range are clipped.
+    pub fn percent(&self, value: f64) -> f64 {
+        let range = self.max - self.min;
+        if range == 0.0 {
+            return 0.0;
+        }
+        ((value - self.min) / range).clamp(0.0, 1.0)
+    }
+
+    /// Rounds `value` to the nearest step (if any) and clips it into the sliders range.
+    pub fn snap(&self, value: f64) -> f64 {
+        let snapped = match self.step {
+            Some(step) if step > 0.0 => (value / step).round() * step,
+            _ => value,
+        };
+        snapped.clamp(self.min.min(self.max), self.min.max(self.max))
+    }
+
+    /// The value at position `percent` (0..1) of the track, snapped to the nearest step.
+    pub fn value_at(&self, percent: f64) -> f64 {
+        self.snap(percent.mul_add(self.max - self.min, self.min))
+    }
+
+    /// Amount by which arrow keys change the value, in the direction from `min` to `max`.
+    pub(super) fn key_step(&self) -> f64 {
+        let range = self.max - self.min;
+        match self.step {
+            Some(step) if step > 0.0 => step * range.signum(),
+            _ => range / 100.0,
+        }
+    }
+
+    /// Amount by which PageUp/PageDown change the value, in the direction from `min` to `max`.
+    /// This is a tenth of the range, rounded to a multiple of `step`.
+    pub(super) fn page_step(&self) -> f64 {
+        let range = self.max - self.min;
+        match self.step {
+            Some(step) if step > 0.0 => {
+                let steps = (range.abs() / 10.0 / step).round().max(1.0);
+                steps * step * range.signum()
+            }
+            _ => range / 10.0,
+        }
+    }
+
+    fn thumb(&self, index: usize) -> Option<SliderThumbRegistration> {
+        self.thumbs
+            .with_value(|thumbs| thumbs.get(index).copied().flatten())
+    }
+
+    /// Area of the track (0..1) thumb `index` may be moved in. Thumbs can not be moved past their neighbors.
+    pub(super) fn thumb_bounds(&self, index: usize) -> (f64, f64) {
+        let lower = index
+            .checked_sub(1)
+            .and_then(|i| self.thumb(i))
+            .map_or(0.0, |thumb| self.percent(thumb.value.get()));
+        let upper = self
+            .thumb(index + 1)
+            .map_or(1.0, |thumb| self.percent(thumb.value.get()));
+        (lower, upper)
+    }
+
+    /// Moves thumb `index` to `value`, respecting its bounds. The value must already be snapped.
+    pub(super) fn set_thumb_value(&self, index: usize, value: f64) {
+        let Some(thumb) = self.thumb(index) else {
+            return;
+        };
+        let (lower, upper) = self.thumb_bounds(index);
+        let percent = self.percent(value);
+        let value = match percent {
+            p if p < lower => lower.mul_add(self.max - self.min, self.min),
+            p if p > upper => upper.mul_add(self.max - self.min, self.min),
+            _ => value,
+        };
+        if (thumb.value.get_untracked() - value).abs() > f64::EPSILON {
+            Callable::call(&thumb.set_value, value);
+        }
+    }
+
+    /// Position (0..1) on the track at the given client coordinates.
+    fn percent_at(&self, client_x: f64, client_y: f64) -> Option<f64> {
+        let rect = self.track.get_untracked()?.get_bounding_client_rect();
+        let percent = match self.orientation {
+            SliderOrientation::Horizontal if rect.width() > 0.0 => {
+                (client_x - rect.left()) / rect.width()
+            }
+            SliderOrientation::Vertical if rect.height() > 0.0 => {
+                (rect.bottom() - client_y) / rect.height()
+            }
+            _ => return None,
+        };
+        Some(percent.clamp(0.0, 1.0))
+    }
+
+    /// The thumb closest to `percent`. If multiple thumbs are stacked, the one which can move towards `percent` is chosen.
+    fn closest_thumb(&self, percent: f64) -> Option<usize> {
+        let positions = self.thumbs.with_value(|thumbs| {
+            thumbs
+                .iter()
+                .enumerate()
+                .filter_map(|(i, thumb)| thumb.map(|t| (i, self.percent(t.value.get_untracked()))))
+                .collect::<Vec<_>>()
+        });
+        let mut closest: Option<(usize, f64)> = None;
+        for (i, position) in positions {
+            let distance = (position - percent).abs();
+            match closest {
+                Some((_, closest_distance)) if distance > closest_distance => {}
+                // Equally close thumbs are stacked. Later thumbs can only move up, earlier ones only move down.
+                Some((_, closest_distance))
+                    if (distance - closest_distance).abs() < f64::EPSILON && percent < position => {
+                }
+                _ => closest = Some((i, distance)),
+            }
+        }
+        closest.map(|(i, _)| i)
+    }
+}
+
+#[derive(Debug)]
+pub struct UseSliderReturn {
+    pub props: UseSliderProps,
+
+    /// Pass this to `use_slider_thumb` for every thumb of this slider.
+    pub state: SliderState,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseSliderProps {
+    /// These attributes must be spread onto the slider element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the element receiving presses, usually the slider element
+    /// surrounding the track: `<foo on:pointerdown=on_pointer_down />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_down: Box<dyn Fn(PointerEvent)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DragState {
+    pointer_id: i32,
+}
+
+// Like all hooks, this takes its input by value, even though it only copies fields out of it.
+#[allow(clippy::needless_pass_by_value)]
+pub fn use_slider<TrackRef>(input: UseSliderInput<TrackRef>) -> UseSliderReturn
+where
+    TrackRef: ElementDescriptor + Clone + 'static,
+{
+    let track_ref = input.track_ref;
+    let track = create_memo(move |_| {
+        track_ref
+            .get()
+            .map(|el| web_sys::Element::from(el.into_any().deref().clone()))
+    });
+
+    let state = SliderState {
+        min: input.min,
+        max: input.max,
+        step: input.step,
+        orientation: input.orientation,
+        disabled: input.disabled,
+        track: track.into(),
+        thumbs: store_value(Vec::new()),
+        dragged_thumb: create_rw_signal(None),
+    };
+
+    let drag = store_value(Option::<DragState>::None);
+
+    let on_pointer_down = Box::new(move |e: PointerEvent| {
+        if state.disabled.get_untracked() || e.button() != 0 {
+            return;
+        }
+        let Some(percent) = state.percent_at(f64::from(e.client_x()), f64::from(e.client_y()))
+        else {
+            return;
+        };
+        let Some(index) = state.closest_thumb(percent) else {
+            return;
+        };
+        // Prevents text selection and the focus from moving to the pressed element. Focus is given to the thumb instead.
+        e.prevent_default();
+        if let Some(element) = state.thumb(index).and_then(|t| t.element.get_untracked()) {
+            focus(&element);
+        }
+        state.set_thumb_value(index, state.value_at(percent));
+        drag.set_value(Some(DragState {
+            pointer_id: e.pointer_id(),
+        }));
+        state.dragged_thumb.set(Some(index));
+    });
+
+    let move_handle = window_event_listener(ev::pointermove, move |e| {
+        let Some(index) = state.dragged_thumb.get_untracked() else {
+            return;
+        };
+        if drag.with_value(|d| d.map(|d| d.pointer_id)) != Some(e.pointer_id()) {
+            return;
+        }
+        if let Some(percent) = state.percent_at(f64::from(e.client_x()), f64::from(e.client_y())) {
+            state.set_thumb_value(index, state.value_at(percent));
+        }
+    });
+
+    let end_drag = move |e: PointerEvent| {
+        if drag.with_value(|d| d.map(|d| d.pointer_id)) == Some(e.pointer_id()) {
+            drag.set_value(None);
+            state.dragged_thumb.set(None);
+        }
+    };
+    let up_handle = window_event_listener(ev::pointerup, end_drag);
+    let cancel_handle = window_event_listener(ev::pointercancel, end_drag);
+
+    on_cleanup(move || {
+        move_handle.remove();
+        up_handle.remove();
+        cancel_handle.remove();
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert("role", Attribute::String(Oco::Borrowed("group")));
+    attrs.insert(
+        "data-orientation",
+        Attribute::String(Oco::Borrowed(input.orientation.as_str())),
+    );
+    attrs.insert(
+        "aria-disabled",
+        Attribute::Fn(Rc::new(move || match state.disabled.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::Option(None),
+        })),
+    );
+
+    UseSliderReturn {
+        props: UseSliderProps {
+            attrs,
+            on_pointer_down,
+        },
+        state,
+    }
+}
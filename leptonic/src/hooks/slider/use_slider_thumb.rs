@@ -0,0 +1,183 @@
+use std::{ops::Deref, rc::Rc};
+
+use educe::Educe;
+use leptos::{html::ElementDescriptor, Attribute, IntoAttribute, NodeRef, Oco};
+use leptos_reactive::{
+    create_memo, on_cleanup, Callback, MaybeSignal, Signal, SignalGet, SignalGetUntracked,
+};
+use web_sys::KeyboardEvent;
+
+use crate::{
+    hooks::slider::use_slider::{SliderState, SliderThumbRegistration},
+    prelude::Consumer,
+    utils::props::Attributes,
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/slider/src/useSliderThumb.ts
+
+#[derive(Clone, Educe)]
+#[educe(Debug)]
+pub struct UseSliderThumbInput<ThumbRef>
+where
+    ThumbRef: ElementDescriptor + 'static,
+{
+    /// The thumb element. It receives focus when the thumb is dragged.
+    #[educe(Debug(ignore))]
+    pub thumb_ref: NodeRef<ThumbRef>,
+
+    /// State of the slider this thumb belongs to, as returned by `use_slider`.
+    pub state: SliderState,
+
+    /// Position of this thumb among all thumbs of the slider, starting with the thumb closest to `min`.
+    pub index: usize,
+
+    /// The current value of this thumb.
+    pub value: Signal<f64>,
+
+    /// Called whenever the user changes the value of this thumb.
+    #[educe(Debug(ignore))]
+    pub set_value: Callback<f64>,
+
+    /// Creates a human readable representation of a value, which is announced by assistive technology.
+    #[educe(Debug(ignore))]
+    pub value_display: Option<Consumer<f64, String>>,
+
+    /// Accessible name of the thumb, e.g. "Minimum" for the first thumb of a range slider.
+    pub aria_label: Option<Oco<'static, str>>,
+
+    /// Disables this thumb when true. The thumb is also disabled when the whole slider is.
+    pub disabled: MaybeSignal<bool>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseSliderThumbProps {
+    /// These attributes must be spread onto the thumb element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the thumb element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+}
+
+#[derive(Debug)]
+pub struct UseSliderThumbReturn {
+    pub props: UseSliderThumbProps,
+
+    /// Position of the thumb on the track, in 0..1 range.
+    pub percent: Signal<f64>,
+
+    /// Whether the thumb is currently dragged using a pointer.
+    pub is_dragged: Signal<bool>,
+}
+
+pub fn use_slider_thumb<ThumbRef>(input: UseSliderThumbInput<ThumbRef>) -> UseSliderThumbReturn
+where
+    ThumbRef: ElementDescriptor + Clone + 'static,
+{
+    let state = input.state;
+    let index = input.index;
+    let value = input.value;
+
+    let thumb_ref = input.thumb_ref;
+    let element = create_memo(move |_| thumb_ref.get().map(|el| el.into_any().deref().clone()));
+
+    state.thumbs.update_value(|thumbs| {
+        if thumbs.len() <= index {
+            thumbs.resize(index + 1, None);
+        }
+        thumbs[index] = Some(SliderThumbRegistration {
+            value,
+            set_value: input.set_value,
+            element: element.into(),
+        });
+    });
+    on_cleanup(move || {
+        state.thumbs.update_value(|thumbs| {
+            if let Some(thumb) = thumbs.get_mut(index) {
+                *thumb = None;
+            }
+        });
+    });
+
+    let thumb_disabled = input.disabled;
+    let disabled = Signal::derive(move || state.disabled.get() || thumb_disabled.get());
+
+    let on_key_down = Box::new(move |e: KeyboardEvent| {
+        if disabled.get_untracked() {
+            return;
+        }
+        let current = value.get_untracked();
+        let new_value = match e.key().as_str() {
+            "ArrowRight" | "ArrowUp" => current + state.key_step(),
+            "ArrowLeft" | "ArrowDown" => current - state.key_step(),
+            "PageUp" => current + state.page_step(),
+            "PageDown" => current - state.page_step(),
+            "Home" => state.min,
+            "End" => state.max,
+            _ => return,
+        };
+        e.prevent_default();
+        state.set_thumb_value(index, state.snap(new_value));
+    });
+
+    // Neighboring thumbs limit the values this thumb can take.
+    let bounds = Signal::derive(move || {
+        let (lower, upper) = state.thumb_bounds(index);
+        let lower = lower.mul_add(state.max - state.min, state.min);
+        let upper = upper.mul_add(state.max - state.min, state.min);
+        (lower.min(upper), lower.max(upper))
+    });
+
+    let value_display = input.value_display;
+    let mut attrs = Attributes::new();
+    attrs.insert("role", Attribute::String(Oco::Borrowed("slider")));
+    attrs.insert(
+        "tabindex",
+        Attribute::Fn(Rc::new(move || match disabled.get() {
+            true => Attribute::Option(None),
+            false => Attribute::String(Oco::Borrowed("0")),
+        })),
+    );
+    attrs.insert(
+        "aria-orientation",
+        Attribute::String(Oco::Borrowed(state.orientation.as_str())),
+    );
+    attrs.insert(
+        "aria-valuenow",
+        Attribute::Fn(Rc::new(move || value.get().into_attribute())),
+    );
+    attrs.insert(
+        "aria-valuemin",
+        Attribute::Fn(Rc::new(move || bounds.get().0.into_attribute())),
+    );
+    attrs.insert(
+        "aria-valuemax",
+        Attribute::Fn(Rc::new(move || bounds.get().1.into_attribute())),
+    );
+    attrs.insert(
+        "aria-valuetext",
+        Attribute::Fn(Rc::new(move || match &value_display {
+            Some(value_display) => {
+                Attribute::String(Oco::Owned(value_display.consume(value.get())))
+            }
+            None => Attribute::Option(None),
+        })),
+    );
+    attrs.insert(
+        "aria-disabled",
+        Attribute::Fn(Rc::new(move || match disabled.get() {
+            true => Attribute::String(Oco::Borrowed("true")),
+            false => Attribute::Option(None),
+        })),
+    );
+    if let Some(aria_label) = input.aria_label {
+        attrs.insert("aria-label", Attribute::String(aria_label));
+    }
+
+    UseSliderThumbReturn {
+        props: UseSliderThumbProps { attrs, on_key_down },
+        percent: Signal::derive(move || state.percent(value.get())),
+        is_dragged: Signal::derive(move || state.dragged_thumb.get() == Some(index)),
+    }
+}
@@ -0,0 +1,447 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+use educe::Educe;
+use leptos::{
+    html::ElementDescriptor, leptos_dom::helpers::TimeoutHandle, set_timeout_with_handle,
+    Attribute, NodeRef, Oco,
+};
+use leptos_reactive::{
+    create_effect, create_signal, on_cleanup, store_value, Callback, MaybeSignal, ReadSignal,
+    Signal, SignalGet, SignalGetUntracked, SignalSet, StoredValue, WriteSignal,
+};
+use web_sys::{FocusEvent, KeyboardEvent, PointerEvent};
+
+use crate::{
+    hooks::{
+        focus::{
+            use_focus::{use_focus, UseFocusInput},
+            use_focus_visible::use_focus_visible,
+        },
+        interactions::use_hover::{use_hover, UseHoverInput},
+        overlay::use_overlay_position::{
+            use_overlay_position, OverlayBoundary, PlacementX, PlacementY,
+            UseOverlayPositionArrowProps, UseOverlayPositionInput, OVERLAY_PADDING,
+        },
+    },
+    utils::{locale::WritingDirection, props::Attributes},
+};
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-stately/tooltip/src/useTooltipTriggerState.ts
+// and: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/tooltip/src/useTooltipTrigger.ts
+
+/// Time after closing the last tooltip in which other tooltips still open without delay.
+const GLOBAL_COOLDOWN: Duration = Duration::from_millis(500);
+
+thread_local! {
+    /// Whether tooltips currently open without delay, because another tooltip was shown recently.
+    static GLOBAL_WARMED_UP: Cell<bool> = const { Cell::new(false) };
+
+    /// Pending timeout ending the global warm-up period.
+    static GLOBAL_COOLDOWN_TIMEOUT: Cell<Option<TimeoutHandle>> = const { Cell::new(None) };
+
+    /// Only one tooltip is shown at any time. Opening a tooltip immediately closes this one.
+    static OPEN_TOOLTIP: RefCell<Option<TooltipState>> = const { RefCell::new(None) };
+}
+
+fn cancel_global_cooldown() {
+    if let Some(handle) = GLOBAL_COOLDOWN_TIMEOUT.with(Cell::take) {
+        handle.clear();
+    }
+}
+
+fn start_global_cooldown() {
+    cancel_global_cooldown();
+    match set_timeout_with_handle(
+        move || GLOBAL_WARMED_UP.with(|w| w.set(false)),
+        GLOBAL_COOLDOWN,
+    ) {
+        Ok(handle) => GLOBAL_COOLDOWN_TIMEOUT.with(|t| t.set(Some(handle))),
+        Err(err) => {
+            tracing::warn!(?err, "Could not schedule tooltip cooldown.");
+            GLOBAL_WARMED_UP.with(|w| w.set(false));
+        }
+    }
+}
+
+/// The open state of a single tooltip. Created by `use_tooltip_trigger`, consumed by `use_tooltip`.
+#[derive(Debug, Clone, Copy)]
+pub struct TooltipState {
+    id: StoredValue<Oco<'static, str>>,
+    is_open: ReadSignal<bool>,
+    set_is_open: WriteSignal<bool>,
+    delay: Duration,
+    close_delay: Duration,
+    open_timeout: StoredValue<Option<TimeoutHandle>>,
+    close_timeout: StoredValue<Option<TimeoutHandle>>,
+}
+
+impl TooltipState {
+    fn new(delay: Duration, close_delay: Duration) -> Self {
+        let (is_open, set_is_open) = create_signal(false);
+        Self {
+            id: store_value(Oco::Owned(format!("tooltip-{}", uuid::Uuid::new_v4()))),
+            is_open,
+            set_is_open,
+            delay,
+            close_delay,
+            open_timeout: store_value(None),
+            close_timeout: store_value(None),
+        }
+    }
+
+    /// Id of the tooltip element.
+    pub fn id(&self) -> Oco<'static, str> {
+        self.id.get_value()
+    }
+
+    /// Whether the tooltip is currently shown.
+    pub fn is_open(&self) -> Signal<bool> {
+        self.is_open.into()
+    }
+
+    /// Opens the tooltip. Unless `immediate` is set or another tooltip was shown recently,
+    /// the tooltip is only shown after the configured delay.
+    pub fn open(&self, immediate: bool) {
+        Self::clear(self.close_timeout);
+        if self.is_open.get_untracked() {
+            return;
+        }
+        if immediate || GLOBAL_WARMED_UP.with(Cell::get) || self.delay.is_zero() {
+            Self::clear(self.open_timeout);
+            self.show();
+        } else if self.open_timeout.with_value(Option::is_none) {
+            let this = *self;
+            match set_timeout_with_handle(
+                move || {
+                    this.open_timeout.set_value(None);
+                    GLOBAL_WARMED_UP.with(|w| w.set(true));
+                    this.show();
+                },
+                self.delay,
+            ) {
+                Ok(handle) => self.open_timeout.set_value(Some(handle)),
+                Err(err) => tracing::warn!(?err, "Could not schedule opening of tooltip."),
+            }
+        }
+    }
+
+    /// Closes the tooltip. Unless `immediate` is set, the tooltip is only hidden after the configured close delay.
+    pub fn close(&self, immediate: bool) {
+        Self::clear(self.open_timeout);
+        if !self.is_open.get_untracked() {
+            return;
+        }
+        if immediate || self.close_delay.is_zero() {
+            Self::clear(self.close_timeout);
+            self.hide();
+        } else if self.close_timeout.with_value(Option::is_none) {
+            let this = *self;
+            match set_timeout_with_handle(
+                move || {
+                    this.close_timeout.set_value(None);
+                    this.hide();
+                },
+                self.close_delay,
+            ) {
+                Ok(handle) => self.close_timeout.set_value(Some(handle)),
+                Err(err) => {
+                    tracing::warn!(?err, "Could not schedule closing of tooltip.");
+                    self.hide();
+                }
+            }
+        }
+    }
+
+    fn show(&self) {
+        let id = self.id();
+        let previous = OPEN_TOOLTIP.with(|open| open.borrow_mut().replace(*self));
+        if let Some(previous) = previous {
+            if previous.id.try_with_value(|other| *other != id) == Some(true) {
+                previous.hide_without_cooldown();
+            }
+        }
+        cancel_global_cooldown();
+        self.set_is_open.set(true);
+    }
+
+    fn hide(&self) {
+        self.hide_without_cooldown();
+        start_global_cooldown();
+    }
+
+    fn hide_without_cooldown(&self) {
+        let id = self.id();
+        OPEN_TOOLTIP.with(|open| {
+            let mut open = open.borrow_mut();
+            if open
+                .as_ref()
+                .and_then(|it| it.id.try_with_value(|other| *other == id))
+                == Some(true)
+            {
+                *open = None;
+            }
+        });
+        self.set_is_open.set(false);
+    }
+
+    fn clear(timeout: StoredValue<Option<TimeoutHandle>>) {
+        if let Some(handle) = timeout.get_value() {
+            handle.clear();
+            timeout.set_value(None);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Educe)]
+#[educe(Debug)]
+pub struct UseTooltipTriggerInput<TriggerRef, TooltipRef>
+where
+    TriggerRef: ElementDescriptor + 'static,
+    TooltipRef: ElementDescriptor + 'static,
+{
+    /// The element the tooltip describes.
+    #[educe(Debug(ignore))]
+    pub trigger_ref: NodeRef<TriggerRef>,
+
+    /// The tooltip element. It is positioned relative to the trigger.
+    #[educe(Debug(ignore))]
+    pub tooltip_ref: NodeRef<TooltipRef>,
+
+    /// Whether the tooltip should never be shown.
+    pub disabled: MaybeSignal<bool>,
+
+    /// Time to wait before showing the tooltip when the trigger is hovered.
+    /// Not applied when another tooltip was shown recently.
+    pub delay: Duration,
+
+    /// Time to wait before hiding the tooltip after the pointer left the trigger.
+    pub close_delay: Duration,
+
+    /// Only show the tooltip when the trigger is focused, never on hover.
+    pub trigger_on_focus_only: bool,
+
+    pub placement_x: MaybeSignal<PlacementX>,
+    pub placement_y: MaybeSignal<PlacementY>,
+    pub writing_direction: MaybeSignal<WritingDirection>,
+
+    /// Distance in px between the trigger and the tooltip.
+    pub offset: MaybeSignal<f64>,
+
+    /// The area the tooltip is kept inside of when being flipped or shifted.
+    pub boundary: OverlayBoundary,
+
+    /// Optional arrow element rendered inside the tooltip. Spread `arrow_props` onto this element.
+    pub arrow_ref: Option<Signal<Option<web_sys::Element>>>,
+}
+
+#[derive(Debug)]
+pub struct UseTooltipTriggerReturn {
+    /// Props for the trigger element.
+    pub props: UseTooltipTriggerProps,
+
+    /// Props for the tooltip element.
+    pub tooltip_props: UseTooltipTriggerTooltipProps,
+
+    /// Props for the arrow element, if an `arrow_ref` was given.
+    pub arrow_props: UseOverlayPositionArrowProps,
+
+    /// Pass this to `use_tooltip`.
+    pub state: TooltipState,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseTooltipTriggerProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the target element: `<foo on:pointerenter=on_pointer_enter />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_enter: Box<dyn Fn(PointerEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:pointerleave=on_pointer_leave />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_leave: Box<dyn Fn(PointerEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:pointerdown=on_pointer_down />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_down: Box<dyn Fn(PointerEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:keydown=on_key_down />`
+    #[educe(Debug(ignore))]
+    pub on_key_down: Box<dyn Fn(KeyboardEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:focus=on_focus />`
+    #[educe(Debug(ignore))]
+    pub on_focus: Box<dyn Fn(FocusEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:blur=on_blur />`
+    #[educe(Debug(ignore))]
+    pub on_blur: Box<dyn Fn(FocusEvent)>,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseTooltipTriggerTooltipProps {
+    /// These attributes must be spread onto the tooltip element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+}
+
+// Like all hooks, this takes its input by value, even though it only copies fields out of it.
+#[allow(clippy::needless_pass_by_value)]
+pub fn use_tooltip_trigger<TriggerRef, TooltipRef>(
+    input: UseTooltipTriggerInput<TriggerRef, TooltipRef>,
+) -> UseTooltipTriggerReturn
+where
+    TriggerRef: ElementDescriptor + Clone + 'static,
+    TooltipRef: ElementDescriptor + Clone + 'static,
+{
+    let state = TooltipState::new(input.delay, input.close_delay);
+    let disabled = input.disabled;
+    let trigger_on_focus_only = input.trigger_on_focus_only;
+
+    let hover = use_hover(UseHoverInput {
+        disabled: Signal::derive(move || disabled.get() || trigger_on_focus_only).into(),
+        on_hover_start: Some(Callback::new(move |_| state.open(false))),
+        on_hover_end: Some(Callback::new(move |_| state.close(false))),
+    });
+
+    // Focus caused by a pointer, e.g. when clicking the trigger, does not show the tooltip.
+    let is_focus_visible = use_focus_visible().is_focus_visible;
+    let focus = use_focus(UseFocusInput {
+        disabled,
+        on_focus: Some(Callback::new(move |_| {
+            if is_focus_visible.get_untracked() {
+                state.open(true);
+            }
+        })),
+        on_blur: Some(Callback::new(move |_| state.close(true))),
+        on_focus_change: None,
+    });
+
+    // Pressing the trigger hides the tooltip, as the user is now interacting with the trigger.
+    let on_pointer_down = Box::new(move |_e: PointerEvent| state.close(true));
+
+    let on_key_down = Box::new(move |e: KeyboardEvent| {
+        if e.key().as_str() == "Escape" && state.is_open.get_untracked() {
+            e.stop_propagation();
+            state.close(true);
+        }
+    });
+
+    create_effect(move |_| {
+        if disabled.get() {
+            state.close(true);
+        }
+    });
+
+    on_cleanup(move || {
+        TooltipState::clear(state.open_timeout);
+        TooltipState::clear(state.close_timeout);
+        if state.is_open.get_untracked() {
+            state.hide();
+        }
+    });
+
+    let overlay_position = use_overlay_position(UseOverlayPositionInput {
+        overlay_ref: input.tooltip_ref,
+        target_ref: input.trigger_ref,
+        placement_x: input.placement_x,
+        placement_y: input.placement_y,
+        writing_direction: input.writing_direction,
+        flip: true,
+        shift: true,
+        offset: input.offset,
+        padding: OVERLAY_PADDING.into(),
+        boundary: input.boundary,
+        arrow_ref: input.arrow_ref,
+    });
+
+    let id = state.id();
+
+    let mut attrs = Attributes::new();
+    let described_by = id.clone();
+    attrs.insert(
+        "aria-describedby",
+        Attribute::Fn(Rc::new(move || match state.is_open.get() {
+            true => Attribute::String(described_by.clone()),
+            false => Attribute::Option(None),
+        })),
+    );
+    attrs.merge(hover.props.attrs);
+    attrs.merge(focus.props.attrs);
+
+    let mut tooltip_attrs = Attributes::new();
+    tooltip_attrs.insert("id", Attribute::String(id));
+    tooltip_attrs.merge(overlay_position.props.attrs);
+
+    UseTooltipTriggerReturn {
+        props: UseTooltipTriggerProps {
+            attrs,
+            on_pointer_enter: hover.props.on_pointer_enter,
+            on_pointer_leave: hover.props.on_pointer_leave,
+            on_pointer_down,
+            on_key_down,
+            on_focus: focus.props.on_focus,
+            on_blur: focus.props.on_blur,
+        },
+        tooltip_props: UseTooltipTriggerTooltipProps {
+            attrs: tooltip_attrs,
+        },
+        arrow_props: overlay_position.arrow_props,
+        state,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UseTooltipInput {
+    /// The state returned by `use_tooltip_trigger`.
+    pub state: TooltipState,
+}
+
+#[derive(Debug)]
+pub struct UseTooltipReturn {
+    pub props: UseTooltipProps,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+pub struct UseTooltipProps {
+    /// These attributes must be spread onto the target element: `<foo {..attrs} />`
+    pub attrs: Attributes,
+
+    /// This handler must be attached to the target element: `<foo on:pointerenter=on_pointer_enter />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_enter: Box<dyn Fn(PointerEvent)>,
+
+    /// This handler must be attached to the target element: `<foo on:pointerleave=on_pointer_leave />`
+    #[educe(Debug(ignore))]
+    pub on_pointer_leave: Box<dyn Fn(PointerEvent)>,
+}
+
+pub fn use_tooltip(input: UseTooltipInput) -> UseTooltipReturn {
+    let state = input.state;
+
+    // Moving the pointer from the trigger onto the tooltip keeps the tooltip open.
+    let hover = use_hover(UseHoverInput {
+        disabled: false.into(),
+        on_hover_start: Some(Callback::new(move |_| state.open(true))),
+        on_hover_end: Some(Callback::new(move |_| state.close(false))),
+    });
+
+    let mut attrs = Attributes::new();
+    attrs.insert("role", Attribute::String(Oco::Borrowed("tooltip")));
+    attrs.merge(hover.props.attrs);
+
+    UseTooltipReturn {
+        props: UseTooltipProps {
+            attrs,
+            on_pointer_enter: hover.props.on_pointer_enter,
+            on_pointer_leave: hover.props.on_pointer_leave,
+        },
+    }
+}
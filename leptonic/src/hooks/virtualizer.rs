@@ -0,0 +1,391 @@
+use std::{collections::HashMap, hash::Hash, ops::Deref, ops::Range};
+
+use educe::Educe;
+use leptos::{ev, html::ElementDescriptor, request_animation_frame, NodeRef};
+use leptos_reactive::{
+    create_effect, create_memo, create_rw_signal, store_value, Callable, Callback, Signal,
+    SignalGet, SignalGetUntracked, SignalSet, SignalUpdate, SignalWith, SignalWithUntracked,
+};
+use leptos_use::{use_event_listener_with_options, use_window, UseEventListenerOptions};
+
+// This is mostly based on work in: https://github.com/TanStack/virtual/blob/main/packages/virtual-core/src/index.ts
+
+/// Attribute marking the spacer elements surrounding the rendered items.
+pub const VIRTUAL_SPACER_ATTRIBUTE: &str = "data-virtual-spacer";
+
+/// Differences in measured sizes below this amount of pixels are ignored.
+const MEASUREMENT_TOLERANCE: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VirtualItemSize {
+    /// Every item is exactly this many pixels high.
+    Fixed(f64),
+
+    /// Items are measured once they are rendered. Items not yet rendered are assumed to be this many pixels high.
+    Estimated(f64),
+}
+
+/// Where an item should end up in the visible area when scrolling to it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VirtualAlign {
+    Start,
+    Center,
+    End,
+    /// Scroll as little as possible to make the item fully visible. Does nothing if it already is.
+    #[default]
+    Auto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualItem {
+    pub index: usize,
+
+    /// Offset of the item from the start of the list in pixels.
+    pub start: f64,
+
+    /// Height of the item in pixels.
+    pub size: f64,
+}
+
+#[derive(Clone, Copy, Educe)]
+#[educe(Debug)]
+pub struct UseVirtualizerInput<AnchorRef, K = usize>
+where
+    AnchorRef: ElementDescriptor + 'static,
+    K: 'static,
+{
+    /// The spacer element rendered in front of the first rendered item, sized to `padding_start`.
+    /// The rendered items must directly follow it as siblings, followed by another spacer sized to `padding_end`.
+    /// Both spacers must carry the `data-virtual-spacer` attribute.
+    #[educe(Debug(ignore))]
+    pub anchor_ref: NodeRef<AnchorRef>,
+
+    /// Total number of items in the list.
+    pub count: Signal<usize>,
+
+    /// Key of the item at the given index. Measured sizes are remembered per key,
+    /// so they stay with their items when items are inserted, removed or reordered.
+    #[educe(Debug(ignore))]
+    pub key: Callback<usize, K>,
+
+    pub item_size: VirtualItemSize,
+
+    /// Number of items rendered in front of and behind the visible ones.
+    pub overscan: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UseVirtualizerReturn {
+    /// Indices of the items which should currently be rendered.
+    pub range: Signal<Range<usize>>,
+
+    /// The items which should currently be rendered.
+    pub items: Signal<Vec<VirtualItem>>,
+
+    /// Space taken by the items in front of the rendered ones. The spacer in front of the rendered items must be this high.
+    pub padding_start: Signal<f64>,
+
+    /// Space taken by the items behind the rendered ones. The spacer behind the rendered items must be this high.
+    pub padding_end: Signal<f64>,
+
+    /// Height of all items combined.
+    pub total_size: Signal<f64>,
+
+    /// Scrolls the nearest scroll container (or the window) so that the item at the given index becomes visible.
+    pub scroll_to_index: Callback<(usize, VirtualAlign)>,
+}
+
+/// Start offsets of all items, followed by the total size of all items.
+fn item_offsets<K: Eq + Hash>(
+    count: usize,
+    item_size: VirtualItemSize,
+    measured: &HashMap<K, f64>,
+    key: impl Fn(usize) -> K,
+) -> Vec<f64> {
+    let mut offsets = Vec::with_capacity(count + 1);
+    let mut offset = 0.0;
+    offsets.push(offset);
+    for i in 0..count {
+        offset += match item_size {
+            VirtualItemSize::Fixed(size) => size,
+            VirtualItemSize::Estimated(size) => measured.get(&key(i)).copied().unwrap_or(size),
+        };
+        offsets.push(offset);
+    }
+    offsets
+}
+
+/// Indices of all items intersecting the `[start, end)` window, extended by `overscan` items on both sides.
+fn visible_range(offsets: &[f64], start: f64, end: f64, overscan: usize) -> Range<usize> {
+    let count = offsets.len().saturating_sub(1);
+    if count == 0 {
+        return 0..0;
+    }
+    let items = &offsets[..count];
+    let first = items
+        .partition_point(|offset| *offset <= start)
+        .saturating_sub(1);
+    let last = items.partition_point(|offset| *offset < end).max(first);
+    first.saturating_sub(overscan)..usize::min(count, last + overscan)
+}
+
+/// The nearest ancestor of `el` scrolling its content vertically.
+fn scroll_parent(el: &web_sys::Element) -> Option<web_sys::Element> {
+    let window = web_sys::window()?;
+    std::iter::successors(el.parent_element(), |el| el.parent_element()).find(|ancestor| {
+        window
+            .get_computed_style(ancestor)
+            .ok()
+            .flatten()
+            .and_then(|style| style.get_property_value("overflow-y").ok())
+            .is_some_and(|overflow| matches!(overflow.as_str(), "auto" | "scroll" | "overlay"))
+    })
+}
+
+// Like all hooks, this takes its input by value, even though it only copies fields out of it.
+#[allow(clippy::too_many_lines, clippy::needless_pass_by_value)]
+pub fn use_virtualizer<AnchorRef, K>(
+    input: UseVirtualizerInput<AnchorRef, K>,
+) -> UseVirtualizerReturn
+where
+    AnchorRef: ElementDescriptor + Clone + 'static,
+    K: Eq + Hash + 'static,
+{
+    let count = input.count;
+    let key = input.key;
+    let item_size = input.item_size;
+    let overscan = input.overscan;
+
+    let anchor_ref = input.anchor_ref;
+    let anchor = create_memo(move |_| {
+        anchor_ref
+            .get()
+            .map(|el| web_sys::Element::from(el.into_any().deref().clone()))
+    });
+    let scroller = store_value(Option::<web_sys::Element>::None);
+
+    let measured = create_rw_signal(HashMap::<K, f64>::new());
+    let offsets = create_memo(move |_| {
+        measured.with(|measured| {
+            item_offsets(count.get(), item_size, measured, |index| key.call(index))
+        })
+    });
+
+    // The visible area, relative to the start of the list.
+    let viewport = create_rw_signal((0.0, 0.0));
+    let update_viewport = move || {
+        let (Some(anchor), Some(window)) = (anchor.get_untracked(), web_sys::window()) else {
+            return;
+        };
+        let list_top = anchor.get_bounding_client_rect().top();
+        let mut top = 0.0;
+        let mut bottom = window
+            .inner_height()
+            .ok()
+            .and_then(|h| h.as_f64())
+            .unwrap_or_default();
+        if let Some(scroller) = scroller.get_value() {
+            let rect = scroller.get_bounding_client_rect();
+            top = f64::max(top, rect.top());
+            bottom = f64::min(bottom, rect.bottom());
+        }
+        let next = (top - list_top, bottom - list_top);
+        if viewport.get_untracked() != next {
+            viewport.set(next);
+        }
+    };
+
+    // The anchor may not be laid out when its node ref is first set.
+    create_effect(move |_| {
+        if let Some(anchor) = anchor.get() {
+            request_animation_frame(move || {
+                scroller.set_value(scroll_parent(&anchor));
+                update_viewport();
+            });
+        }
+    });
+
+    // Scroll events do not bubble. Capturing them on the window catches scrolling of any element.
+    let _ = use_event_listener_with_options(
+        use_window(),
+        ev::scroll,
+        move |_| update_viewport(),
+        UseEventListenerOptions::default()
+            .capture(true)
+            .passive(true),
+    );
+    let _ = use_event_listener_with_options(
+        use_window(),
+        ev::resize,
+        move |_| update_viewport(),
+        UseEventListenerOptions::default().passive(true),
+    );
+
+    let range = create_memo(move |_| {
+        let (start, end) = viewport.get();
+        offsets.with(|offsets| visible_range(offsets, start, end, overscan))
+    });
+
+    // Measure rendered items after the browser laid them out.
+    if let VirtualItemSize::Estimated(_) = item_size {
+        create_effect(move |_| {
+            let range = range.get();
+            let Some(anchor) = anchor.get() else {
+                return;
+            };
+            request_animation_frame(move || {
+                let mut sizes = Vec::new();
+                let mut next = anchor.next_element_sibling();
+                for index in range {
+                    let Some(el) = next.filter(|el| !el.has_attribute(VIRTUAL_SPACER_ATTRIBUTE))
+                    else {
+                        break;
+                    };
+                    sizes.push((key.call(index), el.get_bounding_client_rect().height()));
+                    next = el.next_element_sibling();
+                }
+                let changed = measured.with_untracked(|measured| {
+                    sizes.iter().any(|(key, size)| {
+                        measured
+                            .get(key)
+                            .map_or(true, |known| (known - size).abs() > MEASUREMENT_TOLERANCE)
+                    })
+                });
+                if changed {
+                    measured.update(|measured| measured.extend(sizes));
+                }
+            });
+        });
+    }
+
+    let items = Signal::derive(move || {
+        let range = range.get();
+        offsets.with(|offsets| {
+            range
+                .map(|index| VirtualItem {
+                    index,
+                    start: offsets[index],
+                    size: offsets[index + 1] - offsets[index],
+                })
+                .collect()
+        })
+    });
+
+    let total_size =
+        Signal::derive(move || offsets.with(|offsets| offsets.last().copied().unwrap_or_default()));
+    let padding_start = Signal::derive(move || {
+        let start = range.get().start;
+        offsets.with(|offsets| offsets.get(start).copied().unwrap_or_default())
+    });
+    let padding_end = Signal::derive(move || {
+        let end = range.get().end;
+        offsets.with(|offsets| {
+            offsets.last().copied().unwrap_or_default()
+                - offsets.get(end).copied().unwrap_or_default()
+        })
+    });
+
+    let scroll_to_index = Callback::new(move |(index, align): (usize, VirtualAlign)| {
+        update_viewport();
+        let Some((start, end)) = offsets.with_untracked(|offsets| {
+            offsets
+                .get(index)
+                .copied()
+                .zip(offsets.get(index + 1).copied())
+        }) else {
+            return;
+        };
+        let (view_start, view_end) = viewport.get_untracked();
+        let view_size = view_end - view_start;
+        let target = match align {
+            VirtualAlign::Start => start,
+            VirtualAlign::Center => (start + end - view_size) / 2.0,
+            VirtualAlign::End => end - view_size,
+            VirtualAlign::Auto if start < view_start => start,
+            VirtualAlign::Auto if end > view_end => end - view_size,
+            VirtualAlign::Auto => return,
+        };
+        let delta = target - view_start;
+        match scroller.get_value() {
+            #[allow(clippy::cast_possible_truncation)]
+            Some(scroller) => scroller.set_scroll_top(scroller.scroll_top() + delta.round() as i32),
+            None => {
+                if let Some(window) = web_sys::window() {
+                    window.scroll_by_with_x_and_y(0.0, delta);
+                }
+            }
+        }
+    });
+
+    UseVirtualizerReturn {
+        range: range.into(),
+        items,
+        padding_start,
+        padding_end,
+        total_size,
+        scroll_to_index,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{item_offsets, visible_range, VirtualItemSize};
+
+    #[test]
+    fn offsets_of_fixed_size_items() {
+        let offsets = item_offsets(
+            3,
+            VirtualItemSize::Fixed(10.0),
+            &HashMap::<usize, f64>::new(),
+            |i| i,
+        );
+        assert_eq!(offsets, vec![0.0, 10.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    fn offsets_prefer_measured_sizes_over_estimate() {
+        let measured = HashMap::from([(1, 25.0)]);
+        let offsets = item_offsets(3, VirtualItemSize::Estimated(10.0), &measured, |i| i);
+        assert_eq!(offsets, vec![0.0, 10.0, 35.0, 45.0]);
+    }
+
+    #[test]
+    fn measured_sizes_follow_their_keys() {
+        // The item measured at index 1 moved to the front of the list.
+        let measured = HashMap::from([("b", 25.0)]);
+        let keys = ["b", "a", "c"];
+        let offsets = item_offsets(3, VirtualItemSize::Estimated(10.0), &measured, |i| keys[i]);
+        assert_eq!(offsets, vec![0.0, 25.0, 35.0, 45.0]);
+    }
+
+    #[test]
+    fn range_covers_partially_visible_items() {
+        let offsets = item_offsets(
+            100,
+            VirtualItemSize::Fixed(10.0),
+            &HashMap::<usize, f64>::new(),
+            |i| i,
+        );
+        assert_eq!(visible_range(&offsets, 15.0, 45.0, 0), 1..5);
+        assert_eq!(visible_range(&offsets, 20.0, 40.0, 0), 2..4);
+    }
+
+    #[test]
+    fn range_is_extended_by_overscan_and_clamped() {
+        let offsets = item_offsets(
+            10,
+            VirtualItemSize::Fixed(10.0),
+            &HashMap::<usize, f64>::new(),
+            |i| i,
+        );
+        assert_eq!(visible_range(&offsets, 0.0, 30.0, 2), 0..5);
+        assert_eq!(visible_range(&offsets, 80.0, 200.0, 2), 6..10);
+    }
+
+    #[test]
+    fn range_of_empty_list_is_empty() {
+        assert_eq!(visible_range(&[0.0], 0.0, 100.0, 5), 0..0);
+    }
+}
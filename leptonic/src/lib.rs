@@ -142,6 +142,7 @@ pub mod prelude {
     //pub use crate::hooks::prelude::*;
     pub use crate::contexts::global_click_event::GlobalClickEvent;
     pub use crate::contexts::global_keyboard_event::GlobalKeyboardEvent;
+    pub use crate::contexts::global_modality::Modality;
     pub use crate::create_signal_ls;
 }
 
@@ -0,0 +1,24 @@
+use leptos::{leptos_dom::HydrationCtx, Attribute, Oco};
+
+use crate::utils::props::Attributes;
+
+// This is mostly based on work in: https://github.com/adobe/react-spectrum/blob/main/packages/%40react-aria/utils/src/useDescription.ts
+
+const VISUALLY_HIDDEN_STYLE: &str = "border: 0; clip: rect(0 0 0 0); clip-path: inset(50%); height: 1px; margin: -1px; overflow: hidden; padding: 0; position: absolute; width: 1px; white-space: nowrap;";
+
+/// Attributes of a visually hidden element describing another element, which references it through `aria-describedby`.
+/// The element is rendered by the user of the hook, so that it is part of server rendered markup as well.
+/// Its id is taken from the hydration context, so that the id rendered on the server is kept when hydrating.
+pub(crate) fn use_description() -> (Oco<'static, str>, Attributes) {
+    let key = HydrationCtx::id().unwrap_or_else(HydrationCtx::peek_always);
+    let id: Oco<'static, str> = Oco::Owned(format!("description-{key}"));
+
+    let mut attrs = Attributes::new();
+    attrs.insert("id", Attribute::String(id.clone()));
+    attrs.insert(
+        "style",
+        Attribute::String(Oco::Borrowed(VISUALLY_HIDDEN_STYLE)),
+    );
+
+    (id, attrs)
+}
@@ -0,0 +1,28 @@
+use leptos_use::use_document;
+use wasm_bindgen::JsCast;
+
+const TABBABLE_SELECTOR: &str = r#"a[href], area[href], button:not([disabled]), input:not([disabled]):not([type="hidden"]), select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable], [tabindex]:not([tabindex="-1"])"#;
+
+/// All elements inside of `container` which can be reached using the Tab key, in document order.
+pub(crate) fn tabbable_elements(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(TABBABLE_SELECTOR) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+pub(crate) fn focus(el: &web_sys::HtmlElement) {
+    if let Err(err) = el.focus() {
+        tracing::warn!(?err, "Could not move focus.");
+    }
+}
+
+/// The currently focused element, if any.
+pub(crate) fn active_element() -> Option<web_sys::Element> {
+    use_document()
+        .as_ref()
+        .and_then(web_sys::Document::active_element)
+}
@@ -5,3 +5,441 @@ pub enum WritingDirection {
     /// Right-to-left
     Rtl,
 }
+
+/// Language-specific data used to display calendars.
+///
+/// All data is bundled, so that rendering on the server and in the browser produces the same output.
+/// Use one of the provided locales, e.g. `CalendarLocale::DE`, look one up with `CalendarLocale::find`,
+/// or describe your own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarLocale {
+    /// Language tag of this locale, e.g. "en-GB".
+    pub tag: &'static str,
+    /// The weekday shown in the first column of a calendar.
+    pub first_weekday: time::Weekday,
+    /// Names of the months, starting with January.
+    pub month_names: [&'static str; 12],
+    /// Names of the weekdays, starting with Monday.
+    pub weekday_names: [&'static str; 7],
+    /// Abbreviated names of the weekdays, starting with Monday.
+    pub short_weekday_names: [&'static str; 7],
+    /// Heading of the week number column.
+    pub week_number_label: &'static str,
+    /// Format of a date, as a `time` format description, e.g. "[day].[month].[year]".
+    pub date_format: &'static str,
+    /// Format of a month heading. "[month]" is replaced by the name of the month and "[year]" by the year.
+    pub month_year_format: &'static str,
+}
+
+impl Default for CalendarLocale {
+    fn default() -> Self {
+        Self::EN_GB
+    }
+}
+
+impl CalendarLocale {
+    pub const EN: Self = Self {
+        tag: "en",
+        first_weekday: time::Weekday::Sunday,
+        month_names: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        weekday_names: [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+        short_weekday_names: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        week_number_label: "Wk",
+        date_format: "[month]/[day]/[year]",
+        month_year_format: "[month] [year]",
+    };
+
+    pub const EN_GB: Self = Self {
+        tag: "en-GB",
+        first_weekday: time::Weekday::Monday,
+        date_format: "[day]/[month]/[year]",
+        ..Self::EN
+    };
+
+    pub const DE: Self = Self {
+        tag: "de",
+        first_weekday: time::Weekday::Monday,
+        month_names: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        weekday_names: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        short_weekday_names: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        week_number_label: "KW",
+        date_format: "[day].[month].[year]",
+        month_year_format: "[month] [year]",
+    };
+
+    pub const FR: Self = Self {
+        tag: "fr",
+        first_weekday: time::Weekday::Monday,
+        month_names: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        weekday_names: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        short_weekday_names: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        week_number_label: "Sem.",
+        date_format: "[day]/[month]/[year]",
+        month_year_format: "[month] [year]",
+    };
+
+    pub const ES: Self = Self {
+        tag: "es",
+        first_weekday: time::Weekday::Monday,
+        month_names: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        weekday_names: [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        short_weekday_names: ["lu", "ma", "mi", "ju", "vi", "sá", "do"],
+        week_number_label: "Sem.",
+        date_format: "[day]/[month]/[year]",
+        month_year_format: "[month] de [year]",
+    };
+
+    pub const IT: Self = Self {
+        tag: "it",
+        first_weekday: time::Weekday::Monday,
+        month_names: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        weekday_names: [
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+            "domenica",
+        ],
+        short_weekday_names: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+        week_number_label: "Sett.",
+        date_format: "[day]/[month]/[year]",
+        month_year_format: "[month] [year]",
+    };
+
+    pub const NL: Self = Self {
+        tag: "nl",
+        first_weekday: time::Weekday::Monday,
+        month_names: [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        weekday_names: [
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+            "zondag",
+        ],
+        short_weekday_names: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        week_number_label: "Wk",
+        date_format: "[day]-[month]-[year]",
+        month_year_format: "[month] [year]",
+    };
+
+    pub const PT: Self = Self {
+        tag: "pt",
+        first_weekday: time::Weekday::Monday,
+        month_names: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        weekday_names: [
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+            "domingo",
+        ],
+        short_weekday_names: ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+        week_number_label: "Sem.",
+        date_format: "[day]/[month]/[year]",
+        month_year_format: "[month] de [year]",
+    };
+
+    pub const PL: Self = Self {
+        tag: "pl",
+        first_weekday: time::Weekday::Monday,
+        month_names: [
+            "styczeń",
+            "luty",
+            "marzec",
+            "kwiecień",
+            "maj",
+            "czerwiec",
+            "lipiec",
+            "sierpień",
+            "wrzesień",
+            "październik",
+            "listopad",
+            "grudzień",
+        ],
+        weekday_names: [
+            "poniedziałek",
+            "wtorek",
+            "środa",
+            "czwartek",
+            "piątek",
+            "sobota",
+            "niedziela",
+        ],
+        short_weekday_names: ["pon", "wt", "śr", "czw", "pt", "sob", "niedz"],
+        week_number_label: "Tydz.",
+        date_format: "[day].[month].[year]",
+        month_year_format: "[month] [year]",
+    };
+
+    pub const SV: Self = Self {
+        tag: "sv",
+        first_weekday: time::Weekday::Monday,
+        month_names: [
+            "januari",
+            "februari",
+            "mars",
+            "april",
+            "maj",
+            "juni",
+            "juli",
+            "augusti",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        weekday_names: [
+            "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag", "söndag",
+        ],
+        short_weekday_names: ["mån", "tis", "ons", "tors", "fre", "lör", "sön"],
+        week_number_label: "V.",
+        date_format: "[year]-[month]-[day]",
+        month_year_format: "[month] [year]",
+    };
+
+    pub const DA: Self = Self {
+        tag: "da",
+        first_weekday: time::Weekday::Monday,
+        month_names: [
+            "januar",
+            "februar",
+            "marts",
+            "april",
+            "maj",
+            "juni",
+            "juli",
+            "august",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        weekday_names: [
+            "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag",
+        ],
+        short_weekday_names: ["man", "tir", "ons", "tor", "fre", "lør", "søn"],
+        week_number_label: "Uge",
+        date_format: "[day].[month].[year]",
+        month_year_format: "[month] [year]",
+    };
+
+    /// All bundled locales.
+    pub const ALL: [Self; 11] = [
+        Self::EN,
+        Self::EN_GB,
+        Self::DE,
+        Self::FR,
+        Self::ES,
+        Self::IT,
+        Self::NL,
+        Self::PT,
+        Self::PL,
+        Self::SV,
+        Self::DA,
+    ];
+
+    /// Finds the bundled locale best matching a language tag like "de-AT", ignoring case.
+    /// Falls back to the primary language, e.g. "de", if there is no locale for the full tag.
+    pub fn find(tag: &str) -> Option<Self> {
+        let tag = tag.trim().replace('_', "-");
+        let language = tag.split('-').next().unwrap_or_default();
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.tag.eq_ignore_ascii_case(&tag))
+            .or_else(|| {
+                Self::ALL
+                    .into_iter()
+                    .find(|locale| locale.tag.eq_ignore_ascii_case(language))
+            })
+    }
+
+    pub const fn month_name(&self, month: time::Month) -> &'static str {
+        self.month_names[month as usize - 1]
+    }
+
+    /// Abbreviated names of the weekdays, starting with the `first_weekday`.
+    pub fn ordered_short_weekday_names(&self) -> Vec<&'static str> {
+        let first = self.first_weekday.number_days_from_monday() as usize;
+        (0..7)
+            .map(|i| self.short_weekday_names[(first + i) % 7])
+            .collect()
+    }
+
+    /// Position of `weekday` in a week starting with the `first_weekday`, in range [0..6].
+    pub const fn weekday_index(&self, weekday: time::Weekday) -> u8 {
+        (weekday.number_days_from_monday() + 7 - self.first_weekday.number_days_from_monday()) % 7
+    }
+
+    /// Formats `date` using the `date_format`. Falls back to an ISO 8601 date if the `date_format` is invalid.
+    pub fn format_date(&self, date: time::Date) -> String {
+        time::format_description::parse_borrowed::<2>(self.date_format)
+            .ok()
+            .and_then(|format| date.format(&format).ok())
+            .unwrap_or_else(|| date.to_string())
+    }
+
+    /// Formats a month heading using the `month_year_format`, e.g. "October 2026".
+    pub fn format_month_year(&self, month: time::Month, year: i32) -> String {
+        self.month_year_format
+            .replace("[month]", self.month_name(month))
+            .replace("[year]", &year.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::CalendarLocale;
+
+    #[test]
+    fn find_falls_back_to_the_primary_language() {
+        assert_eq!(CalendarLocale::find("en-gb"), Some(CalendarLocale::EN_GB));
+        assert_eq!(CalendarLocale::find("de_AT"), Some(CalendarLocale::DE));
+        assert_eq!(CalendarLocale::find("en-US"), Some(CalendarLocale::EN));
+        assert_eq!(CalendarLocale::find("ja"), None);
+    }
+
+    #[test]
+    fn weekdays_are_ordered_by_the_first_weekday() {
+        assert_eq!(CalendarLocale::EN.ordered_short_weekday_names()[0], "Sun");
+        assert_eq!(CalendarLocale::EN.weekday_index(time::Weekday::Monday), 1);
+        assert_eq!(CalendarLocale::DE.ordered_short_weekday_names()[0], "Mo");
+        assert_eq!(CalendarLocale::DE.weekday_index(time::Weekday::Sunday), 6);
+    }
+
+    #[test]
+    fn formats_dates_and_months() {
+        let date = date!(2026 - 10 - 18);
+        assert_eq!(CalendarLocale::EN.format_date(date), "10/18/2026");
+        assert_eq!(CalendarLocale::DE.format_date(date), "18.10.2026");
+        assert_eq!(
+            CalendarLocale::ES.format_month_year(date.month(), date.year()),
+            "octubre de 2026"
+        );
+    }
+}
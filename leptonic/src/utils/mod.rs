@@ -1,14 +1,18 @@
 pub mod aria;
 pub mod callback;
 pub mod color;
+pub(crate) mod description;
+pub(crate) mod focus;
 pub mod locale;
 pub mod math;
+pub(crate) mod options;
 pub mod pointer_type;
 pub mod props;
 pub mod scroll_behavior;
 pub mod signals;
 pub(crate) mod text_selection;
 pub mod time;
+pub(crate) mod typeahead;
 
 pub(crate) enum DomContainer {
     Node(web_sys::Node),
@@ -60,6 +64,26 @@ impl ElementExt for web_sys::Element {
     }
 }
 
+pub(crate) trait NodeRefExt {
+    /// Untracked access to the underlying DOM element. `None` as long as the node is not mounted.
+    fn get_html_element_untracked(&self) -> Option<web_sys::HtmlElement>;
+
+    /// Tracked access to the underlying DOM element. `None` as long as the node is not mounted.
+    fn get_element(&self) -> Option<web_sys::Element>;
+}
+
+impl<E: leptos::html::ElementDescriptor + Clone + 'static> NodeRefExt for leptos::NodeRef<E> {
+    fn get_html_element_untracked(&self) -> Option<web_sys::HtmlElement> {
+        use std::ops::Deref;
+        self.get_untracked().map(|el| el.into_any().deref().clone())
+    }
+
+    fn get_element(&self) -> Option<web_sys::Element> {
+        use std::ops::Deref;
+        self.get().map(|el| el.into_any().deref().clone().into())
+    }
+}
+
 pub(crate) trait EventTargetExt {
     fn as_element(&self) -> Option<web_sys::Element>;
     fn as_html_element(&self) -> Option<web_sys::HtmlElement>;
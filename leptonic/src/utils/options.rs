@@ -0,0 +1,31 @@
+use leptos::event_target;
+
+use crate::prelude::Consumer;
+
+/// Distance to the end of a list of options, in pixels, below which further options are loaded.
+pub(crate) const LOAD_MORE_THRESHOLD: i32 = 48;
+
+/// Calls `load_more` once the element scrolled in `e` was scrolled close to its end.
+pub(crate) fn load_more_on_scroll(e: &web_sys::Event, load_more: impl FnOnce()) {
+    let el = event_target::<web_sys::Element>(e);
+    if el.scroll_top() + el.client_height() + LOAD_MORE_THRESHOLD >= el.scroll_height() {
+        load_more();
+    }
+}
+
+/// Keeps the options whose search text contains the search, ignoring case.
+pub(crate) fn default_search_filter<O: Clone + 'static>(
+    search_text_provider: Consumer<O, String>,
+) -> Consumer<(String, Vec<O>), Vec<O>> {
+    Consumer::new(move |(s, o): (String, Vec<O>)| {
+        let lowercased_search = s.to_lowercase();
+        o.into_iter()
+            .filter(|it| {
+                search_text_provider
+                    .consume(it.clone())
+                    .to_lowercase()
+                    .contains(lowercased_search.as_str())
+            })
+            .collect::<Vec<O>>()
+    })
+}
@@ -1,3 +1,4 @@
+use leptos::Oco;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,6 +22,9 @@ pub struct Month {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Week {
     pub id: Uuid,
+    /// ISO 8601 number of this week, taken from its Thursday. Weeks not starting on Monday share most of their days
+    /// with that ISO week.
+    pub number: u8,
     pub days: Vec<Day>, // Not always full?
 }
 
@@ -39,11 +43,27 @@ pub struct Day {
     pub in_month: InMonth,
     pub date_time: time::OffsetDateTime,
     pub disabled: bool,
+    /// Whether this day lies in the selected range, or in the range previewed while selecting one.
     pub highlighted: bool,
+    /// Whether this day is the first day of the highlighted range.
+    pub is_range_start: bool,
+    /// Whether this day is the last day of the highlighted range.
+    pub is_range_end: bool,
     pub is_staging: bool,
     pub is_now: bool,
 }
 
+/// Additional content and styling of a single day in a calendar.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayDecoration {
+    /// CSS classes added to the day, separated by whitespace.
+    pub class: Option<Oco<'static, str>>,
+    /// Short text shown below the number of the day, e.g. a price or the number of free slots.
+    pub badge: Option<Oco<'static, str>>,
+    /// Describes the day, e.g. why it is disabled. Shown as a tooltip.
+    pub title: Option<Oco<'static, str>>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GuideMode {
     CalendarFirst,
@@ -69,6 +89,399 @@ impl Default for Type {
     }
 }
 
+/// How hours are displayed and entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HourCycle {
+    /// Hours from 0 to 23.
+    #[default]
+    H24,
+    /// Hours from 1 to 12, followed by an AM/PM period.
+    H12,
+}
+
+/// Converts an hour in range [0..23] into an hour in range [1..12] and whether it lies in the PM period.
+pub const fn to_12_hour(hour: u8) -> (u8, bool) {
+    let pm = hour >= 12;
+    match hour % 12 {
+        0 => (12, pm),
+        h => (h, pm),
+    }
+}
+
+/// Converts an hour in range [1..12] of the AM or PM period into an hour in range [0..23].
+pub const fn from_12_hour(hour: u8, pm: bool) -> u8 {
+    match pm {
+        true => hour % 12 + 12,
+        false => hour % 12,
+    }
+}
+
+/// Moves `value`, taken from range [0..`len`), `delta` slots of size `step` up or down.
+///
+/// Wraps around at the ends of the range. Values not lying on a slot are first moved to the neighbouring slot in the direction of `delta`.
+pub fn step_value(value: u8, len: u8, step: u8, delta: i32) -> u8 {
+    let step = i32::from(step.max(1));
+    let value = i32::from(value);
+    let slots = (i32::from(len) + step - 1) / step;
+    let slot = value / step;
+    let moved = match delta < 0 && value % step != 0 {
+        true => slot + delta + 1,
+        false => slot + delta,
+    };
+    u8::try_from(moved.rem_euclid(slots) * step).unwrap_or_default()
+}
+
+/// Rounds `value` to the nearest slot of size `step` in range [0..`len`).
+pub fn snap_value(value: u8, len: u8, step: u8) -> u8 {
+    let step = step.max(1);
+    let last_slot = (len - 1) / step * step;
+    let rounded = (u16::from(value) + u16::from(step) / 2) / u16::from(step) * u16::from(step);
+    u8::try_from(rounded).map_or(last_slot, |rounded| rounded.min(last_slot))
+}
+
+/// Moves `date_time` into the range spanned by `min` and `max`.
+pub fn clamp_date_time(
+    date_time: time::OffsetDateTime,
+    min: Option<&time::OffsetDateTime>,
+    max: Option<&time::OffsetDateTime>,
+) -> time::OffsetDateTime {
+    match (min, max) {
+        (Some(min), _) if date_time < *min => *min,
+        (_, Some(max)) if date_time > *max => *max,
+        _ => date_time,
+    }
+}
+
+/// How the value of a `DateTimeInput` is entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateTimeEntry {
+    /// Select the value in a popup, opened by clicking the input.
+    #[default]
+    Popup,
+    /// Type each part of the value into its own segment.
+    Segments,
+    /// Type each part of the value into its own segment, or select it in a popup opened by a button next to the segments.
+    SegmentsAndPopup,
+}
+
+/// A part of a date and time which is entered on its own, or the text between two such parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateTimeSegment {
+    Year,
+    /// Always entered as a number, even if the format description names the month.
+    Month,
+    Day,
+    /// Hours from 0 to 23 or, using a 12-hour clock, from 1 to 12.
+    Hour {
+        twelve_hour: bool,
+    },
+    Minute,
+    Second,
+    /// AM or PM, used together with a 12-hour clock.
+    Period,
+    /// Text shown between two segments, e.g. "-" or ":".
+    Literal(String),
+}
+
+impl DateTimeSegment {
+    /// Range of the values which can be entered into this segment. `None` for periods and literals.
+    pub const fn range(&self) -> Option<(i32, i32)> {
+        match self {
+            Self::Year => Some((1, 9999)),
+            Self::Month | Self::Hour { twelve_hour: true } => Some((1, 12)),
+            Self::Day => Some((1, 31)),
+            Self::Hour { twelve_hour: false } => Some((0, 23)),
+            Self::Minute | Self::Second => Some((0, 59)),
+            Self::Period | Self::Literal(_) => None,
+        }
+    }
+
+    /// Number of characters shown by this segment.
+    pub const fn width(&self) -> usize {
+        match self {
+            Self::Year => 4,
+            Self::Literal(_) => 0,
+            _ => 2,
+        }
+    }
+
+    /// Text shown while nothing was entered into this segment.
+    pub const fn placeholder(&self) -> &'static str {
+        match self {
+            Self::Year => "yyyy",
+            Self::Month | Self::Minute => "mm",
+            Self::Day => "dd",
+            Self::Hour { .. } => "hh",
+            Self::Second => "ss",
+            Self::Period => "am",
+            Self::Literal(_) => "",
+        }
+    }
+
+    /// Accessible name of this segment.
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Year => "Year",
+            Self::Month => "Month",
+            Self::Day => "Day",
+            Self::Hour { .. } => "Hours",
+            Self::Minute => "Minutes",
+            Self::Second => "Seconds",
+            Self::Period => "AM/PM",
+            Self::Literal(_) => "",
+        }
+    }
+
+    /// Whether the digits `typed` so far fill this segment, so that further digits belong to the next segment.
+    /// This is the case when no further digit fits, e.g. after typing "2" into a month.
+    pub fn is_filled(&self, typed: &str) -> bool {
+        match (self.range(), typed.parse::<i32>()) {
+            (Some((_, max)), Ok(value)) => typed.len() >= self.width() || value * 10 > max,
+            _ => false,
+        }
+    }
+}
+
+/// Splits a format description like "[year]-[month]-[day] [hour]:[minute]" into segments.
+///
+/// Only the components year, month, day, hour, minute, second and period are supported. Write "[hour repr:12]" for an
+/// hour on a 12-hour clock. Returns `None` if the format description is invalid or uses any other component.
+pub fn parse_date_time_segments(format: &str) -> Option<Vec<DateTimeSegment>> {
+    time::format_description::parse_borrowed::<2>(format).ok()?;
+
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => literal.push(chars.next()?),
+            '[' => {
+                if !literal.is_empty() {
+                    segments.push(DateTimeSegment::Literal(std::mem::take(&mut literal)));
+                }
+                let component = chars.by_ref().take_while(|c| *c != ']').collect::<String>();
+                let mut parts = component.split_whitespace();
+                segments.push(match parts.next()? {
+                    "year" => DateTimeSegment::Year,
+                    "month" => DateTimeSegment::Month,
+                    "day" => DateTimeSegment::Day,
+                    "hour" => DateTimeSegment::Hour {
+                        twelve_hour: parts.any(|modifier| modifier == "repr:12"),
+                    },
+                    "minute" => DateTimeSegment::Minute,
+                    "second" => DateTimeSegment::Second,
+                    "period" => DateTimeSegment::Period,
+                    _ => return None,
+                });
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(DateTimeSegment::Literal(literal));
+    }
+    Some(segments)
+}
+
+/// The format description used for the segments of a `DateTimeInput` if no other one is given.
+pub fn default_date_time_format(
+    input_type: Type,
+    hour_cycle: HourCycle,
+    show_seconds: bool,
+) -> String {
+    let date = "[year]-[month]-[day]";
+    let time = match (hour_cycle, show_seconds) {
+        (HourCycle::H24, false) => "[hour]:[minute]",
+        (HourCycle::H24, true) => "[hour]:[minute]:[second]",
+        (HourCycle::H12, false) => "[hour repr:12]:[minute] [period]",
+        (HourCycle::H12, true) => "[hour repr:12]:[minute]:[second] [period]",
+    };
+    match input_type {
+        Type::Date => date.to_owned(),
+        Type::Time => time.to_owned(),
+        Type::DateTime => format!("{date} {time}"),
+    }
+}
+
+/// The parts of a date and time entered through segments so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateTimeDraft {
+    pub year: Option<i32>,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+    /// Hours from 0 to 23.
+    pub hour: Option<u8>,
+    pub minute: Option<u8>,
+    pub second: Option<u8>,
+    /// Whether the hour lies in the PM period. Entered separately when using a 12-hour clock.
+    pub pm: Option<bool>,
+}
+
+impl DateTimeDraft {
+    pub fn from_date_time(date_time: time::OffsetDateTime) -> Self {
+        Self {
+            year: Some(date_time.year()),
+            month: Some(u8::from(date_time.month())),
+            day: Some(date_time.day()),
+            hour: Some(date_time.hour()),
+            minute: Some(date_time.minute()),
+            second: Some(date_time.second()),
+            pm: Some(date_time.hour() >= 12),
+        }
+    }
+
+    /// The value entered into `segment`. Hours on a 12-hour clock are in range [1..12]. Periods are 0 for AM and 1 for PM.
+    pub fn get(&self, segment: &DateTimeSegment) -> Option<i32> {
+        match segment {
+            DateTimeSegment::Year => self.year,
+            DateTimeSegment::Month => self.month.map(i32::from),
+            DateTimeSegment::Day => self.day.map(i32::from),
+            DateTimeSegment::Hour { twelve_hour: false } => self.hour.map(i32::from),
+            DateTimeSegment::Hour { twelve_hour: true } => {
+                self.hour.map(|hour| i32::from(to_12_hour(hour).0))
+            }
+            DateTimeSegment::Minute => self.minute.map(i32::from),
+            DateTimeSegment::Second => self.second.map(i32::from),
+            DateTimeSegment::Period => self.pm.map(i32::from),
+            DateTimeSegment::Literal(_) => None,
+        }
+    }
+
+    /// Enters `value` into `segment`, in the representation returned by `get`. Values outside of the `range` of the
+    /// segment clear it.
+    pub fn set(&mut self, segment: &DateTimeSegment, value: Option<i32>) {
+        let value = match segment.range() {
+            Some((min, max)) => value.filter(|value| (min..=max).contains(value)),
+            None => value,
+        };
+        let small = value.and_then(|value| u8::try_from(value).ok());
+        match segment {
+            DateTimeSegment::Year => self.year = value,
+            DateTimeSegment::Month => self.month = small,
+            DateTimeSegment::Day => self.day = small,
+            DateTimeSegment::Hour { twelve_hour: false } => {
+                self.hour = small;
+                self.pm = small.map(|hour| hour >= 12);
+            }
+            DateTimeSegment::Hour { twelve_hour: true } => {
+                let pm = self.pm.unwrap_or(false);
+                self.hour = small.map(|hour| from_12_hour(hour, pm));
+            }
+            DateTimeSegment::Minute => self.minute = small,
+            DateTimeSegment::Second => self.second = small,
+            DateTimeSegment::Period => {
+                self.pm = value.map(|period| period != 0);
+                if let (Some(hour), Some(pm)) = (self.hour, self.pm) {
+                    self.hour = Some(from_12_hour(to_12_hour(hour).0, pm));
+                }
+            }
+            DateTimeSegment::Literal(_) => {}
+        }
+    }
+
+    /// The text shown by `segment`, e.g. "07" or "PM". `None` while nothing was entered into it.
+    pub fn text(&self, segment: &DateTimeSegment) -> Option<String> {
+        match segment {
+            DateTimeSegment::Year => self.year.map(|year| format!("{year:04}")),
+            DateTimeSegment::Period => self.pm.map(|pm| match pm {
+                true => "PM".to_owned(),
+                false => "AM".to_owned(),
+            }),
+            DateTimeSegment::Literal(text) => Some(text.clone()),
+            segment => self.get(segment).map(|value| format!("{value:02}")),
+        }
+    }
+
+    /// Whether a value was entered into every segment.
+    pub fn is_complete(&self, segments: &[DateTimeSegment]) -> bool {
+        segments.iter().all(|segment| match segment {
+            DateTimeSegment::Literal(_) => true,
+            segment => self.get(segment).is_some(),
+        })
+    }
+
+    /// The date and time entered into `segments`. Parts without a segment are taken from `base`.
+    /// `None` if a segment is empty or the entered parts do not form a valid date, like February 30.
+    pub fn build(
+        &self,
+        segments: &[DateTimeSegment],
+        base: time::OffsetDateTime,
+    ) -> Option<time::OffsetDateTime> {
+        if !self.is_complete(segments) {
+            return None;
+        }
+        let month =
+            time::Month::try_from(self.month.unwrap_or_else(|| u8::from(base.month()))).ok()?;
+        let date = time::Date::from_calendar_date(
+            self.year.unwrap_or_else(|| base.year()),
+            month,
+            self.day.unwrap_or_else(|| base.day()),
+        )
+        .ok()?;
+        let time = time::Time::from_hms(
+            self.hour.unwrap_or_else(|| base.hour()),
+            self.minute.unwrap_or_else(|| base.minute()),
+            self.second.unwrap_or_else(|| base.second()),
+        )
+        .ok()?;
+        Some(base.replace_date(date).replace_time(time))
+    }
+
+    /// Moves the value of `segment` `delta` steps up or down, wrapping around at the ends of its range.
+    /// An empty segment starts at the value `base` has in it. Minutes and seconds move in slots of
+    /// `minute_step` and `second_step`.
+    pub fn step(
+        &mut self,
+        segment: &DateTimeSegment,
+        delta: i32,
+        base: time::OffsetDateTime,
+        minute_step: u8,
+        second_step: u8,
+    ) {
+        let current = Self::from_date_time(base);
+        match segment {
+            DateTimeSegment::Year => {
+                let year = self.year.or(current.year).unwrap_or_default();
+                self.year = Some((year + delta).clamp(1, 9999));
+            }
+            DateTimeSegment::Month => {
+                let month = i32::from(self.month.or(current.month).unwrap_or(1));
+                self.set(segment, Some((month - 1 + delta).rem_euclid(12) + 1));
+            }
+            DateTimeSegment::Day => {
+                let days = match (
+                    self.year,
+                    self.month
+                        .and_then(|month| time::Month::try_from(month).ok()),
+                ) {
+                    (Some(year), Some(month)) => i32::from(whole_days_in(year, month)),
+                    _ => 31,
+                };
+                let day = i32::from(self.day.or(current.day).unwrap_or(1)).min(days);
+                self.set(segment, Some((day - 1 + delta).rem_euclid(days) + 1));
+            }
+            DateTimeSegment::Hour { .. } => {
+                let hour = step_value(self.hour.or(current.hour).unwrap_or_default(), 24, 1, delta);
+                self.hour = Some(hour);
+                self.pm = Some(hour >= 12);
+            }
+            DateTimeSegment::Minute => {
+                let minute = self.minute.or(current.minute).unwrap_or_default();
+                self.minute = Some(step_value(minute, 60, minute_step, delta));
+            }
+            DateTimeSegment::Second => {
+                let second = self.second.or(current.second).unwrap_or_default();
+                self.second = Some(step_value(second, 60, second_step, delta));
+            }
+            DateTimeSegment::Period => {
+                let pm = self.pm.or(current.pm).unwrap_or(false);
+                self.set(segment, Some(i32::from(!pm)));
+            }
+            DateTimeSegment::Literal(_) => {}
+        }
+    }
+}
+
 pub trait SaveReplaceYear
 where
     Self: Sized,
@@ -133,6 +546,23 @@ pub fn is_in_range(
     after_min && before_max
 }
 
+/// Whether the day `date` lies between the days of `min` and `max`. Their times are ignored.
+pub fn is_date_in_range(
+    date: time::Date,
+    min: Option<&time::OffsetDateTime>,
+    max: Option<&time::OffsetDateTime>,
+) -> bool {
+    min.map_or(true, |min| date >= min.date()) && max.map_or(true, |max| date <= max.date())
+}
+
+/// Orders two days, so that the earlier one comes first.
+pub fn ordered_dates(a: time::Date, b: time::Date) -> (time::Date, time::Date) {
+    match a <= b {
+        true => (a, b),
+        false => (b, a),
+    }
+}
+
 /// Might decrease the year to x-1 if in January of year x.
 pub fn start_of_previous_month(dt: time::OffsetDateTime) -> time::OffsetDateTime {
     let start = dt.replace_day(1).unwrap();
@@ -161,9 +591,12 @@ pub fn start_of_next_month(dt: time::OffsetDateTime) -> time::OffsetDateTime {
 
 #[cfg(test)]
 mod tests {
-    use time::macros::datetime;
+    use time::macros::{date, datetime};
 
-    use super::SaveReplaceYear;
+    use super::{
+        from_12_hour, is_date_in_range, ordered_dates, parse_date_time_segments, snap_value,
+        step_value, to_12_hour, DateTimeDraft, DateTimeSegment, SaveReplaceYear,
+    };
 
     #[test]
     fn save_replace_year_replaces_when_coming_from_feb_29() {
@@ -178,4 +611,151 @@ mod tests {
         let result = dt.save_replace_month(time::Month::February).unwrap();
         assert_eq!(result, datetime!(2023-02-28 0:00 UTC))
     }
+
+    #[test]
+    fn converts_between_hour_cycles() {
+        assert_eq!(to_12_hour(0), (12, false));
+        assert_eq!(to_12_hour(11), (11, false));
+        assert_eq!(to_12_hour(12), (12, true));
+        assert_eq!(to_12_hour(23), (11, true));
+        for hour in 0..24 {
+            let (h, pm) = to_12_hour(hour);
+            assert_eq!(from_12_hour(h, pm), hour);
+        }
+    }
+
+    #[test]
+    fn step_value_moves_between_slots_and_wraps_around() {
+        assert_eq!(step_value(0, 60, 15, 1), 15);
+        assert_eq!(step_value(45, 60, 15, 1), 0);
+        assert_eq!(step_value(0, 60, 15, -1), 45);
+        assert_eq!(step_value(7, 60, 15, 1), 15);
+        assert_eq!(step_value(7, 60, 15, -1), 0);
+        assert_eq!(step_value(23, 24, 1, 1), 0);
+    }
+
+    #[test]
+    fn snap_value_rounds_to_the_nearest_slot() {
+        assert_eq!(snap_value(7, 60, 15), 0);
+        assert_eq!(snap_value(8, 60, 15), 15);
+        assert_eq!(snap_value(59, 60, 15), 45);
+        assert_eq!(snap_value(42, 60, 1), 42);
+    }
+
+    #[test]
+    fn is_date_in_range_ignores_times() {
+        let min = datetime!(2023-03-10 18:00 UTC);
+        let max = datetime!(2023-03-20 6:00 UTC);
+        assert!(is_date_in_range(
+            date!(2023 - 03 - 10),
+            Some(&min),
+            Some(&max)
+        ));
+        assert!(is_date_in_range(
+            date!(2023 - 03 - 20),
+            Some(&min),
+            Some(&max)
+        ));
+        assert!(!is_date_in_range(
+            date!(2023 - 03 - 09),
+            Some(&min),
+            Some(&max)
+        ));
+        assert!(!is_date_in_range(
+            date!(2023 - 03 - 21),
+            Some(&min),
+            Some(&max)
+        ));
+        assert!(is_date_in_range(date!(1970 - 01 - 01), None, Some(&max)));
+    }
+
+    #[test]
+    fn ordered_dates_puts_the_earlier_day_first() {
+        let (a, b) = (date!(2023 - 03 - 10), date!(2023 - 03 - 20));
+        assert_eq!(ordered_dates(a, b), (a, b));
+        assert_eq!(ordered_dates(b, a), (a, b));
+    }
+
+    #[test]
+    fn parses_date_time_segments() {
+        assert_eq!(
+            parse_date_time_segments("[day].[month].[year] [hour repr:12]:[minute] [period]"),
+            Some(vec![
+                DateTimeSegment::Day,
+                DateTimeSegment::Literal(".".to_owned()),
+                DateTimeSegment::Month,
+                DateTimeSegment::Literal(".".to_owned()),
+                DateTimeSegment::Year,
+                DateTimeSegment::Literal(" ".to_owned()),
+                DateTimeSegment::Hour { twelve_hour: true },
+                DateTimeSegment::Literal(":".to_owned()),
+                DateTimeSegment::Minute,
+                DateTimeSegment::Literal(" ".to_owned()),
+                DateTimeSegment::Period,
+            ])
+        );
+        assert_eq!(parse_date_time_segments("[weekday] [day]"), None);
+        assert_eq!(parse_date_time_segments("[year"), None);
+    }
+
+    #[test]
+    fn is_filled_once_no_further_digit_fits() {
+        assert!(!DateTimeSegment::Month.is_filled("1"));
+        assert!(DateTimeSegment::Month.is_filled("2"));
+        assert!(DateTimeSegment::Month.is_filled("12"));
+        assert!(!DateTimeSegment::Year.is_filled("202"));
+        assert!(DateTimeSegment::Year.is_filled("2026"));
+    }
+
+    #[test]
+    fn builds_date_times_from_complete_drafts_only() {
+        let segments = [
+            DateTimeSegment::Year,
+            DateTimeSegment::Month,
+            DateTimeSegment::Day,
+        ];
+        let base = datetime!(2026-10-18 14:30 UTC);
+        let mut draft = DateTimeDraft::default();
+        draft.set(&DateTimeSegment::Year, Some(2024));
+        draft.set(&DateTimeSegment::Month, Some(2));
+        assert_eq!(draft.build(&segments, base), None);
+        draft.set(&DateTimeSegment::Day, Some(29));
+        assert_eq!(
+            draft.build(&segments, base),
+            Some(datetime!(2024-02-29 14:30 UTC))
+        );
+        draft.set(&DateTimeSegment::Day, Some(30));
+        assert_eq!(draft.build(&segments, base), None);
+        draft.set(&DateTimeSegment::Month, Some(13));
+        assert_eq!(draft.month, None);
+    }
+
+    #[test]
+    fn enters_hours_on_a_12_hour_clock() {
+        let hour = DateTimeSegment::Hour { twelve_hour: true };
+        let mut draft = DateTimeDraft::default();
+        draft.set(&hour, Some(12));
+        assert_eq!(draft.hour, Some(0));
+        draft.set(&DateTimeSegment::Period, Some(1));
+        assert_eq!(draft.hour, Some(12));
+        draft.set(&hour, Some(3));
+        assert_eq!(draft.hour, Some(15));
+        assert_eq!(draft.text(&hour).as_deref(), Some("03"));
+        assert_eq!(draft.text(&DateTimeSegment::Period).as_deref(), Some("PM"));
+    }
+
+    #[test]
+    fn steps_segments_and_wraps_around() {
+        let base = datetime!(2026-02-28 23:45 UTC);
+        let mut draft = DateTimeDraft::from_date_time(base);
+        draft.step(&DateTimeSegment::Day, 1, base, 1, 1);
+        assert_eq!(draft.day, Some(1));
+        draft.step(&DateTimeSegment::Month, -2, base, 1, 1);
+        assert_eq!(draft.month, Some(12));
+        draft.step(&DateTimeSegment::Minute, 1, base, 15, 1);
+        assert_eq!(draft.minute, Some(0));
+        let mut empty = DateTimeDraft::default();
+        empty.step(&DateTimeSegment::Hour { twelve_hour: false }, 1, base, 1, 1);
+        assert_eq!(empty.hour, Some(0));
+    }
 }
@@ -0,0 +1,26 @@
+/// Typeahead input is reset after this amount of milliseconds without a keystroke.
+pub(crate) const TYPEAHEAD_TIMEOUT_MS: f64 = 1000.0;
+
+/// Collects the characters typed in quick succession, which are used to search for an item starting with them.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Typeahead {
+    search: String,
+    last_key_stroke: f64,
+}
+
+impl Typeahead {
+    /// Appends `key` to the current search, starting a new search if the last keystroke happened too long ago.
+    pub(crate) fn push(&mut self, key: &str, now: f64) -> &str {
+        if !self.is_in_progress(now) {
+            self.search.clear();
+        }
+        self.last_key_stroke = now;
+        self.search.push_str(&key.to_lowercase());
+        &self.search
+    }
+
+    /// Whether a search was started and is still continued by a keystroke happening `now`.
+    pub(crate) fn is_in_progress(&self, now: f64) -> bool {
+        !self.search.is_empty() && now - self.last_key_stroke <= TYPEAHEAD_TIMEOUT_MS
+    }
+}